@@ -5,7 +5,7 @@ use clap::{Args, Subcommand, ValueEnum};
 use wikitool_core::article_lint::ArticleFixApplyMode;
 
 use crate::RuntimeOptions;
-use crate::cli_support::OutputFormat;
+use crate::cli_support::{LintOutputFormat, OutputFormat};
 
 mod fix;
 mod lint;
@@ -41,11 +41,11 @@ pub(crate) struct ArticleLintArgs {
     #[arg(
         long,
         value_enum,
-        default_value_t = OutputFormat::Text,
+        default_value_t = LintOutputFormat::Text,
         value_name = "FORMAT",
-        help = "Output format: text|json"
+        help = "Output format: text|json|sarif|checkstyle"
     )]
-    format: OutputFormat,
+    format: LintOutputFormat,
     #[arg(long, help = "Treat warnings as errors")]
     strict: bool,
     #[arg(
@@ -64,6 +64,34 @@ pub(crate) struct ArticleLintArgs {
     titles_file: Option<PathBuf>,
     #[arg(long, help = "Lint the current changed main-namespace article set")]
     changed: bool,
+    #[arg(
+        long = "category",
+        value_name = "CATEGORY",
+        help = "Lint every page directly in a category (needs `knowledge build`); repeatable"
+    )]
+    categories: Vec<String>,
+    #[arg(
+        long = "namespace",
+        value_name = "NAMESPACE",
+        help = "Lint every page under wiki_content/ in a namespace, e.g. Main; repeatable"
+    )]
+    namespaces: Vec<String>,
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Worker threads for batch lint (default: available CPUs)"
+    )]
+    jobs: Option<usize>,
+    #[arg(
+        long,
+        help = "Batch lint: print per-rule and per-page counts without individual issues"
+    )]
+    summary: bool,
+    #[arg(
+        long,
+        help = "Record every current issue in .wikitool/lint-baseline.json so later runs report only new ones"
+    )]
+    write_baseline: bool,
 }
 
 #[derive(Debug, Args)]
@@ -115,6 +115,8 @@ pub(super) fn run_article_fix(runtime: &RuntimeOptions, args: ArticleFixArgs) ->
         &args.paths,
         args.titles_file.as_ref(),
         args.changed,
+        &[],
+        &[],
     )?;
     let target_paths = resolve_article_targets(&paths, args.path.as_deref(), &selection, true)?;
     let results = target_paths
@@ -1,19 +1,25 @@
+use std::num::NonZeroUsize;
 use std::path::Path;
 
 use anyhow::{Result, bail};
 use serde::Serialize;
 use wikitool_core::article_lint::{
-    ArticleLintReport, lint_article, lint_article_with_resources, lint_article_with_title,
-    load_article_lint_resources,
+    ArticleLintAggregate, ArticleLintReport, LintBaselineWriteSummary, aggregate_article_lint,
+    lint_article_with_resources, lint_articles_with_resources, load_article_lint_resources,
+    write_lint_baseline,
 };
+use wikitool_core::findings::findings_from_article_reports;
+use wikitool_core::runtime::ResolvedPaths;
 
-use crate::cli_support::{normalize_path, resolve_runtime_paths};
+use crate::cli_support::{
+    LintOutputFormat, normalize_path, print_lint_findings, resolve_runtime_paths,
+};
 use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
 
 use super::output::{flag, print_article_target_selection, print_report};
 use super::selection::{
-    ArticleTargetSelection, article_selection_from_args, resolve_article_targets,
-    single_state_path_title_override, uses_single_path_mode,
+    ArticleTargetSelection, article_selection_from_args, resolve_article_selector_path,
+    resolve_article_targets, single_state_path_title_override, uses_single_path_mode,
 };
 use super::*;
 #[derive(Debug, Serialize)]
@@ -21,40 +27,43 @@ struct ArticleLintBatchReport {
     project_root: String,
     strict: bool,
     selection: ArticleTargetSelection,
+    jobs: usize,
+    #[serde(flatten)]
+    aggregate: ArticleLintAggregate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reports: Option<Vec<ArticleLintReport>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ArticleLintBaselineReport {
+    project_root: String,
     target_count: usize,
-    total_errors: usize,
-    total_warnings: usize,
-    total_suggestions: usize,
-    reports: Vec<ArticleLintReport>,
+    baseline: LintBaselineWriteSummary,
 }
 
 pub(super) fn run_article_lint(runtime: &RuntimeOptions, args: ArticleLintArgs) -> Result<()> {
     let paths = resolve_runtime_paths(runtime)?;
-    if let Some(title_override) = single_state_path_title_override(
-        &paths,
-        args.path.as_deref(),
-        &args.titles,
-        &args.paths,
-        args.titles_file.as_ref(),
-        args.changed,
-    )? {
-        let report = lint_article_with_title(
+    if args.write_baseline {
+        return run_write_baseline(runtime, &paths, &args);
+    }
+    if !uses_tree_selectors(&args)
+        && let Some(title_override) = single_state_path_title_override(
+            &paths,
+            args.path.as_deref(),
+            &args.titles,
+            &args.paths,
+            args.titles_file.as_ref(),
+            args.changed,
+        )?
+    {
+        let report = lint_single_article(
             &paths,
             args.path.as_deref().expect("single path"),
             Some(title_override),
+            args.format,
         )?;
 
-        if args.format.is_json() {
-            println!("{}", serde_json::to_string_pretty(&report)?);
-        } else {
-            println!("article lint");
-            println!("project_root: {}", normalize_path(&paths.project_root));
-            print_report(&report);
-            println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
-            if runtime.diagnostics {
-                println!("\n[diagnostics]\n{}", paths.diagnostics());
-            }
-        }
+        print_single_report(runtime, &paths, args.format, &report)?;
 
         if report.errors > 0 || (args.strict && report.warnings > 0) {
             bail!(
@@ -67,26 +76,15 @@ pub(super) fn run_article_lint(runtime: &RuntimeOptions, args: ArticleLintArgs)
         return Ok(());
     }
 
-    if uses_single_path_mode(
-        args.path.as_deref(),
-        &args.titles,
-        &args.paths,
-        args.titles_file.as_ref(),
-        args.changed,
-    ) {
-        let report = lint_article(&paths, args.path.as_deref().expect("single path"))?;
+    if uses_single_lint_path(&args) {
+        let report = lint_single_article(
+            &paths,
+            args.path.as_deref().expect("single path"),
+            None,
+            args.format,
+        )?;
 
-        if args.format.is_json() {
-            println!("{}", serde_json::to_string_pretty(&report)?);
-        } else {
-            println!("article lint");
-            println!("project_root: {}", normalize_path(&paths.project_root));
-            print_report(&report);
-            println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
-            if runtime.diagnostics {
-                println!("\n[diagnostics]\n{}", paths.diagnostics());
-            }
-        }
+        print_single_report(runtime, &paths, args.format, &report)?;
 
         if report.errors > 0 || (args.strict && report.warnings > 0) {
             bail!(
@@ -99,51 +97,88 @@ pub(super) fn run_article_lint(runtime: &RuntimeOptions, args: ArticleLintArgs)
         return Ok(());
     }
 
-    let selection = article_selection_from_args(
-        &args.titles,
-        &args.paths,
-        args.titles_file.as_ref(),
-        args.changed,
-    )?;
+    let selection = lint_selection_from_args(&args)?;
     let target_paths = resolve_article_targets(&paths, args.path.as_deref(), &selection, false)?;
-    let resources = load_article_lint_resources(&paths)?;
-    let reports = target_paths
-        .iter()
-        .map(|relative_path| {
-            lint_article_with_resources(&paths, Path::new(relative_path), None, &resources)
-        })
-        .collect::<Result<Vec<_>>>()?;
-    let total_errors = reports.iter().map(|report| report.errors).sum();
-    let total_warnings = reports.iter().map(|report| report.warnings).sum();
-    let total_suggestions = reports.iter().map(|report| report.suggestions).sum();
+    let jobs = lint_jobs(args.jobs)?;
+    let mut resources = load_article_lint_resources(&paths)?;
+    if !args.summary && !args.format.is_findings_only() {
+        resources = resources.with_inbound_links();
+    }
+    let reports = lint_articles_with_resources(&paths, &target_paths, &resources, jobs)?;
+    if args.format.is_findings_only() {
+        print_lint_findings(
+            args.format,
+            &paths.project_root,
+            &findings_from_article_reports(&reports),
+        )?;
+    }
     let batch_report = ArticleLintBatchReport {
         project_root: normalize_path(&paths.project_root),
         strict: args.strict,
         selection,
-        target_count: reports.len(),
-        total_errors,
-        total_warnings,
-        total_suggestions,
-        reports,
+        jobs,
+        aggregate: aggregate_article_lint(&reports),
+        reports: (!args.summary).then_some(reports),
     };
+    let aggregate = &batch_report.aggregate;
 
     if args.format.is_json() {
         println!("{}", serde_json::to_string_pretty(&batch_report)?);
-    } else {
+    } else if !args.format.is_findings_only() {
         println!("article lint");
         println!("project_root: {}", normalize_path(&paths.project_root));
         println!("strict: {}", flag(batch_report.strict));
         print_article_target_selection(&batch_report.selection);
-        println!("target_count: {}", batch_report.target_count);
-        println!("total_errors: {}", batch_report.total_errors);
-        println!("total_warnings: {}", batch_report.total_warnings);
-        println!("total_suggestions: {}", batch_report.total_suggestions);
-        if batch_report.reports.is_empty() {
-            println!("reports: <none>");
+        println!("jobs: {}", batch_report.jobs);
+        println!("target_count: {}", aggregate.target_count);
+        println!("total_errors: {}", aggregate.total_errors);
+        println!("total_warnings: {}", aggregate.total_warnings);
+        println!("total_suggestions: {}", aggregate.total_suggestions);
+        println!("total_suppressed: {}", aggregate.total_suppressed);
+        println!("total_baselined: {}", aggregate.total_baselined);
+        if aggregate.rules.is_empty() {
+            println!("rules: <none>");
         } else {
-            for report in &batch_report.reports {
-                println!();
-                print_report(report);
+            for rule in &aggregate.rules {
+                println!(
+                    "rule: {} issues={} pages={} errors={} warnings={} suggestions={}",
+                    rule.rule_id,
+                    rule.issue_count,
+                    rule.page_count,
+                    rule.errors,
+                    rule.warnings,
+                    rule.suggestions
+                );
+            }
+        }
+        let flagged_pages = aggregate
+            .pages
+            .iter()
+            .filter(|page| page.errors + page.warnings + page.suggestions > 0)
+            .collect::<Vec<_>>();
+        println!(
+            "clean_pages: {}",
+            aggregate.pages.len() - flagged_pages.len()
+        );
+        for page in flagged_pages {
+            println!(
+                "page: {} errors={} warnings={} suggestions={} suppressed={} baselined={}",
+                page.relative_path,
+                page.errors,
+                page.warnings,
+                page.suggestions,
+                page.suppressed,
+                page.baselined
+            );
+        }
+        match batch_report.reports.as_deref() {
+            None => {}
+            Some([]) => println!("reports: <none>"),
+            Some(reports) => {
+                for report in reports {
+                    println!();
+                    print_report(report);
+                }
             }
         }
         println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
@@ -152,14 +187,159 @@ pub(super) fn run_article_lint(runtime: &RuntimeOptions, args: ArticleLintArgs)
         }
     }
 
-    if batch_report.total_errors > 0 || (args.strict && batch_report.total_warnings > 0) {
+    if aggregate.total_errors > 0 || (args.strict && aggregate.total_warnings > 0) {
         bail!(
             "article lint found {} error(s), {} warning(s), and {} suggestion(s) across {} file(s)",
-            batch_report.total_errors,
-            batch_report.total_warnings,
-            batch_report.total_suggestions,
-            batch_report.target_count
+            aggregate.total_errors,
+            aggregate.total_warnings,
+            aggregate.total_suggestions,
+            aggregate.target_count
         );
     }
     Ok(())
 }
+
+fn uses_tree_selectors(args: &ArticleLintArgs) -> bool {
+    !args.categories.is_empty() || !args.namespaces.is_empty()
+}
+
+fn uses_single_lint_path(args: &ArticleLintArgs) -> bool {
+    !uses_tree_selectors(args)
+        && uses_single_path_mode(
+            args.path.as_deref(),
+            &args.titles,
+            &args.paths,
+            args.titles_file.as_ref(),
+            args.changed,
+        )
+}
+
+fn lint_selection_from_args(args: &ArticleLintArgs) -> Result<ArticleTargetSelection> {
+    article_selection_from_args(
+        &args.titles,
+        &args.paths,
+        args.titles_file.as_ref(),
+        args.changed,
+        &args.categories,
+        &args.namespaces,
+    )
+}
+
+fn lint_jobs(requested: Option<usize>) -> Result<usize> {
+    match requested {
+        Some(0) => bail!("article lint requires --jobs >= 1"),
+        Some(jobs) => Ok(jobs),
+        None => Ok(std::thread::available_parallelism()
+            .map(NonZeroUsize::get)
+            .unwrap_or(1)),
+    }
+}
+
+/// Lint one page, counting inbound links only when the output shows quality
+/// metrics.
+fn lint_single_article(
+    paths: &ResolvedPaths,
+    article_path: &Path,
+    title_override: Option<&str>,
+    format: LintOutputFormat,
+) -> Result<ArticleLintReport> {
+    let mut resources = load_article_lint_resources(paths)?;
+    if !format.is_findings_only() {
+        resources = resources.with_inbound_links();
+    }
+    lint_article_with_resources(paths, article_path, title_override, &resources)
+}
+
+fn print_single_report(
+    runtime: &RuntimeOptions,
+    paths: &ResolvedPaths,
+    format: LintOutputFormat,
+    report: &ArticleLintReport,
+) -> Result<()> {
+    if format.is_findings_only() {
+        print_lint_findings(
+            format,
+            &paths.project_root,
+            &findings_from_article_reports(std::slice::from_ref(report)),
+        )?;
+    } else if format.is_json() {
+        println!("{}", serde_json::to_string_pretty(report)?);
+    } else {
+        println!("article lint");
+        println!("project_root: {}", normalize_path(&paths.project_root));
+        print_report(report);
+        println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+        if runtime.diagnostics {
+            println!("\n[diagnostics]\n{}", paths.diagnostics());
+        }
+    }
+    Ok(())
+}
+
+fn run_write_baseline(
+    runtime: &RuntimeOptions,
+    paths: &ResolvedPaths,
+    args: &ArticleLintArgs,
+) -> Result<()> {
+    if args.format.is_findings_only() {
+        bail!(
+            "article lint --write-baseline supports --format text|json, not {}",
+            args.format
+        );
+    }
+    if !uses_tree_selectors(args)
+        && single_state_path_title_override(
+            paths,
+            args.path.as_deref(),
+            &args.titles,
+            &args.paths,
+            args.titles_file.as_ref(),
+            args.changed,
+        )?
+        .is_some()
+    {
+        bail!("article lint --write-baseline does not accept state drafts");
+    }
+    let target_paths = if uses_single_lint_path(args) {
+        vec![resolve_article_selector_path(
+            paths,
+            args.path.as_deref().expect("single path"),
+        )?]
+    } else {
+        resolve_article_targets(
+            paths,
+            args.path.as_deref(),
+            &lint_selection_from_args(args)?,
+            false,
+        )?
+    };
+    let resources = load_article_lint_resources(paths)?.without_baseline();
+    let reports =
+        lint_articles_with_resources(paths, &target_paths, &resources, lint_jobs(args.jobs)?)?;
+    let report = ArticleLintBaselineReport {
+        project_root: normalize_path(&paths.project_root),
+        target_count: reports.len(),
+        baseline: write_lint_baseline(paths, &reports)?,
+    };
+
+    if args.format.is_json() {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("article lint");
+        println!("project_root: {}", report.project_root);
+        println!("mode: write-baseline");
+        println!("target_count: {}", report.target_count);
+        println!("baseline.path: {}", report.baseline.path);
+        println!("baseline.file_count: {}", report.baseline.file_count);
+        println!("baseline.issue_count: {}", report.baseline.issue_count);
+        println!(
+            "baseline.retained_file_count: {}",
+            report.baseline.retained_file_count
+        );
+        println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+        if runtime.diagnostics {
+            println!("\n[diagnostics]\n{}", paths.diagnostics());
+        }
+    }
+    Ok(())
+}
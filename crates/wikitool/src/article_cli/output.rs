@@ -13,6 +13,12 @@ pub(super) fn print_article_target_selection(selection: &ArticleTargetSelection)
     } else {
         println!("selection.paths: {}", selection.paths.join(" | "));
     }
+    if !selection.categories.is_empty() {
+        println!("selection.categories: {}", selection.categories.join(" | "));
+    }
+    if !selection.namespaces.is_empty() {
+        println!("selection.namespaces: {}", selection.namespaces.join(" | "));
+    }
 }
 
 pub(super) fn print_report(report: &ArticleLintReport) {
@@ -33,6 +39,25 @@ pub(super) fn print_report(report: &ArticleLintReport) {
     println!("errors: {}", report.errors);
     println!("warnings: {}", report.warnings);
     println!("suggestions: {}", report.suggestions);
+    println!("suppressed: {}", report.suppressed);
+    println!("baselined: {}", report.baselined);
+    let quality = &report.quality;
+    println!(
+        "quality: words={} lead={} refs={} refs_per_100_words={:.2} uncited_paragraphs={}/{} sections={} largest_section_share={:.2} images={} inbound={}",
+        quality.prose_words,
+        quality.lead_words,
+        quality.reference_count,
+        quality.references_per_100_words(),
+        quality.uncited_paragraphs,
+        quality.paragraph_count,
+        quality.section_count,
+        quality.largest_section_share(),
+        quality.image_count,
+        quality
+            .inbound_links
+            .map(|count| count.to_string())
+            .unwrap_or_else(|| "<none>".to_string())
+    );
     if report.issues.is_empty() {
         println!("issues: <none>");
         return;
@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result, bail};
 use serde::Serialize;
+use wikitool_core::article_lint::{ArticleLintTargetFilter, collect_article_lint_targets};
 use wikitool_core::filesystem::{
     relative_path_to_title, title_to_relative_path, validate_scoped_path,
 };
@@ -16,6 +17,19 @@ pub(super) struct ArticleTargetSelection {
     pub(super) changed: bool,
     pub(super) titles: Vec<String>,
     pub(super) paths: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(super) categories: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(super) namespaces: Vec<String>,
+}
+
+impl ArticleTargetSelection {
+    fn tree_filter(&self) -> ArticleLintTargetFilter {
+        ArticleLintTargetFilter {
+            namespaces: self.namespaces.clone(),
+            categories: self.categories.clone(),
+        }
+    }
 }
 
 pub(super) fn uses_single_path_mode(
@@ -75,6 +89,8 @@ pub(super) fn article_selection_from_args(
     paths: &[PathBuf],
     titles_file: Option<&PathBuf>,
     changed: bool,
+    categories: &[String],
+    namespaces: &[String],
 ) -> Result<ArticleTargetSelection> {
     let mut loaded_titles = titles.to_vec();
     if let Some(titles_file) = titles_file {
@@ -92,6 +108,8 @@ pub(super) fn article_selection_from_args(
         changed,
         titles: loaded_titles,
         paths: paths.iter().map(normalize_path).collect(),
+        categories: categories.to_vec(),
+        namespaces: namespaces.to_vec(),
     })
 }
 
@@ -128,8 +146,13 @@ pub(super) fn resolve_article_targets(
         }
     }
 
+    let tree_filter = selection.tree_filter();
+    if !tree_filter.is_empty() {
+        target_paths.extend(collect_article_lint_targets(paths, &tree_filter)?);
+    }
+
     if target_paths.is_empty() {
-        if selection.changed {
+        if selection.changed || !tree_filter.is_empty() {
             return Ok(Vec::new());
         }
         bail!("article command requires a file path, selector, or --changed");
@@ -158,7 +181,7 @@ fn resolve_article_title(
     bail!("no local article file found for title: {trimmed}")
 }
 
-fn resolve_article_selector_path(
+pub(super) fn resolve_article_selector_path(
     paths: &wikitool_core::runtime::ResolvedPaths,
     path: &Path,
 ) -> Result<String> {
@@ -6,6 +6,7 @@ use anyhow::{Context, Result};
 use clap::ValueEnum;
 use wikitool_core::config::{WikiConfig, load_config, wiki_target_warnings_for_config};
 use wikitool_core::filesystem::ScanStats;
+use wikitool_core::findings::{Finding, render_checkstyle, render_sarif};
 use wikitool_core::knowledge::content_index::StoredIndexStats;
 use wikitool_core::research::{ExportFormat, ExternalFetchFormat};
 use wikitool_core::runtime::{PathOverrides, ResolutionContext, ResolvedPaths, resolve_paths};
@@ -38,6 +39,58 @@ impl std::fmt::Display for OutputFormat {
     }
 }
 
+/// Output formats for lint-style commands. SARIF and checkstyle carry only the
+/// findings, for code-scanning UIs and editors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum LintOutputFormat {
+    Text,
+    Json,
+    Sarif,
+    Checkstyle,
+}
+
+impl LintOutputFormat {
+    pub(crate) fn is_json(self) -> bool {
+        self == Self::Json
+    }
+
+    pub(crate) fn is_findings_only(self) -> bool {
+        matches!(self, Self::Sarif | Self::Checkstyle)
+    }
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::Sarif => "sarif",
+            Self::Checkstyle => "checkstyle",
+        }
+    }
+}
+
+impl std::fmt::Display for LintOutputFormat {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// Print `findings` as SARIF or checkstyle; a no-op for text and JSON, which
+/// callers render themselves.
+pub(crate) fn print_lint_findings(
+    format: LintOutputFormat,
+    project_root: &Path,
+    findings: &[Finding],
+) -> Result<()> {
+    match format {
+        LintOutputFormat::Sarif => {
+            println!("{}", render_sarif("wikitool", project_root, findings)?)
+        }
+        LintOutputFormat::Checkstyle => print!("{}", render_checkstyle(findings)),
+        LintOutputFormat::Text | LintOutputFormat::Json => {}
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub(crate) enum FetchContentFormat {
     Wikitext,
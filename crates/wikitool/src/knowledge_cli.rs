@@ -19,9 +19,12 @@ use crate::knowledge_inspect_cli;
 mod article_start;
 mod build;
 mod contracts;
+mod diff;
+mod graph;
 mod interview;
 mod shared;
 mod status;
+mod translations;
 mod warm;
 
 pub(crate) use warm::run_knowledge_warm;
@@ -45,6 +48,12 @@ enum KnowledgeSubcommand {
     Contracts(KnowledgeContractsArgs),
     #[command(about = "Create, validate, show, and audit knowledge interview briefs")]
     Interview(interview::KnowledgeInterviewArgs),
+    #[command(about = "Export indexed page graphs for external visualization")]
+    Graph(graph::KnowledgeGraphArgs),
+    #[command(about = "Report structural changes between recorded index builds")]
+    Diff(diff::KnowledgeDiffArgs),
+    #[command(about = "Track translation subpages against changes to their base pages")]
+    Translations(translations::KnowledgeTranslationsArgs),
     #[command(about = "Inspect indexed knowledge structures directly")]
     Inspect(knowledge_inspect_cli::KnowledgeInspectArgs),
 }
@@ -404,6 +413,11 @@ pub(crate) fn run_knowledge(runtime: &RuntimeOptions, args: KnowledgeArgs) -> Re
         }
         KnowledgeSubcommand::Contracts(args) => contracts::run_knowledge_contracts(runtime, args),
         KnowledgeSubcommand::Interview(args) => interview::run_knowledge_interview(runtime, args),
+        KnowledgeSubcommand::Graph(args) => graph::run_knowledge_graph(runtime, args),
+        KnowledgeSubcommand::Diff(args) => diff::run_knowledge_diff(runtime, args),
+        KnowledgeSubcommand::Translations(args) => {
+            translations::run_knowledge_translations(runtime, args)
+        }
         KnowledgeSubcommand::Inspect(args) => {
             knowledge_inspect_cli::run_knowledge_inspect(runtime, args)
         }
@@ -35,6 +35,9 @@ pub(super) fn run_knowledge_build(
     println!("rebuild.unchanged: {}", report.rebuild.unchanged);
     println!("rebuild.inserted_rows: {}", report.rebuild.inserted_rows);
     println!("rebuild.inserted_links: {}", report.rebuild.inserted_links);
+    if let Some(build_id) = report.rebuild.build_id {
+        println!("rebuild.build_id: {build_id}");
+    }
     print_scan_stats("scan", &report.rebuild.scan);
     print_knowledge_status("knowledge", &report.status);
     print_database_schema_status(&paths);
@@ -0,0 +1,151 @@
+use anyhow::Result;
+use clap::Args;
+use serde::Serialize;
+use wikitool_core::knowledge::snapshots::{
+    IndexBuildSummary, IndexDiffOptions, IndexDiffReport, IndexDiffSection, IndexLinkChange,
+    diff_index_builds, list_index_builds,
+};
+
+use crate::cli_support::{OutputFormat, normalize_path, resolve_runtime_paths};
+use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
+
+#[derive(Debug, Args)]
+pub(crate) struct KnowledgeDiffArgs {
+    #[arg(
+        long,
+        value_name = "BUILD",
+        help = "Older build id (defaults to the build before --until)"
+    )]
+    since: Option<i64>,
+    #[arg(
+        long,
+        value_name = "BUILD",
+        help = "Newer build id (defaults to the latest build)"
+    )]
+    until: Option<i64>,
+    #[arg(long, help = "List recorded builds instead of diffing")]
+    list: bool,
+    #[arg(
+        long,
+        default_value_t = 50,
+        value_name = "N",
+        help = "Maximum items listed per change kind"
+    )]
+    limit: usize,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct KnowledgeDiffOutput {
+    project_root: String,
+    index_ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    builds: Option<Vec<IndexBuildSummary>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diff: Option<IndexDiffReport>,
+}
+
+pub(super) fn run_knowledge_diff(runtime: &RuntimeOptions, args: KnowledgeDiffArgs) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let (index_ready, builds, diff) = if args.list {
+        let builds = list_index_builds(&paths)?;
+        (builds.is_some(), builds, None)
+    } else {
+        let options = IndexDiffOptions {
+            since: args.since,
+            until: args.until,
+            limit: args.limit,
+        };
+        let diff = diff_index_builds(&paths, &options)?;
+        (diff.is_some(), None, diff)
+    };
+
+    if args.format.is_json() {
+        let output = KnowledgeDiffOutput {
+            project_root: normalize_path(&paths.project_root),
+            index_ready,
+            builds,
+            diff,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("knowledge diff");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!("mode: report-only");
+    if let Some(builds) = &builds {
+        println!("builds.count: {}", builds.len());
+        for build in builds {
+            println!("builds.item: {}", format_build(build));
+        }
+    }
+    if let Some(diff) = &diff {
+        print_diff(diff);
+    }
+    if !index_ready {
+        println!("knowledge.diff.storage: <not built> (run `wikitool knowledge build`)");
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
+
+fn print_diff(diff: &IndexDiffReport) {
+    println!("diff.from: {}", format_build(&diff.from_build));
+    println!("diff.to: {}", format_build(&diff.to_build));
+    for count in &diff.validation_counts {
+        println!(
+            "diff.count.{}: {} -> {} ({:+})",
+            count.name,
+            count.before,
+            count.after,
+            count.after as i64 - count.before as i64
+        );
+    }
+    print_title_section("added_pages", &diff.added_pages);
+    print_title_section("removed_pages", &diff.removed_pages);
+    print_link_section("added_links", &diff.added_links);
+    print_link_section("removed_links", &diff.removed_links);
+    print_link_section("new_red_links", &diff.new_red_links);
+    print_link_section("resolved_red_links", &diff.resolved_red_links);
+    print_link_section("added_categories", &diff.added_categories);
+    print_link_section("removed_categories", &diff.removed_categories);
+    print_title_section("new_orphans", &diff.new_orphans);
+    print_title_section("resolved_orphans", &diff.resolved_orphans);
+    print_title_section("new_uncategorized", &diff.new_uncategorized);
+    println!("diff.reference_changes: {}", diff.reference_changes.total);
+    for change in &diff.reference_changes.items {
+        println!("  {}: {} -> {}", change.name, change.before, change.after);
+    }
+}
+
+fn print_title_section(name: &str, section: &IndexDiffSection<String>) {
+    println!("diff.{name}: {}", section.total);
+    for title in &section.items {
+        println!("  {title}");
+    }
+}
+
+fn print_link_section(name: &str, section: &IndexDiffSection<IndexLinkChange>) {
+    println!("diff.{name}: {}", section.total);
+    for change in &section.items {
+        println!("  {} -> {}", change.source_title, change.target_title);
+    }
+}
+
+fn format_build(build: &IndexBuildSummary) -> String {
+    format!(
+        "#{} {} pages={} links={} references={}",
+        build.build_id, build.built_at, build.page_count, build.link_count, build.reference_count
+    )
+}
@@ -0,0 +1,203 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, bail};
+use clap::{Args, Subcommand, ValueEnum};
+use wikitool_core::graph::{EdgeKind, GraphExportFormat, GraphKind, render_graph};
+use wikitool_core::knowledge::graph_export::{GraphExportOptions, build_graph_export};
+
+use crate::RuntimeOptions;
+use crate::cli_support::{normalize_path, resolve_runtime_paths};
+
+#[derive(Debug, Args)]
+pub(crate) struct KnowledgeGraphArgs {
+    #[command(subcommand)]
+    command: KnowledgeGraphSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum KnowledgeGraphSubcommand {
+    #[command(about = "Export an indexed page graph as DOT, GraphML, or JSON")]
+    Export(KnowledgeGraphExportArgs),
+}
+
+#[derive(Debug, Args)]
+struct KnowledgeGraphExportArgs {
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = GraphKindArg::Links,
+        value_name = "KIND",
+        help = "Graph kind: links|categories|transclusion|redirects"
+    )]
+    kind: GraphKindArg,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = GraphFormatArg::Dot,
+        value_name = "FORMAT",
+        help = "Export format: dot|graphml|json"
+    )]
+    format: GraphFormatArg,
+    #[arg(
+        long = "namespace",
+        value_name = "NAMESPACE",
+        help = "Keep only nodes and edges within this namespace (repeatable)"
+    )]
+    namespaces: Vec<String>,
+    #[arg(
+        long = "edge-kind",
+        value_enum,
+        value_name = "KIND",
+        help = "Keep only this edge kind (repeatable): link|category|redirect|template|module"
+    )]
+    edge_kinds: Vec<EdgeKindArg>,
+    #[arg(
+        long,
+        value_name = "TITLE",
+        help = "Export only the neighborhood around this title"
+    )]
+    ego: Option<String>,
+    #[arg(
+        long,
+        default_value_t = 1,
+        value_name = "N",
+        help = "Hop limit for --ego extraction, following edges in either direction"
+    )]
+    depth: usize,
+    #[arg(long, help = "Keep nodes with no edges in whole-graph exports")]
+    include_isolated: bool,
+    #[arg(long, help = "Keep self-loop edges (dropped by default)")]
+    self_loops: bool,
+    #[arg(
+        short = 'o',
+        long,
+        value_name = "PATH",
+        help = "Write the export to a file instead of stdout"
+    )]
+    output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum GraphKindArg {
+    Links,
+    Categories,
+    Transclusion,
+    Redirects,
+}
+
+impl GraphKindArg {
+    fn as_graph_kind(self) -> GraphKind {
+        match self {
+            Self::Links => GraphKind::ArticleLinksFiltered,
+            Self::Categories => GraphKind::Categories,
+            Self::Transclusion => GraphKind::Transclusion,
+            Self::Redirects => GraphKind::Redirects,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum GraphFormatArg {
+    Dot,
+    #[value(name = "graphml")]
+    GraphMl,
+    Json,
+}
+
+impl GraphFormatArg {
+    fn as_export_format(self) -> GraphExportFormat {
+        match self {
+            Self::Dot => GraphExportFormat::Dot,
+            Self::GraphMl => GraphExportFormat::GraphMl,
+            Self::Json => GraphExportFormat::Json,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum EdgeKindArg {
+    Link,
+    Category,
+    Redirect,
+    Template,
+    Module,
+}
+
+impl EdgeKindArg {
+    fn as_edge_kind(self) -> EdgeKind {
+        match self {
+            Self::Link => EdgeKind::Link,
+            Self::Category => EdgeKind::CategoryMembership,
+            Self::Redirect => EdgeKind::Redirect,
+            Self::Template => EdgeKind::TemplateTransclusion,
+            Self::Module => EdgeKind::ModuleInvocation,
+        }
+    }
+}
+
+pub(crate) fn run_knowledge_graph(
+    runtime: &RuntimeOptions,
+    args: KnowledgeGraphArgs,
+) -> Result<()> {
+    match args.command {
+        KnowledgeGraphSubcommand::Export(args) => run_knowledge_graph_export(runtime, args),
+    }
+}
+
+fn run_knowledge_graph_export(
+    runtime: &RuntimeOptions,
+    args: KnowledgeGraphExportArgs,
+) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let mut options = GraphExportOptions::new(args.kind.as_graph_kind());
+    if !args.namespaces.is_empty() {
+        options.filter.include_namespaces = Some(args.namespaces.into_iter().collect());
+    }
+    if !args.edge_kinds.is_empty() {
+        options.filter.include_edge_kinds = Some(
+            args.edge_kinds
+                .into_iter()
+                .map(EdgeKindArg::as_edge_kind)
+                .collect(),
+        );
+    }
+    options.filter.exclude_self_loops = !args.self_loops;
+    options.ego_title = args.ego;
+    options.ego_depth = args.depth;
+    options.include_isolated = args.include_isolated;
+
+    let Some(graph) = build_graph_export(&paths, &options)? else {
+        bail!("knowledge index is not built (run `wikitool knowledge build`)");
+    };
+    let rendered = render_graph(&graph, args.format.as_export_format())?;
+
+    match args.output {
+        Some(path) => {
+            if let Some(parent) = path.parent()
+                && !parent.as_os_str().is_empty()
+            {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("failed to create {}", normalize_path(parent)))?;
+            }
+            fs::write(&path, rendered)
+                .with_context(|| format!("failed to write {}", normalize_path(&path)))?;
+            println!("knowledge graph export");
+            println!("project_root: {}", normalize_path(&paths.project_root));
+            println!("graph.kind: {}", graph.kind.as_str());
+            println!("graph.nodes: {}", graph.node_count());
+            println!("graph.edges: {}", graph.edge_count());
+            println!("output_path: {}", normalize_path(&path));
+            if runtime.diagnostics {
+                println!("\n[diagnostics]\n{}", paths.diagnostics());
+            }
+        }
+        None => {
+            print!("{rendered}");
+            if !rendered.ends_with('\n') {
+                println!();
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,233 @@
+use anyhow::{Result, bail};
+use clap::{Args, Subcommand};
+use serde::Serialize;
+use wikitool_core::knowledge::translations::{
+    TranslationStatusOptions, TranslationStatusReport, TranslationSyncMark, TranslationSyncStatus,
+    mark_translation_synced, query_translation_status,
+};
+
+use crate::cli_support::{OutputFormat, normalize_path, resolve_runtime_paths};
+use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
+
+#[derive(Debug, Args)]
+pub(crate) struct KnowledgeTranslationsArgs {
+    #[command(subcommand)]
+    command: KnowledgeTranslationsSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum KnowledgeTranslationsSubcommand {
+    #[command(about = "Report translations whose base page changed since they were last synced")]
+    Status(TranslationsStatusArgs),
+    #[command(about = "Mark translations as synced against the current base page text")]
+    Mark(TranslationsMarkArgs),
+}
+
+#[derive(Debug, Args)]
+struct TranslationsStatusArgs {
+    #[arg(
+        value_name = "TITLE",
+        help = "Translation page, or base page to report all of its translations"
+    )]
+    title: Option<String>,
+    #[arg(long, help = "Also list current, unmarked, and orphaned translations")]
+    all: bool,
+    #[arg(long, help = "Omit base-page diffs and report line counts only")]
+    no_diff: bool,
+    #[arg(
+        long,
+        default_value_t = 50,
+        value_name = "N",
+        help = "Maximum translations to list"
+    )]
+    limit: usize,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Args)]
+struct TranslationsMarkArgs {
+    #[arg(
+        value_name = "TITLE",
+        help = "Translation pages to mark (e.g. `Page/fr`)"
+    )]
+    titles: Vec<String>,
+    #[arg(
+        long,
+        help = "Mark every translation that has no sync marker yet (adoption baseline)"
+    )]
+    unmarked: bool,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct TranslationsStatusOutput {
+    project_root: String,
+    index_ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    translations: Option<TranslationStatusReport>,
+}
+
+#[derive(Debug, Serialize)]
+struct TranslationsMarkOutput {
+    project_root: String,
+    marked: Vec<TranslationSyncMark>,
+}
+
+pub(super) fn run_knowledge_translations(
+    runtime: &RuntimeOptions,
+    args: KnowledgeTranslationsArgs,
+) -> Result<()> {
+    match args.command {
+        KnowledgeTranslationsSubcommand::Status(args) => run_translations_status(runtime, args),
+        KnowledgeTranslationsSubcommand::Mark(args) => run_translations_mark(runtime, args),
+    }
+}
+
+fn run_translations_status(runtime: &RuntimeOptions, args: TranslationsStatusArgs) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let options = TranslationStatusOptions {
+        title: args.title,
+        include_all: args.all,
+        include_diff: !args.no_diff,
+        limit: args.limit,
+    };
+    let report = query_translation_status(&paths, &options)?;
+
+    if args.format.is_json() {
+        let output = TranslationsStatusOutput {
+            project_root: normalize_path(&paths.project_root),
+            index_ready: report.is_some(),
+            translations: report,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("knowledge translations status");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!("mode: report-only");
+    match report {
+        Some(report) => {
+            println!("translations.count: {}", report.translation_count);
+            println!("translations.outdated: {}", report.outdated_count);
+            println!("translations.unmarked: {}", report.unmarked_count);
+            println!("translations.missing_base: {}", report.missing_base_count);
+            println!("translations.current: {}", report.current_count);
+            if report.entries.is_empty() {
+                println!("translations.items: <none>");
+            }
+            for entry in &report.entries {
+                println!(
+                    "translations.item: {} | base={} | {} | +{} -{}",
+                    entry.title,
+                    entry.base_title,
+                    entry.status.as_str(),
+                    entry.lines_added,
+                    entry.lines_removed
+                );
+                if entry.status == TranslationSyncStatus::Outdated {
+                    println!(
+                        "  synced_revision: {} -> base_revision: {}",
+                        format_revision(entry.synced_revision),
+                        format_revision(entry.base_revision)
+                    );
+                }
+                if let Some(reason) = &entry.diff_unavailable {
+                    println!("  diff: <unavailable> ({reason})");
+                }
+                if let Some(diff) = &entry.diff {
+                    for line in diff.lines() {
+                        println!("  {line}");
+                    }
+                }
+            }
+        }
+        None => {
+            println!(
+                "knowledge.translations.storage: <not built> (run `wikitool knowledge build`)"
+            );
+        }
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
+
+fn run_translations_mark(runtime: &RuntimeOptions, args: TranslationsMarkArgs) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let mut titles = args.titles;
+    if args.unmarked {
+        let options = TranslationStatusOptions {
+            include_all: true,
+            include_diff: false,
+            limit: usize::MAX,
+            ..TranslationStatusOptions::default()
+        };
+        let Some(report) = query_translation_status(&paths, &options)? else {
+            bail!("knowledge index is not built (run `wikitool knowledge build`)");
+        };
+        titles.extend(
+            report
+                .entries
+                .into_iter()
+                .filter(|entry| entry.status == TranslationSyncStatus::Unmarked)
+                .map(|entry| entry.title),
+        );
+    }
+    if titles.is_empty() {
+        bail!("pass translation titles to mark, or --unmarked");
+    }
+    let marked = titles
+        .iter()
+        .map(|title| mark_translation_synced(&paths, title))
+        .collect::<Result<Vec<_>>>()?;
+
+    if args.format.is_json() {
+        let output = TranslationsMarkOutput {
+            project_root: normalize_path(&paths.project_root),
+            marked,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("knowledge translations mark");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!("marked.count: {}", marked.len());
+    for mark in &marked {
+        println!(
+            "marked.item: {} | base={} | revision={} | hash={}{}",
+            mark.title,
+            mark.base_title,
+            format_revision(mark.base_revision),
+            mark.base_hash,
+            if mark.changed { "" } else { " | unchanged" }
+        );
+    }
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
+
+fn format_revision(revision: Option<i64>) -> String {
+    revision
+        .map(|revision| revision.to_string())
+        .unwrap_or_else(|| "local".to_string())
+}
@@ -6,10 +6,19 @@ use crate::briefs::BriefView;
 use crate::cli_support::OutputFormat;
 
 mod backlinks;
+mod categories;
+mod centrality;
 mod chunks;
+mod consistency;
+mod facts;
 mod pages;
+mod quality;
 mod references;
+mod stale;
 mod templates;
+mod unreliable;
+mod unused;
+mod wanted;
 #[derive(Debug, Args)]
 pub(crate) struct KnowledgeInspectArgs {
     #[command(subcommand)]
@@ -132,6 +141,24 @@ enum KnowledgeInspectSubcommand {
         )]
         format: OutputFormat,
     },
+    /// Analyze category tree depth, sizing, and redundant categorization
+    Categories(categories::CategoryInspectArgs),
+    /// Rank pages by link-graph PageRank and HITS hub/authority scores
+    Centrality(centrality::CentralityInspectArgs),
+    /// Compare infobox values with other infoboxes and lead mentions of the same subject
+    Consistency(consistency::ConsistencyInspectArgs),
+    /// Query typed infobox parameter values across indexed pages
+    Facts(facts::FactsInspectArgs),
+    /// Sort and filter per-article quality metrics computed at build time
+    Quality(quality::QualityInspectArgs),
+    /// Rank pages by dated and time-relative statements that have gone stale
+    Stale(stale::StaleInspectArgs),
+    /// Count pages citing each source the style guide lists as unreliable
+    Unreliable(unreliable::UnreliableInspectArgs),
+    /// List Template, Module and File pages no content page uses, even transitively
+    Unused(unused::UnusedInspectArgs),
+    /// Rank missing link targets by inbound links and show where they are mentioned
+    Wanted(wanted::WantedInspectArgs),
     #[command(name = "empty-categories")]
     /// Show categories with no indexed members
     EmptyCategories {
@@ -191,6 +218,23 @@ pub(crate) fn run_knowledge_inspect(
         KnowledgeInspectSubcommand::Orphans { format } => {
             pages::run_inspect_orphans(runtime, format)
         }
+        KnowledgeInspectSubcommand::Categories(args) => {
+            categories::run_inspect_categories(runtime, args)
+        }
+        KnowledgeInspectSubcommand::Centrality(args) => {
+            centrality::run_inspect_centrality(runtime, args)
+        }
+        KnowledgeInspectSubcommand::Consistency(args) => {
+            consistency::run_inspect_consistency(runtime, args)
+        }
+        KnowledgeInspectSubcommand::Facts(args) => facts::run_inspect_facts(runtime, args),
+        KnowledgeInspectSubcommand::Quality(args) => quality::run_inspect_quality(runtime, args),
+        KnowledgeInspectSubcommand::Stale(args) => stale::run_inspect_stale(runtime, args),
+        KnowledgeInspectSubcommand::Unreliable(args) => {
+            unreliable::run_inspect_unreliable(runtime, args)
+        }
+        KnowledgeInspectSubcommand::Unused(args) => unused::run_inspect_unused(runtime, args),
+        KnowledgeInspectSubcommand::Wanted(args) => wanted::run_inspect_wanted(runtime, args),
         KnowledgeInspectSubcommand::EmptyCategories { format } => {
             pages::run_inspect_empty_categories(runtime, format)
         }
@@ -0,0 +1,196 @@
+use anyhow::Result;
+use clap::Args;
+use serde::Serialize;
+use wikitool_core::knowledge::categories::{
+    CategoryTreeOptions, CategoryTreeReport, inspect_category_tree,
+};
+use wikitool_core::profile::load_or_build_remilia_profile_overlay;
+
+use crate::cli_support::{OutputFormat, normalize_path, resolve_runtime_paths};
+use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
+
+#[derive(Debug, Args)]
+pub(crate) struct CategoryInspectArgs {
+    #[arg(
+        long = "root",
+        value_name = "CATEGORY",
+        help = "Root category of the preferred tree (repeatable; defaults to profile preferred categories)"
+    )]
+    roots: Vec<String>,
+    #[arg(
+        long,
+        default_value_t = 200,
+        value_name = "N",
+        help = "Flag categories with more than N direct page members (0 disables)"
+    )]
+    oversized: usize,
+    #[arg(
+        long,
+        default_value_t = 2,
+        value_name = "N",
+        help = "Flag categories with fewer than N members including subcategories (0 disables)"
+    )]
+    undersized: usize,
+    #[arg(
+        long,
+        default_value_t = 50,
+        value_name = "N",
+        help = "Maximum entries to return per report section"
+    )]
+    limit: usize,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct InspectCategoriesReport {
+    project_root: String,
+    index_ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category_tree: Option<CategoryTreeReport>,
+}
+
+pub(super) fn run_inspect_categories(
+    runtime: &RuntimeOptions,
+    args: CategoryInspectArgs,
+) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let root_categories = if args.roots.is_empty() {
+        load_or_build_remilia_profile_overlay(&paths)?
+            .categories
+            .preferred_categories
+    } else {
+        args.roots
+    };
+    let options = CategoryTreeOptions {
+        root_categories,
+        oversized_threshold: args.oversized,
+        undersized_threshold: args.undersized,
+        limit: args.limit,
+    };
+    let report = inspect_category_tree(&paths, &options)?;
+
+    if args.format.is_json() {
+        let output = InspectCategoriesReport {
+            project_root: normalize_path(&paths.project_root),
+            index_ready: report.is_some(),
+            category_tree: report,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("knowledge inspect categories");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!("mode: report-only");
+    match report {
+        Some(report) => print_category_tree(&report),
+        None => {
+            println!("knowledge.inspect.storage: <not built> (run `wikitool knowledge build`)");
+        }
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
+
+fn print_category_tree(report: &CategoryTreeReport) {
+    println!(
+        "categories.roots: {}",
+        join_or_none(&report.root_categories)
+    );
+    println!("categories.roots_inferred: {}", report.inferred_roots);
+    println!(
+        "categories.missing_roots: {}",
+        join_or_none(&report.missing_root_categories)
+    );
+    println!("categories.count: {}", report.category_count);
+    println!("categories.max_depth: {}", report.max_depth);
+    println!(
+        "categories.unreachable: {}",
+        report.unreachable_category_count
+    );
+    for node in &report.categories {
+        println!(
+            "category: {} depth={} pages={} subcategories={} chain={}",
+            node.title,
+            node.depth
+                .map_or_else(|| "<unreachable>".to_string(), |depth| depth.to_string()),
+            node.page_member_count,
+            node.subcategory_count,
+            if node.parent_chain.is_empty() {
+                format!("<none> parents={}", join_or_none(&node.parent_categories))
+            } else {
+                node.parent_chain.join(" > ")
+            }
+        );
+    }
+
+    println!(
+        "categories.oversized: {} (threshold > {})",
+        report.oversized_count, report.oversized_threshold
+    );
+    for issue in &report.oversized_categories {
+        println!(
+            "oversized_category: {} pages={} subcategories={}",
+            issue.title, issue.page_member_count, issue.subcategory_count
+        );
+    }
+    println!(
+        "categories.undersized: {} (threshold < {})",
+        report.undersized_count, report.undersized_threshold
+    );
+    for issue in &report.undersized_categories {
+        println!(
+            "undersized_category: {} pages={} subcategories={}",
+            issue.title, issue.page_member_count, issue.subcategory_count
+        );
+    }
+    println!("categories.redundant: {}", report.redundant_count);
+    for issue in &report.redundant_categories {
+        println!(
+            "redundant_category: {} pages={} covered_by={}",
+            issue.title,
+            issue.page_member_count,
+            join_or_none(&issue.covering_subcategories)
+        );
+    }
+    println!(
+        "categories.pages_outside_root_tree: {}",
+        report.pages_outside_root_tree_count
+    );
+    for page in &report.pages_outside_root_tree {
+        println!(
+            "outside_page: {} categories={}",
+            page.title,
+            join_or_none(&page.categories)
+        );
+    }
+    println!(
+        "categories.pages_under_missing_categories: {}",
+        report.pages_under_missing_categories_count
+    );
+    for page in &report.pages_under_missing_categories {
+        println!(
+            "uncategorized_parent_page: {} categories={}",
+            page.title,
+            join_or_none(&page.categories)
+        );
+    }
+}
+
+fn join_or_none(values: &[String]) -> String {
+    if values.is_empty() {
+        "<none>".to_string()
+    } else {
+        values.join(" | ")
+    }
+}
@@ -0,0 +1,127 @@
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+use wikitool_core::knowledge::centrality::{
+    PageCentralityOptions, PageCentralityReport, PageCentralitySort, query_page_centrality,
+};
+
+use crate::cli_support::{OutputFormat, normalize_path, resolve_runtime_paths};
+use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
+
+#[derive(Debug, Args)]
+pub(crate) struct CentralityInspectArgs {
+    #[arg(value_name = "TITLE", help = "Show scores and rank for one page")]
+    title: Option<String>,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = CentralitySortArg::Pagerank,
+        value_name = "SCORE",
+        help = "Sort by: pagerank|hub|authority"
+    )]
+    sort: CentralitySortArg,
+    #[arg(
+        long,
+        default_value = "Main",
+        value_name = "NAMESPACE",
+        help = "Namespace to rank within"
+    )]
+    namespace: String,
+    #[arg(long, help = "Rank across all namespaces")]
+    all_namespaces: bool,
+    #[arg(
+        long,
+        default_value_t = 20,
+        value_name = "N",
+        help = "Maximum pages to return"
+    )]
+    limit: usize,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum CentralitySortArg {
+    Pagerank,
+    Hub,
+    Authority,
+}
+
+impl CentralitySortArg {
+    fn as_sort(self) -> PageCentralitySort {
+        match self {
+            Self::Pagerank => PageCentralitySort::Pagerank,
+            Self::Hub => PageCentralitySort::Hub,
+            Self::Authority => PageCentralitySort::Authority,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct InspectCentralityReport {
+    project_root: String,
+    index_ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    centrality: Option<PageCentralityReport>,
+}
+
+pub(super) fn run_inspect_centrality(
+    runtime: &RuntimeOptions,
+    args: CentralityInspectArgs,
+) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let options = PageCentralityOptions {
+        title: args.title,
+        namespace: (!args.all_namespaces).then_some(args.namespace),
+        sort: args.sort.as_sort(),
+        limit: args.limit,
+    };
+    let report = query_page_centrality(&paths, &options)?;
+
+    if args.format.is_json() {
+        let output = InspectCentralityReport {
+            project_root: normalize_path(&paths.project_root),
+            index_ready: report.is_some(),
+            centrality: report,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("knowledge inspect centrality");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!("mode: report-only");
+    match report {
+        Some(report) => {
+            println!("centrality.sort: {}", report.sort.as_str());
+            println!(
+                "centrality.namespace: {}",
+                report.namespace.as_deref().unwrap_or("<all>")
+            );
+            println!("centrality.ranked_pages: {}", report.page_count);
+            if report.pages.is_empty() {
+                println!("centrality.pages: <none>");
+            }
+            for page in &report.pages {
+                println!(
+                    "centrality.page: #{} {} pagerank={:.6} hub={:.6} authority={:.6}",
+                    page.rank, page.title, page.pagerank, page.hub_score, page.authority_score
+                );
+            }
+        }
+        None => {
+            println!("knowledge.inspect.storage: <not built> (run `wikitool knowledge build`)");
+        }
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
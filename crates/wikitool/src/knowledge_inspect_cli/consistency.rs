@@ -0,0 +1,114 @@
+use anyhow::Result;
+use clap::Args;
+use serde::Serialize;
+use wikitool_core::knowledge::consistency::{
+    FactConsistencyOptions, FactConsistencyReport, FactLocation, check_fact_consistency,
+};
+
+use crate::cli_support::{OutputFormat, normalize_path, resolve_runtime_paths};
+use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
+
+#[derive(Debug, Args)]
+pub(crate) struct ConsistencyInspectArgs {
+    #[arg(value_name = "TITLE", help = "Check only this page's infobox")]
+    title: Option<String>,
+    #[arg(
+        long,
+        help = "Compare only against other infoboxes, not lead-sentence mentions"
+    )]
+    no_leads: bool,
+    #[arg(
+        long,
+        default_value_t = 50,
+        value_name = "N",
+        help = "Maximum conflicts to return"
+    )]
+    limit: usize,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct InspectConsistencyReport {
+    project_root: String,
+    index_ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    consistency: Option<FactConsistencyReport>,
+}
+
+pub(super) fn run_inspect_consistency(
+    runtime: &RuntimeOptions,
+    args: ConsistencyInspectArgs,
+) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let options = FactConsistencyOptions {
+        title: args.title,
+        include_leads: !args.no_leads,
+        limit: args.limit,
+    };
+    let report = check_fact_consistency(&paths, &options)?;
+
+    if args.format.is_json() {
+        let output = InspectConsistencyReport {
+            project_root: normalize_path(&paths.project_root),
+            index_ready: report.is_some(),
+            consistency: report,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("knowledge inspect consistency");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!("mode: report-only");
+    match report {
+        Some(report) => {
+            println!("consistency.subjects: {}", report.subject_count);
+            println!("consistency.compared_facts: {}", report.compared_fact_count);
+            println!("consistency.conflicts: {}", report.conflict_count);
+            if report.conflicts.is_empty() {
+                println!("consistency.items: <none>");
+            }
+            for conflict in &report.conflicts {
+                println!(
+                    "consistency.conflict: {} | {} ({}) | {} vs {}",
+                    conflict.subject_title,
+                    conflict.parameter_key,
+                    conflict.value_kind,
+                    conflict.canonical_value,
+                    conflict.other_value
+                );
+                println!(
+                    "  canonical: {}",
+                    format_location(&conflict.canonical_location)
+                );
+                println!("  other: {}", format_location(&conflict.other_location));
+            }
+        }
+        None => {
+            println!("knowledge.inspect.storage: <not built> (run `wikitool knowledge build`)");
+        }
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
+
+fn format_location(location: &FactLocation) -> String {
+    let line = location
+        .line
+        .map(|line| format!(":{line}"))
+        .unwrap_or_default();
+    format!(
+        "{}{} [{}] {}",
+        location.page_title, line, location.source, location.evidence
+    )
+}
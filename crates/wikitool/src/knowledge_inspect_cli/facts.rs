@@ -0,0 +1,161 @@
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+use wikitool_core::knowledge::facts::{InfoboxFactOptions, InfoboxFactReport, query_infobox_facts};
+
+use crate::cli_support::{OutputFormat, normalize_path, resolve_runtime_paths};
+use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
+
+#[derive(Debug, Args)]
+pub(crate) struct FactsInspectArgs {
+    #[arg(
+        long,
+        value_name = "TEMPLATE",
+        help = "Infobox template or module title (Template: prefix optional)"
+    )]
+    template: Option<String>,
+    #[arg(long, value_name = "PARAM", help = "Infobox parameter name")]
+    param: Option<String>,
+    #[arg(long, value_name = "TITLE", help = "Only facts from this page")]
+    title: Option<String>,
+    #[arg(
+        long,
+        value_name = "TEXT",
+        help = "Only facts whose normalized value contains this text"
+    )]
+    value: Option<String>,
+    #[arg(
+        long,
+        value_enum,
+        value_name = "KIND",
+        help = "Only facts of this value kind: date|number|link|url|text"
+    )]
+    kind: Option<FactKindArg>,
+    #[arg(
+        long,
+        help = "List pages using --template without a non-empty --param instead of facts"
+    )]
+    missing: bool,
+    #[arg(
+        long,
+        default_value_t = 100,
+        value_name = "N",
+        help = "Maximum facts or pages to return"
+    )]
+    limit: usize,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum FactKindArg {
+    Date,
+    Number,
+    Link,
+    Url,
+    Text,
+}
+
+impl FactKindArg {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Date => "date",
+            Self::Number => "number",
+            Self::Link => "link",
+            Self::Url => "url",
+            Self::Text => "text",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct InspectFactsReport {
+    project_root: String,
+    index_ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    facts: Option<InfoboxFactReport>,
+}
+
+pub(super) fn run_inspect_facts(runtime: &RuntimeOptions, args: FactsInspectArgs) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let options = InfoboxFactOptions {
+        template: args.template,
+        param: args.param,
+        title: args.title,
+        value: args.value,
+        value_kind: args.kind.map(|kind| kind.as_str().to_string()),
+        missing: args.missing,
+        limit: args.limit,
+    };
+    let report = query_infobox_facts(&paths, &options)?;
+
+    if args.format.is_json() {
+        let output = InspectFactsReport {
+            project_root: normalize_path(&paths.project_root),
+            index_ready: report.is_some(),
+            facts: report,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("knowledge inspect facts");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!("mode: report-only");
+    match report {
+        Some(report) => {
+            println!(
+                "facts.template: {}",
+                report.template.as_deref().unwrap_or("<all>")
+            );
+            println!(
+                "facts.param: {}",
+                report.param.as_deref().unwrap_or("<all>")
+            );
+            for template in &report.templates {
+                println!(
+                    "facts.template_usage: {} pages={} facts={}",
+                    template.template_title, template.page_count, template.fact_count
+                );
+            }
+            if report.missing {
+                println!("facts.missing_count: {}", report.missing_count);
+                if report.missing_pages.is_empty() {
+                    println!("facts.missing_pages: <none>");
+                }
+                for title in &report.missing_pages {
+                    println!("facts.missing_page: {title}");
+                }
+            } else {
+                println!("facts.count: {}", report.fact_count);
+                if report.facts.is_empty() {
+                    println!("facts.items: <none>");
+                }
+                for fact in &report.facts {
+                    println!(
+                        "facts.item: {} | {} | {} = {} ({})",
+                        fact.page_title,
+                        fact.template_title,
+                        fact.parameter_key,
+                        fact.normalized_value,
+                        fact.value_kind
+                    );
+                }
+            }
+        }
+        None => {
+            println!("knowledge.inspect.storage: <not built> (run `wikitool knowledge build`)");
+        }
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use anyhow::Result;
+use clap::Args;
+use serde::Serialize;
+use wikitool_core::knowledge::quality::{
+    PageQualityMetric, PageQualityMetrics, PageQualityOptions, PageQualityReport,
+    PageQualityThreshold, query_page_quality,
+};
+
+use crate::cli_support::{OutputFormat, normalize_path, resolve_runtime_paths};
+use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
+
+const METRIC_NAMES: &str = "prose_words|reference_count|references_per_100_words|uncited_paragraphs|section_count|largest_section_share|lead_words|image_count|inbound_links";
+
+#[derive(Debug, Args)]
+pub(crate) struct QualityInspectArgs {
+    #[arg(value_name = "TITLE", help = "Show metrics for one page")]
+    title: Option<String>,
+    #[arg(
+        long,
+        default_value = "prose_words",
+        value_parser = parse_metric,
+        value_name = "METRIC",
+        help = "Sort by metric (ascending unless --descending)"
+    )]
+    sort: PageQualityMetric,
+    #[arg(long, help = "Sort highest first")]
+    descending: bool,
+    #[arg(
+        long = "min",
+        value_parser = parse_bound,
+        value_name = "METRIC=VALUE",
+        help = "Keep pages with metric >= VALUE (repeatable)"
+    )]
+    minimums: Vec<(PageQualityMetric, f64)>,
+    #[arg(
+        long = "max",
+        value_parser = parse_bound,
+        value_name = "METRIC=VALUE",
+        help = "Keep pages with metric <= VALUE (repeatable)"
+    )]
+    maximums: Vec<(PageQualityMetric, f64)>,
+    #[arg(
+        long,
+        default_value_t = 50,
+        value_name = "N",
+        help = "Maximum pages to return"
+    )]
+    limit: usize,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct InspectQualityReport {
+    project_root: String,
+    index_ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quality: Option<PageQualityReport>,
+}
+
+pub(super) fn run_inspect_quality(
+    runtime: &RuntimeOptions,
+    args: QualityInspectArgs,
+) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let thresholds = args
+        .minimums
+        .iter()
+        .map(|(metric, value)| PageQualityThreshold {
+            metric: *metric,
+            min: Some(*value),
+            max: None,
+        })
+        .chain(
+            args.maximums
+                .iter()
+                .map(|(metric, value)| PageQualityThreshold {
+                    metric: *metric,
+                    min: None,
+                    max: Some(*value),
+                }),
+        )
+        .collect();
+    let options = PageQualityOptions {
+        title: args.title,
+        sort: args.sort,
+        descending: args.descending,
+        thresholds,
+        limit: args.limit,
+    };
+    let report = query_page_quality(&paths, &options)?;
+
+    if args.format.is_json() {
+        let output = InspectQualityReport {
+            project_root: normalize_path(&paths.project_root),
+            index_ready: report.is_some(),
+            quality: report,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("knowledge inspect quality");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!("mode: report-only");
+    match report {
+        Some(report) => {
+            println!(
+                "quality.sort: {} {}",
+                report.sort.as_str(),
+                if report.descending { "desc" } else { "asc" }
+            );
+            for threshold in &report.thresholds {
+                if let Some(min) = threshold.min {
+                    println!("quality.threshold: {} >= {min}", threshold.metric.as_str());
+                }
+                if let Some(max) = threshold.max {
+                    println!("quality.threshold: {} <= {max}", threshold.metric.as_str());
+                }
+            }
+            println!("quality.pages: {}", report.page_count);
+            println!("quality.matched: {}", report.matched_count);
+            if report.pages.is_empty() {
+                println!("quality.items: <none>");
+            }
+            for page in &report.pages {
+                println!("quality.page: {}", format_metrics(page));
+            }
+        }
+        None => {
+            println!("knowledge.inspect.storage: <not built> (run `wikitool knowledge build`)");
+        }
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
+
+fn format_metrics(page: &PageQualityMetrics) -> String {
+    let inbound = page
+        .inbound_links
+        .map(|count| count.to_string())
+        .unwrap_or_else(|| "<no index>".to_string());
+    format!(
+        "{} | words={} lead={} refs={} refs/100w={:.2} uncited_paragraphs={}/{} sections={} largest_section={:.0}% images={} inbound={inbound}",
+        page.title,
+        page.prose_words,
+        page.lead_words,
+        page.reference_count,
+        page.references_per_100_words,
+        page.uncited_paragraphs,
+        page.paragraph_count,
+        page.section_count,
+        page.largest_section_share * 100.0,
+        page.image_count,
+    )
+}
+
+fn parse_metric(value: &str) -> Result<PageQualityMetric, String> {
+    PageQualityMetric::parse(value)
+        .ok_or_else(|| format!("unknown metric `{value}` (expected {METRIC_NAMES})"))
+}
+
+fn parse_bound(value: &str) -> Result<(PageQualityMetric, f64), String> {
+    let (metric, bound) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected METRIC=VALUE, got `{value}`"))?;
+    let bound = bound
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| format!("threshold `{bound}` is not a number"))?;
+    Ok((parse_metric(metric)?, bound))
+}
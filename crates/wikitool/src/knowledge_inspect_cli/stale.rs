@@ -0,0 +1,128 @@
+use anyhow::Result;
+use clap::Args;
+use serde::Serialize;
+use wikitool_core::knowledge::staleness::{
+    StaleClaimOptions, StaleClaimReport, query_stale_claims,
+};
+
+use crate::cli_support::{OutputFormat, normalize_path, resolve_runtime_paths};
+use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
+
+#[derive(Debug, Args)]
+pub(crate) struct StaleInspectArgs {
+    #[arg(value_name = "TITLE", help = "Show stale claims for one page")]
+    title: Option<String>,
+    #[arg(
+        long,
+        default_value_t = 365,
+        value_name = "DAYS",
+        help = "Age at which a dated statement counts as stale"
+    )]
+    days: i64,
+    #[arg(
+        long,
+        default_value = "Main",
+        value_name = "NAMESPACE",
+        help = "Namespace to scan"
+    )]
+    namespace: String,
+    #[arg(long, help = "Scan all namespaces")]
+    all_namespaces: bool,
+    #[arg(
+        long,
+        default_value_t = 20,
+        value_name = "N",
+        help = "Maximum pages to return"
+    )]
+    limit: usize,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct InspectStaleReport {
+    project_root: String,
+    index_ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stale: Option<StaleClaimReport>,
+}
+
+pub(super) fn run_inspect_stale(runtime: &RuntimeOptions, args: StaleInspectArgs) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let options = StaleClaimOptions {
+        title: args.title,
+        namespace: (!args.all_namespaces).then_some(args.namespace),
+        stale_after_days: args.days,
+        limit: args.limit,
+    };
+    let report = query_stale_claims(&paths, &options)?;
+
+    if args.format.is_json() {
+        let output = InspectStaleReport {
+            project_root: normalize_path(&paths.project_root),
+            index_ready: report.is_some(),
+            stale: report,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("knowledge inspect stale");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!("mode: report-only");
+    match report {
+        Some(report) => {
+            println!("stale.today: {}", report.today);
+            println!("stale.after_days: {}", report.stale_after_days);
+            println!(
+                "stale.namespace: {}",
+                report.namespace.as_deref().unwrap_or("<all>")
+            );
+            println!("stale.scanned_pages: {}", report.scanned_page_count);
+            println!("stale.pages_with_claims: {}", report.stale_page_count);
+            println!("stale.claims: {}", report.stale_claim_count);
+            if report.pages.is_empty() {
+                println!("stale.pages: <none>");
+            }
+            for page in &report.pages {
+                println!(
+                    "stale.page: {} claims={} dated={} undated={} oldest_days={}",
+                    page.title,
+                    page.stale_claim_count,
+                    page.dated_claim_count,
+                    page.undated_claim_count,
+                    page.oldest_age_days
+                        .map(|age| age.to_string())
+                        .unwrap_or_else(|| "-".to_string())
+                );
+                for claim in &page.claims {
+                    println!(
+                        "  stale.claim: line={} kind={} date={} age_days={} text={}",
+                        claim.line,
+                        claim.kind,
+                        claim.claim_date.as_deref().unwrap_or("-"),
+                        claim
+                            .age_days
+                            .map(|age| age.to_string())
+                            .unwrap_or_else(|| "-".to_string()),
+                        claim.text
+                    );
+                }
+            }
+        }
+        None => {
+            println!("knowledge.inspect.storage: <not built> (run `wikitool knowledge build`)");
+        }
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
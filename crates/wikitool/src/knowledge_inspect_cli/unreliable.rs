@@ -0,0 +1,94 @@
+use anyhow::Result;
+use clap::Args;
+use serde::Serialize;
+use wikitool_core::knowledge::unreliable::{UnreliableSourcesReport, query_unreliable_sources};
+use wikitool_core::profile::load_or_build_remilia_profile_overlay;
+
+use crate::cli_support::{OutputFormat, normalize_path, resolve_runtime_paths};
+use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
+
+#[derive(Debug, Args)]
+pub(crate) struct UnreliableInspectArgs {
+    #[arg(
+        long,
+        default_value_t = 10,
+        value_name = "N",
+        help = "Citing pages to list per source"
+    )]
+    pages: usize,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct InspectUnreliableReport {
+    project_root: String,
+    index_ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unreliable: Option<UnreliableSourcesReport>,
+}
+
+pub(super) fn run_inspect_unreliable(
+    runtime: &RuntimeOptions,
+    args: UnreliableInspectArgs,
+) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let overlay = load_or_build_remilia_profile_overlay(&paths)?;
+    let report =
+        query_unreliable_sources(&paths, &overlay.citations.unreliable_sources, args.pages)?;
+
+    if args.format.is_json() {
+        let output = InspectUnreliableReport {
+            project_root: normalize_path(&paths.project_root),
+            index_ready: report.is_some(),
+            unreliable: report,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("knowledge inspect unreliable");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!("mode: report-only");
+    match report {
+        Some(report) => {
+            println!("unreliable.rules: {}", report.rule_count);
+            println!("unreliable.citing_pages: {}", report.citing_page_count);
+            println!("unreliable.citations: {}", report.citation_count);
+            if report.sources.is_empty() {
+                println!("unreliable.sources: <none>");
+            }
+            for source in &report.sources {
+                println!(
+                    "unreliable.source: {} ({}) | pages={} | citations={}{}",
+                    source.label,
+                    source.matcher,
+                    source.page_count,
+                    source.citation_count,
+                    if source.reason.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" | {}", source.reason)
+                    }
+                );
+                for page in &source.pages {
+                    println!("  page: {} | citations={}", page.title, page.citation_count);
+                }
+            }
+        }
+        None => {
+            println!("knowledge.inspect.storage: <not built> (run `wikitool knowledge build`)");
+        }
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
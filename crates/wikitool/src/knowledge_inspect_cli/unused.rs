@@ -0,0 +1,117 @@
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+use wikitool_core::filesystem::Namespace;
+use wikitool_core::knowledge::unused::{UnusedPagesOptions, UnusedPagesReport, query_unused_pages};
+
+use crate::cli_support::{OutputFormat, normalize_path, resolve_runtime_paths};
+use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
+
+#[derive(Debug, Args)]
+pub(crate) struct UnusedInspectArgs {
+    #[arg(
+        long = "namespace",
+        value_enum,
+        value_name = "NAMESPACE",
+        help = "Limit to template|module|file; repeat for several (default: all three)"
+    )]
+    namespaces: Vec<UnusedNamespaceArg>,
+    #[arg(
+        long,
+        default_value_t = 100,
+        value_name = "N",
+        help = "Maximum candidates to list (oldest local modification first)"
+    )]
+    limit: usize,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum UnusedNamespaceArg {
+    Template,
+    Module,
+    File,
+}
+
+impl UnusedNamespaceArg {
+    fn as_namespace(self) -> Namespace {
+        match self {
+            Self::Template => Namespace::Template,
+            Self::Module => Namespace::Module,
+            Self::File => Namespace::File,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct InspectUnusedReport {
+    project_root: String,
+    index_ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unused: Option<UnusedPagesReport>,
+}
+
+pub(super) fn run_inspect_unused(runtime: &RuntimeOptions, args: UnusedInspectArgs) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let options = UnusedPagesOptions {
+        namespaces: args
+            .namespaces
+            .iter()
+            .map(|namespace| namespace.as_namespace())
+            .collect(),
+        limit: args.limit,
+    };
+    let report = query_unused_pages(&paths, &options)?;
+
+    if args.format.is_json() {
+        let output = InspectUnusedReport {
+            project_root: normalize_path(&paths.project_root),
+            index_ready: report.is_some(),
+            unused: report,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("knowledge inspect unused");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!("mode: report-only");
+    match report {
+        Some(report) => {
+            println!("unused.count: {}", report.candidate_count);
+            println!("unused.templates: {}", report.template_count);
+            println!("unused.modules: {}", report.module_count);
+            println!("unused.files: {}", report.file_count);
+            if report.pages.is_empty() {
+                println!("unused.items: <none>");
+            }
+            for page in &report.pages {
+                println!(
+                    "unused.item: {} | modified={} | bytes={} | {}",
+                    page.title,
+                    page.modified_at.as_deref().unwrap_or("<unknown>"),
+                    page.bytes,
+                    page.relative_path
+                );
+                if !page.used_only_by.is_empty() {
+                    println!("  used_only_by: {}", page.used_only_by.join(", "));
+                }
+            }
+        }
+        None => {
+            println!("knowledge.inspect.storage: <not built> (run `wikitool knowledge build`)");
+        }
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
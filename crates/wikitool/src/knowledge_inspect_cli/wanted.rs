@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Args;
+use serde::Serialize;
+use wikitool_core::knowledge::wanted::{
+    WantedPageBriefFile, WantedPagesOptions, WantedPagesReport, query_wanted_pages,
+    write_wanted_page_briefs,
+};
+
+use crate::cli_support::{OutputFormat, normalize_path, resolve_runtime_paths};
+use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
+
+#[derive(Debug, Args)]
+pub(crate) struct WantedInspectArgs {
+    #[arg(
+        long,
+        default_value_t = 50,
+        value_name = "N",
+        help = "Maximum wanted pages to list"
+    )]
+    limit: usize,
+    #[arg(
+        long,
+        default_value_t = 3,
+        value_name = "N",
+        help = "Linking sentences to show per wanted page"
+    )]
+    mentions: usize,
+    #[arg(
+        long,
+        default_value_t = 1,
+        value_name = "N",
+        help = "Only list targets linked from at least N distinct pages"
+    )]
+    min_sources: usize,
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Run article-start for the top N wanted pages and write the briefs to disk"
+    )]
+    briefs: Option<usize>,
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Directory for --briefs output (default: <state>/exports/wanted)"
+    )]
+    brief_dir: Option<PathBuf>,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct InspectWantedReport {
+    project_root: String,
+    index_ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wanted: Option<WantedPagesReport>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    briefs: Vec<WantedPageBriefFile>,
+}
+
+pub(super) fn run_inspect_wanted(runtime: &RuntimeOptions, args: WantedInspectArgs) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let options = WantedPagesOptions {
+        limit: args.limit,
+        mention_limit: args.mentions,
+        min_sources: args.min_sources,
+    };
+    let report = query_wanted_pages(&paths, &options)?;
+    let briefs = match (&report, args.briefs) {
+        (Some(report), Some(count)) if count > 0 => {
+            let output_dir = args
+                .brief_dir
+                .unwrap_or_else(|| paths.state_dir.join("exports").join("wanted"));
+            let count = count.min(report.pages.len());
+            write_wanted_page_briefs(&paths, &report.pages[..count], &output_dir)?
+        }
+        _ => Vec::new(),
+    };
+
+    if args.format.is_json() {
+        let output = InspectWantedReport {
+            project_root: normalize_path(&paths.project_root),
+            index_ready: report.is_some(),
+            wanted: report,
+            briefs,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("knowledge inspect wanted");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!(
+        "mode: {}",
+        if briefs.is_empty() {
+            "report-only"
+        } else {
+            "write-briefs"
+        }
+    );
+    match report {
+        Some(report) => {
+            println!("wanted.count: {}", report.wanted_count);
+            if report.pages.is_empty() {
+                println!("wanted.items: <none>");
+            }
+            for page in &report.pages {
+                println!(
+                    "wanted.item: {} | links={} | pages={} | from={}",
+                    page.title,
+                    page.link_count,
+                    page.source_count,
+                    page.sources.join(", ")
+                );
+                for mention in &page.mentions {
+                    println!(
+                        "  mention: {}:{} | {}",
+                        mention.source_title, mention.line, mention.sentence
+                    );
+                }
+            }
+            for brief in &briefs {
+                println!(
+                    "wanted.brief: {} | {} | {}",
+                    brief.title, brief.local_state, brief.path
+                );
+            }
+        }
+        None => {
+            println!("knowledge.inspect.storage: <not built> (run `wikitool knowledge build`)");
+        }
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
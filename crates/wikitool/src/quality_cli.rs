@@ -1,19 +1,25 @@
 use anyhow::{Result, bail};
-use clap::{Args, ValueEnum};
+use clap::{Args, Subcommand, ValueEnum};
 use serde::Serialize;
+use wikitool_core::findings::findings_from_lua_report;
 use wikitool_core::knowledge::inspect::{
-    LiveValidationReport, ValidationReport, run_validation_checks, verify_validation_report_live,
+    LiveTitleVerification, LiveValidationReport, ValidationCategory, ValidationOptions,
+    ValidationReport, run_validation_checks_with_options, verify_validation_report_live,
 };
 use wikitool_core::lint::{LuaLintReport, LuaLintResult, lint_modules};
 
 use crate::cli_support::{
-    OutputFormat, normalize_path, print_string_list, resolve_runtime_paths,
-    resolve_runtime_with_config,
+    LintOutputFormat, OutputFormat, normalize_path, print_lint_findings, print_string_list,
+    resolve_runtime_paths, resolve_runtime_with_config,
 };
 use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
 
+mod external_links;
+
 #[derive(Debug, Args)]
 pub(crate) struct ValidateArgs {
+    #[command(subcommand)]
+    command: Option<ValidateSubcommand>,
     #[arg(
         long,
         value_enum,
@@ -28,7 +34,7 @@ pub(crate) struct ValidateArgs {
         long = "category",
         value_enum,
         value_name = "CATEGORY",
-        help = "Limit validation to one issue category; repeat for multiple categories"
+        help = "Check an issue category; repeatable. Without it only broken-links, double-redirects, uncategorized-pages and orphan-pages run"
     )]
     categories: Vec<ValidateCategory>,
     #[arg(
@@ -43,11 +49,15 @@ pub(crate) struct ValidateArgs {
         help = "Limit issues to a page title"
     )]
     titles: Vec<String>,
+    #[arg(long, help = "Verify selected issues against the live wiki API")]
+    verify_live: bool,
     #[arg(
         long,
-        help = "Verify selected broken links and redirect issues against the live wiki API"
+        default_value_t = ValidationOptions::default().oversized_page_bytes,
+        value_name = "BYTES",
+        help = "Report pages larger than BYTES as oversized"
     )]
-    verify_live: bool,
+    max_page_bytes: u64,
     #[arg(long, help = "Report validation issues without exiting non-zero")]
     advisory: bool,
 }
@@ -55,23 +65,39 @@ pub(crate) struct ValidateArgs {
 impl Default for ValidateArgs {
     fn default() -> Self {
         Self {
+            command: None,
             format: OutputFormat::Text,
             summary: false,
             categories: Vec::new(),
             limit: None,
             titles: Vec::new(),
             verify_live: false,
+            max_page_bytes: ValidationOptions::default().oversized_page_bytes,
             advisory: false,
         }
     }
 }
 
+#[derive(Debug, Subcommand)]
+enum ValidateSubcommand {
+    /// Check cited external URLs over HTTP with per-host rate limits and a local result cache
+    #[command(name = "external-links")]
+    ExternalLinks(external_links::ExternalLinksValidateArgs),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ValueEnum)]
 enum ValidateCategory {
     BrokenLinks,
     DoubleRedirects,
     UncategorizedPages,
     OrphanPages,
+    SelfLinks,
+    RedirectBypasses,
+    MissingAnchors,
+    MissingFiles,
+    MissingCategoryPages,
+    OversizedPages,
+    BrokenRedirects,
 }
 
 impl ValidateCategory {
@@ -81,6 +107,29 @@ impl ValidateCategory {
             Self::DoubleRedirects => "double_redirects",
             Self::UncategorizedPages => "uncategorized_pages",
             Self::OrphanPages => "orphan_pages",
+            Self::SelfLinks => "self_links",
+            Self::RedirectBypasses => "redirect_bypasses",
+            Self::MissingAnchors => "missing_anchors",
+            Self::MissingFiles => "missing_files",
+            Self::MissingCategoryPages => "missing_category_pages",
+            Self::OversizedPages => "oversized_pages",
+            Self::BrokenRedirects => "broken_redirects",
+        }
+    }
+
+    fn category(self) -> ValidationCategory {
+        match self {
+            Self::BrokenLinks => ValidationCategory::BrokenLinks,
+            Self::DoubleRedirects => ValidationCategory::DoubleRedirects,
+            Self::UncategorizedPages => ValidationCategory::UncategorizedPages,
+            Self::OrphanPages => ValidationCategory::OrphanPages,
+            Self::SelfLinks => ValidationCategory::SelfLinks,
+            Self::RedirectBypasses => ValidationCategory::RedirectBypasses,
+            Self::MissingAnchors => ValidationCategory::MissingAnchors,
+            Self::MissingFiles => ValidationCategory::MissingFiles,
+            Self::MissingCategoryPages => ValidationCategory::MissingCategoryPages,
+            Self::OversizedPages => ValidationCategory::OversizedPages,
+            Self::BrokenRedirects => ValidationCategory::BrokenRedirects,
         }
     }
 }
@@ -91,11 +140,11 @@ pub(crate) struct LintArgs {
     #[arg(
         long,
         value_enum,
-        default_value_t = OutputFormat::Text,
+        default_value_t = LintOutputFormat::Text,
         value_name = "FORMAT",
-        help = "Output format: text|json"
+        help = "Output format: text|json|sarif|checkstyle"
     )]
-    format: OutputFormat,
+    format: LintOutputFormat,
     #[arg(long, help = "Treat warnings as errors")]
     strict: bool,
     #[arg(long, help = "Omit metadata from JSON output")]
@@ -125,6 +174,7 @@ struct ValidateFiltersJson {
     limit: Option<usize>,
     titles: Vec<String>,
     verify_live: bool,
+    max_page_bytes: u64,
     advisory: bool,
 }
 
@@ -134,6 +184,20 @@ struct ValidateSummary {
     double_redirects: usize,
     uncategorized_pages: usize,
     orphan_pages: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    self_links: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    redirect_bypasses: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    missing_anchors: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    missing_files: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    missing_category_pages: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    oversized_pages: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    broken_redirects: Option<usize>,
 }
 
 #[derive(Debug, Serialize)]
@@ -151,13 +215,24 @@ struct LintJson<'a> {
 }
 
 pub(crate) fn run_validate(runtime: &RuntimeOptions, args: ValidateArgs) -> Result<()> {
+    if let Some(ValidateSubcommand::ExternalLinks(external)) = args.command {
+        return external_links::run_validate_external_links(runtime, external);
+    }
     if matches!(args.limit, Some(0)) {
         bail!("validate requires --limit >= 1");
     }
 
     let paths = resolve_runtime_paths(runtime)?;
 
-    let report = match run_validation_checks(&paths)? {
+    let options = ValidationOptions {
+        categories: args
+            .categories
+            .iter()
+            .map(|category| category.category())
+            .collect(),
+        oversized_page_bytes: args.max_page_bytes,
+    };
+    let report = match run_validation_checks_with_options(&paths, &options)? {
         Some(report) => report,
         None => {
             let message = "content_index.storage: <not built> (run `wikitool knowledge build`)";
@@ -205,7 +280,9 @@ pub(crate) fn run_validate(runtime: &RuntimeOptions, args: ValidateArgs) -> Resu
         "failed"
     };
     if args.format.is_json() {
-        let summary = args.summary.then(|| validation_summary(&report));
+        let summary = args
+            .summary
+            .then(|| validation_summary(&report, &args.categories));
         println!(
             "{}",
             serde_json::to_string_pretty(&ValidateJson {
@@ -227,12 +304,12 @@ pub(crate) fn run_validate(runtime: &RuntimeOptions, args: ValidateArgs) -> Resu
     println!("project_root: {}", normalize_path(&paths.project_root));
     print_validation_filters(&args);
     if args.summary {
-        print_validation_summary(&report);
+        print_validation_summary(&report, &args.categories);
     } else {
-        print_validation_issues(&report);
+        print_validation_issues(&report, &args.categories);
     }
     if let Some(live_verification) = &live_verification {
-        print_live_validation(live_verification);
+        print_live_validation(live_verification, &args.categories);
     }
     println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
     if runtime.diagnostics {
@@ -256,6 +333,13 @@ fn validation_issue_count(report: &ValidationReport) -> usize {
         + report.double_redirects.len()
         + report.uncategorized_pages.len()
         + report.orphan_pages.len()
+        + report.self_links.len()
+        + report.redirect_bypasses.len()
+        + report.missing_anchors.len()
+        + report.missing_files.len()
+        + report.missing_category_pages.len()
+        + report.oversized_pages.len()
+        + report.broken_redirects.len()
 }
 
 fn filter_validation_report(report: &ValidationReport, args: &ValidateArgs) -> ValidationReport {
@@ -274,6 +358,8 @@ fn filter_validation_report(report: &ValidationReport, args: &ValidateArgs) -> V
         validation_category_selected(&args.categories, ValidateCategory::UncategorizedPages);
     let include_orphans =
         validation_category_selected(&args.categories, ValidateCategory::OrphanPages);
+    let select =
+        |category: ValidateCategory| validation_category_selected(&args.categories, category);
 
     ValidationReport {
         broken_links: if include_broken_links {
@@ -334,14 +420,92 @@ fn filter_validation_report(report: &ValidationReport, args: &ValidateArgs) -> V
         } else {
             Vec::new()
         },
+        self_links: filter_category(
+            select(ValidateCategory::SelfLinks),
+            &report.self_links,
+            &title_filters,
+            args.limit,
+            |title| vec![title],
+        ),
+        redirect_bypasses: filter_category(
+            select(ValidateCategory::RedirectBypasses),
+            &report.redirect_bypasses,
+            &title_filters,
+            args.limit,
+            |issue| {
+                vec![
+                    &issue.source_title,
+                    &issue.link_title,
+                    &issue.redirect_target,
+                ]
+            },
+        ),
+        missing_anchors: filter_category(
+            select(ValidateCategory::MissingAnchors),
+            &report.missing_anchors,
+            &title_filters,
+            args.limit,
+            |issue| vec![&issue.source_title, &issue.target_title],
+        ),
+        missing_files: filter_category(
+            select(ValidateCategory::MissingFiles),
+            &report.missing_files,
+            &title_filters,
+            args.limit,
+            |issue| vec![&issue.source_title, &issue.file_title],
+        ),
+        missing_category_pages: filter_category(
+            select(ValidateCategory::MissingCategoryPages),
+            &report.missing_category_pages,
+            &title_filters,
+            args.limit,
+            |issue| vec![&issue.category_title],
+        ),
+        oversized_pages: filter_category(
+            select(ValidateCategory::OversizedPages),
+            &report.oversized_pages,
+            &title_filters,
+            args.limit,
+            |issue| vec![&issue.title],
+        ),
+        broken_redirects: filter_category(
+            select(ValidateCategory::BrokenRedirects),
+            &report.broken_redirects,
+            &title_filters,
+            args.limit,
+            |issue| vec![&issue.title, &issue.target_title],
+        ),
     }
 }
 
+fn filter_category<T: Clone>(
+    selected: bool,
+    issues: &[T],
+    title_filters: &[String],
+    limit: Option<usize>,
+    titles: impl Fn(&T) -> Vec<&String>,
+) -> Vec<T> {
+    if !selected {
+        return Vec::new();
+    }
+    limit_items(
+        issues
+            .iter()
+            .filter(|issue| validation_issue_title_matches(title_filters, titles(issue)))
+            .cloned(),
+        limit,
+    )
+}
+
 fn validation_category_selected(
     categories: &[ValidateCategory],
     category: ValidateCategory,
 ) -> bool {
-    categories.is_empty() || categories.contains(&category)
+    if categories.is_empty() {
+        category.category().is_default()
+    } else {
+        categories.contains(&category)
+    }
 }
 
 fn limit_items<T>(items: impl IntoIterator<Item = T>, limit: Option<usize>) -> Vec<T> {
@@ -385,16 +549,46 @@ fn validate_filters_json(args: &ValidateArgs) -> ValidateFiltersJson {
         limit: args.limit,
         titles: args.titles.clone(),
         verify_live: args.verify_live,
+        max_page_bytes: args.max_page_bytes,
         advisory: args.advisory,
     }
 }
 
-fn validation_summary(report: &ValidationReport) -> ValidateSummary {
+/// Default categories are always counted; opt-in ones only when selected.
+fn validation_summary(
+    report: &ValidationReport,
+    categories: &[ValidateCategory],
+) -> ValidateSummary {
+    let count = |category: ValidateCategory, len: usize| {
+        validation_category_selected(categories, category).then_some(len)
+    };
     ValidateSummary {
         broken_links: report.broken_links.len(),
         double_redirects: report.double_redirects.len(),
         uncategorized_pages: report.uncategorized_pages.len(),
         orphan_pages: report.orphan_pages.len(),
+        self_links: count(ValidateCategory::SelfLinks, report.self_links.len()),
+        redirect_bypasses: count(
+            ValidateCategory::RedirectBypasses,
+            report.redirect_bypasses.len(),
+        ),
+        missing_anchors: count(
+            ValidateCategory::MissingAnchors,
+            report.missing_anchors.len(),
+        ),
+        missing_files: count(ValidateCategory::MissingFiles, report.missing_files.len()),
+        missing_category_pages: count(
+            ValidateCategory::MissingCategoryPages,
+            report.missing_category_pages.len(),
+        ),
+        oversized_pages: count(
+            ValidateCategory::OversizedPages,
+            report.oversized_pages.len(),
+        ),
+        broken_redirects: count(
+            ValidateCategory::BrokenRedirects,
+            report.broken_redirects.len(),
+        ),
     }
 }
 
@@ -402,11 +596,19 @@ pub(crate) fn run_lint(runtime: &RuntimeOptions, args: LintArgs) -> Result<()> {
     let paths = resolve_runtime_paths(runtime)?;
     let report = lint_modules(&paths, args.title.as_deref())?;
 
-    if args.format.is_json() {
-        println!(
-            "{}",
-            serde_json::to_string_pretty(&lint_json_output(&report, args.no_meta))?
-        );
+    if args.format.is_findings_only() || args.format.is_json() {
+        if args.format.is_json() {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&lint_json_output(&report, args.no_meta))?
+            );
+        } else {
+            print_lint_findings(
+                args.format,
+                &paths.project_root,
+                &findings_from_lua_report(&paths, &report)?,
+            )?;
+        }
         if report.total_errors > 0 || (args.strict && report.total_warnings > 0) {
             bail!(
                 "lint found {} error(s) and {} warning(s)",
@@ -488,7 +690,8 @@ fn lint_json_output<'a>(report: &'a LuaLintReport, no_meta: bool) -> LintJson<'a
     }
 }
 
-fn print_validation_issues(report: &ValidationReport) {
+fn print_validation_issues(report: &ValidationReport, categories: &[ValidateCategory]) {
+    let selected = |category| validation_category_selected(categories, category);
     println!("validate.broken_links.count: {}", report.broken_links.len());
     if report.broken_links.is_empty() {
         println!("validate.broken_links: <none>");
@@ -518,10 +721,78 @@ fn print_validation_issues(report: &ValidationReport) {
 
     print_string_list("validate.uncategorized_pages", &report.uncategorized_pages);
     print_string_list("validate.orphan_pages", &report.orphan_pages);
+    if selected(ValidateCategory::SelfLinks) {
+        print_string_list("validate.self_links", &report.self_links);
+    }
+    if selected(ValidateCategory::RedirectBypasses) {
+        print_issue_list(
+            "validate.redirect_bypasses",
+            &report.redirect_bypasses,
+            |issue| {
+                format!(
+                    "source={} link={} target={}",
+                    issue.source_title, issue.link_title, issue.redirect_target
+                )
+            },
+        );
+    }
+    if selected(ValidateCategory::MissingAnchors) {
+        print_issue_list(
+            "validate.missing_anchors",
+            &report.missing_anchors,
+            |issue| {
+                format!(
+                    "source={} target={} anchor={}",
+                    issue.source_title, issue.target_title, issue.anchor
+                )
+            },
+        );
+    }
+    if selected(ValidateCategory::MissingFiles) {
+        print_issue_list("validate.missing_files", &report.missing_files, |issue| {
+            format!("source={} file={}", issue.source_title, issue.file_title)
+        });
+    }
+    if selected(ValidateCategory::MissingCategoryPages) {
+        print_issue_list(
+            "validate.missing_category_pages",
+            &report.missing_category_pages,
+            |issue| {
+                format!(
+                    "category={} members={}",
+                    issue.category_title, issue.member_count
+                )
+            },
+        );
+    }
+    if selected(ValidateCategory::OversizedPages) {
+        print_issue_list(
+            "validate.oversized_pages",
+            &report.oversized_pages,
+            |issue| format!("title={} bytes={}", issue.title, issue.bytes),
+        );
+    }
+    if selected(ValidateCategory::BrokenRedirects) {
+        print_issue_list(
+            "validate.broken_redirects",
+            &report.broken_redirects,
+            |issue| format!("title={} target={}", issue.title, issue.target_title),
+        );
+    }
+}
+
+fn print_issue_list<T>(key: &str, issues: &[T], describe: impl Fn(&T) -> String) {
+    println!("{key}.count: {}", issues.len());
+    if issues.is_empty() {
+        println!("{key}: <none>");
+    }
+    for issue in issues {
+        println!("{key}.issue: {}", describe(issue));
+    }
 }
 
-fn print_validation_summary(report: &ValidationReport) {
-    let summary = validation_summary(report);
+fn print_validation_summary(report: &ValidationReport, categories: &[ValidateCategory]) {
+    let summary = validation_summary(report, categories);
     println!("validate.issue_count: {}", validation_issue_count(report));
     println!("validate.broken_links.count: {}", summary.broken_links);
     println!(
@@ -533,11 +804,25 @@ fn print_validation_summary(report: &ValidationReport) {
         summary.uncategorized_pages
     );
     println!("validate.orphan_pages.count: {}", summary.orphan_pages);
+    for (key, count) in [
+        ("self_links", summary.self_links),
+        ("redirect_bypasses", summary.redirect_bypasses),
+        ("missing_anchors", summary.missing_anchors),
+        ("missing_files", summary.missing_files),
+        ("missing_category_pages", summary.missing_category_pages),
+        ("oversized_pages", summary.oversized_pages),
+        ("broken_redirects", summary.broken_redirects),
+    ] {
+        if let Some(count) = count {
+            println!("validate.{key}.count: {count}");
+        }
+    }
 }
 
 fn print_validation_filters(args: &ValidateArgs) {
     if args.categories.is_empty() && args.limit.is_none() && args.titles.is_empty() {
         println!("filters: <none>");
+        println!("max_page_bytes: {}", args.max_page_bytes);
         println!(
             "verify_live: {}",
             if args.verify_live { "true" } else { "false" }
@@ -572,13 +857,15 @@ fn print_validation_filters(args: &ValidateArgs) {
             args.titles.join(", ")
         }
     );
+    println!("max_page_bytes: {}", args.max_page_bytes);
     println!(
         "verify_live: {}",
         if args.verify_live { "true" } else { "false" }
     );
 }
 
-fn print_live_validation(report: &LiveValidationReport) {
+fn print_live_validation(report: &LiveValidationReport, categories: &[ValidateCategory]) {
+    let selected = |category| validation_category_selected(categories, category);
     println!("validate.live.request_count: {}", report.request_count);
     println!(
         "validate.live.broken_links.count: {}",
@@ -615,6 +902,68 @@ fn print_live_validation(report: &LiveValidationReport) {
                 .unwrap_or_else(|| "<none>".to_string())
         );
     }
+    if selected(ValidateCategory::SelfLinks) {
+        print_live_titles("validate.live.self_links", &report.self_links);
+    }
+    if selected(ValidateCategory::RedirectBypasses) {
+        print_live_titles("validate.live.redirect_bypasses", &report.redirect_bypasses);
+    }
+    if selected(ValidateCategory::MissingAnchors) {
+        println!(
+            "validate.live.missing_anchors.count: {}",
+            report.missing_anchors.len()
+        );
+        for issue in &report.missing_anchors {
+            println!(
+                "validate.live.missing_anchor: source={} target={} anchor={} status={} heading_exists={}",
+                issue.source_title,
+                issue.target_title,
+                issue.anchor,
+                serde_json::to_string(&issue.live_status)
+                    .unwrap_or_else(|_| "\"unknown\"".to_string()),
+                issue
+                    .live_heading_exists
+                    .map(|exists| exists.to_string())
+                    .unwrap_or_else(|| "<unknown>".to_string())
+            );
+        }
+    }
+    if selected(ValidateCategory::MissingFiles) {
+        print_live_titles("validate.live.missing_files", &report.missing_files);
+    }
+    if selected(ValidateCategory::MissingCategoryPages) {
+        print_live_titles(
+            "validate.live.missing_category_pages",
+            &report.missing_category_pages,
+        );
+    }
+    if selected(ValidateCategory::OversizedPages) {
+        print_live_titles("validate.live.oversized_pages", &report.oversized_pages);
+    }
+    if selected(ValidateCategory::BrokenRedirects) {
+        print_live_titles("validate.live.broken_redirects", &report.broken_redirects);
+    }
+}
+
+fn print_live_titles(key: &str, issues: &[LiveTitleVerification]) {
+    println!("{key}.count: {}", issues.len());
+    for issue in issues {
+        println!(
+            "{key}.issue: issue={} checked={} status={} resolved={} page_id={} bytes={}",
+            issue.issue_title,
+            issue.checked_title,
+            serde_json::to_string(&issue.live_status).unwrap_or_else(|_| "\"unknown\"".to_string()),
+            issue.resolved_title.as_deref().unwrap_or("<none>"),
+            issue
+                .page_id
+                .map(|value| value.to_string())
+                .unwrap_or_else(|| "<none>".to_string()),
+            issue
+                .live_bytes
+                .map(|value| value.to_string())
+                .unwrap_or_else(|| "<none>".to_string())
+        );
+    }
 }
 
 #[cfg(test)]
@@ -639,6 +988,7 @@ mod tests {
             }],
             uncategorized_pages: vec!["Uncategorized".to_string()],
             orphan_pages: Vec::new(),
+            ..ValidationReport::default()
         };
 
         let value = serde_json::to_value(ValidateJson {
@@ -651,6 +1001,7 @@ mod tests {
                 limit: None,
                 titles: Vec::new(),
                 verify_live: false,
+                max_page_bytes: 100_000,
                 advisory: false,
             },
             summary: None,
@@ -681,6 +1032,7 @@ mod tests {
                 limit: None,
                 titles: Vec::new(),
                 verify_live: false,
+                max_page_bytes: 100_000,
                 advisory: false,
             },
             summary: None,
@@ -706,6 +1058,7 @@ mod tests {
             double_redirects: Vec::new(),
             uncategorized_pages: vec!["Uncategorized".to_string()],
             orphan_pages: vec!["Orphan".to_string()],
+            ..ValidationReport::default()
         };
 
         let value = serde_json::to_value(ValidateJson {
@@ -718,9 +1071,10 @@ mod tests {
                 limit: None,
                 titles: Vec::new(),
                 verify_live: false,
+                max_page_bytes: 100_000,
                 advisory: false,
             },
-            summary: Some(validation_summary(&report)),
+            summary: Some(validation_summary(&report, &[])),
             report: None,
             live_verification: None,
             message: None,
@@ -754,14 +1108,17 @@ mod tests {
             }],
             uncategorized_pages: vec!["Alpha".to_string()],
             orphan_pages: vec!["Beta".to_string()],
+            ..ValidationReport::default()
         };
         let args = ValidateArgs {
+            command: None,
             format: OutputFormat::Json,
             summary: false,
             categories: vec![ValidateCategory::BrokenLinks],
             limit: Some(1),
             titles: vec!["Main:Alpha".to_string()],
             verify_live: false,
+            max_page_bytes: 100_000,
             advisory: false,
         };
 
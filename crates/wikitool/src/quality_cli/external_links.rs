@@ -0,0 +1,159 @@
+use anyhow::{Result, bail};
+use clap::Args;
+use serde::Serialize;
+use wikitool_core::knowledge::external_links::{
+    ExternalLinkCheckOptions, ExternalLinkReport, check_external_links,
+};
+
+use crate::cli_support::{OutputFormat, normalize_path, resolve_runtime_paths};
+use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
+
+#[derive(Debug, Args)]
+pub(crate) struct ExternalLinksValidateArgs {
+    #[arg(
+        long = "title",
+        value_name = "TITLE",
+        help = "Only check URLs cited on this page; repeat for multiple pages"
+    )]
+    titles: Vec<String>,
+    #[arg(long, value_name = "N", help = "Maximum distinct URLs to check")]
+    limit: Option<usize>,
+    #[arg(
+        long,
+        default_value_t = 7,
+        value_name = "DAYS",
+        help = "Reuse cached results younger than this many days"
+    )]
+    max_age_days: u64,
+    #[arg(long, help = "Ignore cached results and re-check every URL")]
+    refresh: bool,
+    #[arg(
+        long,
+        default_value_t = 1_000,
+        value_name = "MS",
+        help = "Minimum delay between requests to the same host"
+    )]
+    delay_ms: u64,
+    #[arg(
+        long,
+        default_value_t = 15,
+        value_name = "SECONDS",
+        help = "Per-request timeout"
+    )]
+    timeout: u64,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json; text exits non-zero on findings, json reports findings via status"
+    )]
+    format: OutputFormat,
+    #[arg(long, help = "Report link issues without exiting non-zero")]
+    advisory: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ExternalLinksJson {
+    project_root: String,
+    index_ready: bool,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    report: Option<ExternalLinkReport>,
+}
+
+pub(super) fn run_validate_external_links(
+    runtime: &RuntimeOptions,
+    args: ExternalLinksValidateArgs,
+) -> Result<()> {
+    if matches!(args.limit, Some(0)) {
+        bail!("validate external-links requires --limit >= 1");
+    }
+    let paths = resolve_runtime_paths(runtime)?;
+    let options = ExternalLinkCheckOptions {
+        titles: args.titles,
+        limit: args.limit,
+        max_age_seconds: args.max_age_days.saturating_mul(86_400),
+        refresh: args.refresh,
+        per_host_delay_ms: args.delay_ms,
+        timeout_seconds: args.timeout,
+    };
+    let report = check_external_links(&paths, &options)?;
+    let status = match &report {
+        None => "not_ready",
+        Some(report) if report.issue_count == 0 => "clean",
+        Some(_) if args.advisory => "advisory",
+        Some(_) => "failed",
+    };
+
+    if args.format.is_json() {
+        let index_ready = report.is_some();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&ExternalLinksJson {
+                project_root: normalize_path(&paths.project_root),
+                index_ready,
+                status,
+                report,
+            })?
+        );
+        if !index_ready {
+            bail!("validate external-links requires a built local index");
+        }
+        return Ok(());
+    }
+
+    println!("validate external-links");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    let Some(report) = report else {
+        println!("content_index.storage: <not built> (run `wikitool knowledge build`)");
+        println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+        bail!("validate external-links requires a built local index");
+    };
+    println!("external_links.urls: {}", report.url_count);
+    println!(
+        "external_links.checked: {} (cached={} fetched={})",
+        report.checked_count, report.cached_count, report.fetched_count
+    );
+    for (status, count) in &report.status_counts {
+        println!("external_links.status.{status}: {count}");
+    }
+    println!(
+        "external_links.issues: {} (archived_copies={})",
+        report.issue_count, report.archived_issue_count
+    );
+    if report.links.is_empty() {
+        println!("external_links.items: <none>");
+    }
+    for link in &report.links {
+        let http_status = link
+            .http_status
+            .map(|status| status.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        println!(
+            "external_links.item: {} | {} | http={} | pages={}",
+            link.status.as_str(),
+            link.url,
+            http_status,
+            link.source_pages.join(", ")
+        );
+        if let Some(detail) = &link.detail {
+            println!("  detail: {detail}");
+        }
+        if let Some(archived_copy) = &link.archived_copy {
+            println!("  archived_copy: {archived_copy}");
+        }
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    println!("validate.status: {status}");
+    if status == "failed" {
+        bail!(
+            "external link check detected {} issue(s)",
+            report.issue_count
+        );
+    }
+    Ok(())
+}
@@ -9,7 +9,7 @@ use wikitool_core::sync::{PushReport, SyncPlanReport, SyncSelection};
 
 use crate::RuntimeOptions;
 use crate::briefs::BriefView;
-use crate::cli_support::OutputFormat;
+use crate::cli_support::LintOutputFormat;
 
 mod checks;
 mod draft;
@@ -26,11 +26,11 @@ pub(crate) struct ReviewArgs {
     #[arg(
         long,
         value_enum,
-        default_value_t = OutputFormat::Json,
+        default_value_t = LintOutputFormat::Json,
         value_name = "FORMAT",
-        help = "Output format: text|json"
+        help = "Output format: text|json|sarif|checkstyle; sarif and checkstyle carry only article lint findings"
     )]
-    format: OutputFormat,
+    format: LintOutputFormat,
     #[arg(
         long,
         value_enum,
@@ -3,7 +3,7 @@ use super::draft::{
 };
 use super::next_steps::build_review_next_steps;
 use super::*;
-use crate::{briefs::BriefView, cli_support::OutputFormat};
+use crate::{briefs::BriefView, cli_support::LintOutputFormat};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -11,7 +11,7 @@ use wikitool_core::runtime::{ResolvedPaths, ValueSource};
 
 fn review_args() -> ReviewArgs {
     ReviewArgs {
-        format: OutputFormat::Json,
+        format: LintOutputFormat::Json,
         view: BriefView::Brief,
         strict: false,
         templates: false,
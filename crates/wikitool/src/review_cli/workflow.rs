@@ -1,10 +1,11 @@
 use anyhow::{Result, bail};
 use wikitool_core::filesystem::validate_scoped_path;
+use wikitool_core::findings::findings_from_article_reports;
 use wikitool_core::knowledge_interview::{InterviewValidationStatus, validate_interview_brief};
 use wikitool_core::runtime::{ensure_runtime_ready_for_sync, inspect_runtime};
 use wikitool_core::sync::{SyncPlanOptions, SyncSelection, plan_sync_changes_with_config};
 
-use crate::cli_support::{normalize_path, resolve_runtime_with_config};
+use crate::cli_support::{normalize_path, print_lint_findings, resolve_runtime_with_config};
 use crate::{LOCAL_DB_POLICY_MESSAGE, RuntimeOptions};
 
 use super::checks::{run_changed_article_lint, run_review_push_dry_run, run_review_validation};
@@ -196,7 +197,13 @@ pub(super) fn run_review(runtime: &RuntimeOptions, args: ReviewArgs) -> Result<(
         next_steps,
     };
 
-    if args.format.is_json() {
+    if args.format.is_findings_only() {
+        print_lint_findings(
+            args.format,
+            &paths.project_root,
+            &findings_from_article_reports(&report.changed_article_lint.reports),
+        )?;
+    } else if args.format.is_json() {
         if args.view.is_full() {
             println!("{}", serde_json::to_string_pretty(&report)?);
         } else {
@@ -1,6 +1,10 @@
-use anyhow::{Result, bail};
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, bail};
 use clap::{Args, Subcommand};
 use serde::Serialize;
+use wikitool_core::knowledge::template_impact::{TemplateImpactReport, query_template_impact};
 use wikitool_core::profile::{
     TemplateCatalog, TemplateCatalogEntry, TemplateCatalogEntryLookup, find_template_catalog_entry,
     load_or_build_remilia_profile_overlay, load_template_catalog,
@@ -25,6 +29,8 @@ enum TemplatesSubcommand {
     Show(TemplatesShowArgs),
     #[command(about = "Show example invocations for one template")]
     Examples(TemplatesExamplesArgs),
+    #[command(about = "List pages affected by a template or module change, including indirect use")]
+    Impact(TemplatesImpactArgs),
 }
 
 #[derive(Debug, Args)]
@@ -87,11 +93,47 @@ pub(crate) struct TemplatesExamplesArgs {
     format: OutputFormat,
 }
 
+#[derive(Debug, Args)]
+pub(crate) struct TemplatesImpactArgs {
+    #[arg(help = "Template or Module: title; bare names resolve to Template:")]
+    template: String,
+    #[arg(
+        long,
+        help = "Also list affected templates, modules, and other non-content pages"
+    )]
+    all_namespaces: bool,
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Write affected titles one per line, for `purge --titles-file` or `article lint --titles-file`"
+    )]
+    emit_titles: Option<PathBuf>,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        value_name = "FORMAT",
+        help = "Output format: text|json"
+    )]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct TemplatesImpactOutput {
+    project_root: String,
+    index_ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emitted_titles_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    impact: Option<TemplateImpactReport>,
+}
+
 pub(crate) fn run_templates(runtime: &RuntimeOptions, args: TemplatesArgs) -> Result<()> {
     match args.command {
         TemplatesSubcommand::Catalog(args) => run_templates_catalog(runtime, args),
         TemplatesSubcommand::Show(args) => run_templates_show(runtime, args),
         TemplatesSubcommand::Examples(args) => run_templates_examples(runtime, args),
+        TemplatesSubcommand::Impact(args) => run_templates_impact(runtime, args),
     }
 }
 
@@ -214,6 +256,78 @@ fn run_templates_examples(runtime: &RuntimeOptions, args: TemplatesExamplesArgs)
     Ok(())
 }
 
+fn run_templates_impact(runtime: &RuntimeOptions, args: TemplatesImpactArgs) -> Result<()> {
+    let paths = resolve_runtime_paths(runtime)?;
+    let report = query_template_impact(&paths, &args.template, args.all_namespaces)?;
+
+    let emitted_titles_path = match (&args.emit_titles, &report) {
+        (Some(path), Some(report)) => {
+            let mut body = report
+                .pages
+                .iter()
+                .map(|page| page.title.as_str())
+                .collect::<Vec<_>>()
+                .join("\n");
+            if !body.is_empty() {
+                body.push('\n');
+            }
+            fs::write(path, body)
+                .with_context(|| format!("failed to write {}", normalize_path(path)))?;
+            Some(normalize_path(path))
+        }
+        _ => None,
+    };
+
+    if args.format.is_json() {
+        let output = TemplatesImpactOutput {
+            project_root: normalize_path(&paths.project_root),
+            index_ready: report.is_some(),
+            emitted_titles_path,
+            impact: report,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("templates impact");
+    println!("project_root: {}", normalize_path(&paths.project_root));
+    println!("mode: report-only");
+    match report {
+        Some(report) => {
+            println!("target_title: {}", report.target_title);
+            println!("affected_count: {}", report.affected_count);
+            println!("content_page_count: {}", report.content_page_count);
+            println!("direct_count: {}", report.direct_count);
+            println!("max_depth: {}", report.max_depth);
+            for (namespace, count) in &report.namespace_counts {
+                println!("namespace_count: {namespace}={count}");
+            }
+            if report.pages.is_empty() {
+                println!("affected: <none>");
+            }
+            for page in &report.pages {
+                println!(
+                    "affected: {} depth={} path={}",
+                    page.title,
+                    page.depth,
+                    page.dependency_path.join(" -> ")
+                );
+            }
+            if let Some(path) = emitted_titles_path {
+                println!("emitted_titles: {path}");
+            }
+        }
+        None => {
+            println!("knowledge.inspect.storage: <not built> (run `wikitool knowledge build`)");
+        }
+    }
+    println!("policy: {LOCAL_DB_POLICY_MESSAGE}");
+    if runtime.diagnostics {
+        println!("\n[diagnostics]\n{}", paths.diagnostics());
+    }
+    Ok(())
+}
+
 fn load_or_sync_catalog(paths: &wikitool_core::runtime::ResolvedPaths) -> Result<TemplateCatalog> {
     let overlay = load_or_build_remilia_profile_overlay(paths)?;
     if let Some(catalog) = load_template_catalog(paths, &overlay.profile_id)? {
@@ -98,6 +98,7 @@ fn sample_snapshot() -> WikiProfileSnapshot {
                 forbid_curly_quotes: true,
                 forbid_placeholder_fragments: vec!["todo".to_string()],
                 proper_nouns: vec!["Webring".to_string()],
+                phrase_rewrites: Vec::new(),
             },
             golden_set: GoldenSetRules {
                 article_corpus_available: true,
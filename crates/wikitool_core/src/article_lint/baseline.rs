@@ -0,0 +1,161 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::runtime::ResolvedPaths;
+use crate::support::{compute_hash, normalize_path};
+
+use super::model::{ArticleLintIssue, ArticleLintReport};
+
+const LINT_BASELINE_SCHEMA_VERSION: &str = "lint_baseline_v1";
+const LINT_BASELINE_FILE_NAME: &str = "lint-baseline.json";
+
+/// Known issues recorded by `article lint --write-baseline`. Issues are keyed
+/// by page, rule and a fingerprint of message and evidence rather than by
+/// line, so edits elsewhere on a page do not resurface them.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(super) struct LintBaseline {
+    schema_version: String,
+    entries: Vec<LintBaselineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+struct LintBaselineEntry {
+    relative_path: String,
+    rule_id: String,
+    fingerprint: String,
+    count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LintBaselineWriteSummary {
+    pub path: String,
+    pub file_count: usize,
+    pub issue_count: usize,
+    /// Entries kept for pages that were not linted in this run.
+    pub retained_file_count: usize,
+}
+
+pub(super) fn lint_baseline_path(paths: &ResolvedPaths) -> PathBuf {
+    paths.state_dir.join(LINT_BASELINE_FILE_NAME)
+}
+
+pub(super) fn load_lint_baseline(paths: &ResolvedPaths) -> Result<Option<LintBaseline>> {
+    let path = lint_baseline_path(paths);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))?;
+    let baseline: LintBaseline = serde_json::from_str(&content)
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+    if baseline.schema_version != LINT_BASELINE_SCHEMA_VERSION {
+        bail!(
+            "unsupported lint baseline schema `{}` in {} (expected {LINT_BASELINE_SCHEMA_VERSION}); rerun `article lint --write-baseline`",
+            baseline.schema_version,
+            path.display()
+        );
+    }
+    Ok(Some(baseline))
+}
+
+impl LintBaseline {
+    /// Remaining allowance per (rule, fingerprint) for one page; each matched
+    /// issue consumes one, so a page that grows a second copy of a known issue
+    /// still reports the new one.
+    pub(super) fn allowances_for(&self, relative_path: &str) -> BTreeMap<(String, String), usize> {
+        let mut out = BTreeMap::new();
+        for entry in self
+            .entries
+            .iter()
+            .filter(|entry| entry.relative_path == relative_path)
+        {
+            *out.entry((entry.rule_id.clone(), entry.fingerprint.clone()))
+                .or_insert(0) += entry.count;
+        }
+        out
+    }
+}
+
+pub(super) fn issue_fingerprint(issue: &ArticleLintIssue) -> String {
+    compute_hash(&format!(
+        "{}\n{}\n{}",
+        issue.rule_id,
+        issue.message,
+        issue.evidence.as_deref().unwrap_or_default()
+    ))
+}
+
+/// Record every issue in `reports` as known. Entries for pages outside this run
+/// are kept so a partial lint does not drop the rest of the baseline.
+pub fn write_lint_baseline(
+    paths: &ResolvedPaths,
+    reports: &[ArticleLintReport],
+) -> Result<LintBaselineWriteSummary> {
+    let path = lint_baseline_path(paths);
+    let linted = reports
+        .iter()
+        .map(|report| report.relative_path.as_str())
+        .collect::<Vec<_>>();
+    let mut counts = BTreeMap::<(String, String, String), usize>::new();
+    let mut retained_files = BTreeSet::new();
+    if let Some(existing) = load_lint_baseline(paths)? {
+        for entry in existing.entries {
+            if linted.contains(&entry.relative_path.as_str()) {
+                continue;
+            }
+            retained_files.insert(entry.relative_path.clone());
+            *counts
+                .entry((entry.relative_path, entry.rule_id, entry.fingerprint))
+                .or_insert(0) += entry.count;
+        }
+    }
+    let mut issue_count = 0usize;
+    let mut file_count = 0usize;
+    for report in reports {
+        if !report.issues.is_empty() {
+            file_count += 1;
+        }
+        for issue in &report.issues {
+            issue_count += 1;
+            *counts
+                .entry((
+                    report.relative_path.clone(),
+                    issue.rule_id.clone(),
+                    issue_fingerprint(issue),
+                ))
+                .or_insert(0) += 1;
+        }
+    }
+    let baseline = LintBaseline {
+        schema_version: LINT_BASELINE_SCHEMA_VERSION.to_string(),
+        entries: counts
+            .into_iter()
+            .map(
+                |((relative_path, rule_id, fingerprint), count)| LintBaselineEntry {
+                    relative_path,
+                    rule_id,
+                    fingerprint,
+                    count,
+                },
+            )
+            .collect(),
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let payload =
+        serde_json::to_string_pretty(&baseline).context("failed to serialize lint baseline")?;
+    fs::write(&path, format!("{payload}\n"))
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(LintBaselineWriteSummary {
+        path: normalize_path(&path),
+        file_count,
+        issue_count,
+        retained_file_count: retained_files.len(),
+    })
+}
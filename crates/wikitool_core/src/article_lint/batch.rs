@@ -0,0 +1,238 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::{Result, anyhow, bail};
+
+use crate::content_store::parsing::open_indexed_connection;
+use crate::filesystem::{ScanOptions, scan_files};
+use crate::runtime::ResolvedPaths;
+
+use super::model::{
+    ArticleLintAggregate, ArticleLintPageSummary, ArticleLintReport, ArticleLintRuleCount,
+    ArticleLintSeverity,
+};
+use super::{ArticleLintResources, lint_article_with_resources};
+
+/// Tree selectors for batch lint. A page is selected when it is in any listed
+/// namespace or directly in any listed category.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArticleLintTargetFilter {
+    pub namespaces: Vec<String>,
+    pub categories: Vec<String>,
+}
+
+impl ArticleLintTargetFilter {
+    pub fn is_empty(&self) -> bool {
+        self.namespaces.is_empty() && self.categories.is_empty()
+    }
+}
+
+/// Relative paths of non-redirect pages under `wiki_content/` matching
+/// `filter`. Namespaces come from a file scan; categories need the local index.
+pub fn collect_article_lint_targets(
+    paths: &ResolvedPaths,
+    filter: &ArticleLintTargetFilter,
+) -> Result<Vec<String>> {
+    let mut out = BTreeSet::new();
+    if !filter.namespaces.is_empty() {
+        let scanned = scan_files(
+            paths,
+            &ScanOptions {
+                include_content: true,
+                include_templates: false,
+                ..ScanOptions::default()
+            },
+        )?;
+        for file in scanned {
+            if !file.is_redirect
+                && file.relative_path.starts_with("wiki_content/")
+                && filter
+                    .namespaces
+                    .iter()
+                    .any(|namespace| namespace.trim().eq_ignore_ascii_case(&file.namespace))
+            {
+                out.insert(file.relative_path);
+            }
+        }
+    }
+    if !filter.categories.is_empty() {
+        let Some(connection) = open_indexed_connection(paths)? else {
+            bail!(
+                "article lint --category requires a built local index (run `wikitool knowledge build`)"
+            );
+        };
+        let mut statement = connection.prepare(
+            "SELECT DISTINCT p.relative_path
+             FROM indexed_links l
+             JOIN indexed_pages p ON p.title = l.source_title
+             WHERE l.is_category_membership = 1
+               AND l.target_title = ?1
+               AND p.is_redirect = 0
+             ORDER BY p.relative_path ASC",
+        )?;
+        for category in &filter.categories {
+            let category_title = category_title(category);
+            let rows =
+                statement.query_map([category_title.as_str()], |row| row.get::<_, String>(0))?;
+            for relative_path in rows {
+                let relative_path = relative_path?;
+                if relative_path.starts_with("wiki_content/")
+                    && paths.project_root.join(&relative_path).exists()
+                {
+                    out.insert(relative_path);
+                }
+            }
+        }
+    }
+    Ok(out.into_iter().collect())
+}
+
+fn category_title(value: &str) -> String {
+    let trimmed = value.trim().replace('_', " ");
+    let name = match trimmed.split_once(':') {
+        Some((prefix, rest)) if prefix.trim().eq_ignore_ascii_case("category") => rest.trim(),
+        _ => trimmed.as_str(),
+    };
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => format!("Category:{}{}", first.to_uppercase(), chars.as_str()),
+        None => "Category:".to_string(),
+    }
+}
+
+/// Lint `relative_paths` on up to `jobs` worker threads. Each worker gets its
+/// own copy of `resources` with a fresh index connection, since SQLite
+/// connections cannot be shared across threads. Reports come back in input
+/// order; the first failing page in that order decides the error.
+pub fn lint_articles_with_resources(
+    paths: &ResolvedPaths,
+    relative_paths: &[String],
+    resources: &ArticleLintResources,
+    jobs: usize,
+) -> Result<Vec<ArticleLintReport>> {
+    let jobs = jobs.clamp(1, relative_paths.len().max(1));
+    if jobs == 1 {
+        return relative_paths
+            .iter()
+            .map(|relative_path| {
+                lint_article_with_resources(paths, Path::new(relative_path), None, resources)
+            })
+            .collect();
+    }
+
+    let workers = (0..jobs)
+        .map(|_| {
+            Ok(ArticleLintResources {
+                inner: resources.inner.fork(paths)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let next = AtomicUsize::new(0);
+    let mut slots = (0..relative_paths.len())
+        .map(|_| None)
+        .collect::<Vec<Option<Result<ArticleLintReport>>>>();
+    std::thread::scope(|scope| {
+        let handles = workers
+            .into_iter()
+            .map(|worker| {
+                let next = &next;
+                scope.spawn(move || {
+                    let mut out = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(relative_path) = relative_paths.get(index) else {
+                            break;
+                        };
+                        out.push((
+                            index,
+                            lint_article_with_resources(
+                                paths,
+                                Path::new(relative_path),
+                                None,
+                                &worker,
+                            ),
+                        ));
+                    }
+                    out
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            let results = handle
+                .join()
+                .map_err(|_| anyhow!("article lint worker panicked"))?;
+            for (index, result) in results {
+                slots[index] = Some(result);
+            }
+        }
+        Ok::<_, anyhow::Error>(())
+    })?;
+    slots
+        .into_iter()
+        .map(|slot| slot.expect("every page is linted once"))
+        .collect()
+}
+
+pub fn aggregate_article_lint(reports: &[ArticleLintReport]) -> ArticleLintAggregate {
+    let mut rules = BTreeMap::<&str, (ArticleLintRuleCount, BTreeSet<&str>)>::new();
+    for report in reports {
+        for issue in &report.issues {
+            let (count, pages) = rules.entry(issue.rule_id.as_str()).or_insert_with(|| {
+                (
+                    ArticleLintRuleCount {
+                        rule_id: issue.rule_id.clone(),
+                        issue_count: 0,
+                        page_count: 0,
+                        errors: 0,
+                        warnings: 0,
+                        suggestions: 0,
+                    },
+                    BTreeSet::new(),
+                )
+            });
+            count.issue_count += 1;
+            match issue.severity {
+                ArticleLintSeverity::Error => count.errors += 1,
+                ArticleLintSeverity::Warning => count.warnings += 1,
+                ArticleLintSeverity::Suggestion => count.suggestions += 1,
+            }
+            pages.insert(report.relative_path.as_str());
+        }
+    }
+    let mut rules = rules
+        .into_values()
+        .map(|(mut count, pages)| {
+            count.page_count = pages.len();
+            count
+        })
+        .collect::<Vec<_>>();
+    rules.sort_by(|left, right| {
+        right
+            .issue_count
+            .cmp(&left.issue_count)
+            .then_with(|| left.rule_id.cmp(&right.rule_id))
+    });
+
+    ArticleLintAggregate {
+        target_count: reports.len(),
+        total_errors: reports.iter().map(|report| report.errors).sum(),
+        total_warnings: reports.iter().map(|report| report.warnings).sum(),
+        total_suggestions: reports.iter().map(|report| report.suggestions).sum(),
+        total_suppressed: reports.iter().map(|report| report.suppressed).sum(),
+        total_baselined: reports.iter().map(|report| report.baselined).sum(),
+        rules,
+        pages: reports
+            .iter()
+            .map(|report| ArticleLintPageSummary {
+                relative_path: report.relative_path.clone(),
+                title: report.title.clone(),
+                errors: report.errors,
+                warnings: report.warnings,
+                suggestions: report.suggestions,
+                suppressed: report.suppressed,
+                baselined: report.baselined,
+            })
+            .collect(),
+    }
+}
@@ -42,11 +42,23 @@ pub(super) struct ArticleSection {
 pub(super) struct TemplateOccurrence {
     pub(super) template_title: String,
     pub(super) parameter_keys: Vec<String>,
+    pub(super) parameters: Vec<TemplateParameterOccurrence>,
     pub(super) raw_wikitext: String,
     pub(super) start: usize,
     pub(super) end: usize,
 }
 
+/// A named `key=value` argument of a template invocation; offsets are absolute.
+#[derive(Debug, Clone)]
+pub(super) struct TemplateParameterOccurrence {
+    pub(super) key: String,
+    pub(super) value: String,
+    pub(super) key_start: usize,
+    pub(super) key_end: usize,
+    pub(super) value_start: usize,
+    pub(super) value_end: usize,
+}
+
 #[derive(Debug, Clone)]
 pub(super) struct ModuleInvocationOccurrence {
     pub(super) module_title: String,
@@ -77,6 +89,29 @@ pub(super) struct TemplateStylesOccurrence {
     pub(super) end: usize,
 }
 
+/// A `<!-- wikitool-disable ... -->` region or a
+/// `<!-- wikitool-disable-next-line ... -->` line.
+#[derive(Debug, Clone)]
+pub(super) struct LintSuppression {
+    /// Rule ids or `prefix.*` patterns; empty silences every rule.
+    pub(super) rule_ids: Vec<String>,
+    pub(super) first_line: usize,
+    /// Inclusive; `None` runs to the end of the page.
+    pub(super) last_line: Option<usize>,
+}
+
+impl LintSuppression {
+    fn covers_rule(&self, rule_id: &str) -> bool {
+        self.rule_ids.is_empty()
+            || self.rule_ids.iter().any(|pattern| {
+                pattern == rule_id
+                    || pattern
+                        .strip_suffix('*')
+                        .is_some_and(|prefix| rule_id.starts_with(prefix))
+            })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(super) struct ParsedArticleDocument {
     pub(super) relative_path: String,
@@ -92,6 +127,7 @@ pub(super) struct ParsedArticleDocument {
     pub(super) references: Vec<RefOccurrence>,
     pub(super) parser_tags: Vec<ParserTagOccurrence>,
     pub(super) template_styles: Vec<TemplateStylesOccurrence>,
+    pub(super) suppressions: Vec<LintSuppression>,
 }
 
 impl ParsedArticleDocument {
@@ -143,6 +179,21 @@ impl ParsedArticleDocument {
             .collect()
     }
 
+    /// Issues without a line are only silenced by regions left open to the end
+    /// of the page.
+    pub(super) fn is_suppressed(&self, rule_id: &str, line: Option<usize>) -> bool {
+        self.suppressions.iter().any(|suppression| {
+            suppression.covers_rule(rule_id)
+                && match line {
+                    Some(line) => {
+                        line >= suppression.first_line
+                            && suppression.last_line.is_none_or(|last| line <= last)
+                    }
+                    None => suppression.last_line.is_none(),
+                }
+        })
+    }
+
     pub(super) fn find_section(&self, heading: &str) -> Option<&ArticleSection> {
         self.sections.iter().find(|section| {
             section
@@ -184,6 +235,7 @@ pub(super) fn load_article_document_with_title(
     let references = extract_ref_occurrences(&content);
     let parser_tags = extract_open_tag_occurrences(&content);
     let template_styles = extract_templatestyles_occurrences(&content);
+    let suppressions = extract_lint_suppressions(&content, &lines);
 
     Ok(ParsedArticleDocument {
         relative_path,
@@ -199,9 +251,70 @@ pub(super) fn load_article_document_with_title(
         references,
         parser_tags,
         template_styles,
+        suppressions,
     })
 }
 
+/// `wikitool-disable-next-line` covers the line after the comment;
+/// `wikitool-disable` runs until a matching `wikitool-enable` or the end of the
+/// page. Rule ids may be separated by spaces or commas.
+fn extract_lint_suppressions(content: &str, lines: &[LineRecord]) -> Vec<LintSuppression> {
+    let line_number = |offset: usize| {
+        lines
+            .iter()
+            .find(|line| offset <= line.end)
+            .map(|line| line.number)
+            .unwrap_or(lines.len())
+    };
+    let mut out: Vec<LintSuppression> = Vec::new();
+    let mut cursor = 0usize;
+    while let Some(found) = content[cursor..].find("<!--") {
+        let body_start = cursor + found + 4;
+        let Some(body_end) = content[body_start..]
+            .find("-->")
+            .map(|index| body_start + index)
+        else {
+            break;
+        };
+        cursor = body_end + 3;
+        let body = content[body_start..body_end].trim();
+        let (directive, rest) = body.split_once(char::is_whitespace).unwrap_or((body, ""));
+        let rule_ids = rest
+            .split(|ch: char| ch.is_whitespace() || ch == ',')
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        match directive {
+            "wikitool-disable-next-line" => {
+                let line = line_number(body_end) + 1;
+                out.push(LintSuppression {
+                    rule_ids,
+                    first_line: line,
+                    last_line: Some(line),
+                });
+            }
+            "wikitool-disable" => out.push(LintSuppression {
+                rule_ids,
+                first_line: line_number(body_start),
+                last_line: None,
+            }),
+            "wikitool-enable" => {
+                let line = line_number(body_start);
+                for open in out
+                    .iter_mut()
+                    .filter(|suppression| suppression.last_line.is_none())
+                {
+                    if rule_ids.is_empty() || rule_ids.iter().any(|id| open.rule_ids.contains(id)) {
+                        open.last_line = Some(line);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
 fn normalize_title_override(title_override: Option<&str>) -> Result<Option<String>> {
     let Some(title_override) = title_override else {
         return Ok(None);
@@ -296,9 +409,11 @@ fn extract_template_occurrences(content: &str) -> Vec<TemplateOccurrence> {
                 let raw_name = segments.first().map(String::as_str).unwrap_or("").trim();
                 if let Some(template_title) = canonical_template_title(raw_name) {
                     let parameter_keys = collect_parameter_keys(&segments);
+                    let parameters = collect_named_parameters(inner, start + 2);
                     out.push(TemplateOccurrence {
                         template_title,
                         parameter_keys,
+                        parameters,
                         raw_wikitext: content[start..cursor + 2].to_string(),
                         start,
                         end: cursor + 2,
@@ -337,6 +452,35 @@ fn collect_parameter_keys(segments: &[String]) -> Vec<String> {
     out
 }
 
+fn collect_named_parameters(inner: &str, offset: usize) -> Vec<TemplateParameterOccurrence> {
+    let mut out = Vec::new();
+    for (segment_start, segment_end) in split_template_segment_ranges(inner).into_iter().skip(1) {
+        let segment = &inner[segment_start..segment_end];
+        let Some(equals) = top_level_equals_index(segment) else {
+            continue;
+        };
+        let raw_key = &segment[..equals];
+        let key = normalize_template_parameter_key(raw_key);
+        if key.is_empty() {
+            continue;
+        }
+        let raw_value = &segment[equals + 1..];
+        let key_start = segment_start + (raw_key.len() - raw_key.trim_start().len());
+        let value_start =
+            segment_start + equals + 1 + (raw_value.len() - raw_value.trim_start().len());
+        let value = raw_value.trim();
+        out.push(TemplateParameterOccurrence {
+            key,
+            value: value.to_string(),
+            key_start: offset + key_start,
+            key_end: offset + segment_start + raw_key.trim_end().len(),
+            value_start: offset + value_start,
+            value_end: offset + value_start + value.len(),
+        });
+    }
+    out
+}
+
 fn extract_module_invocation_occurrences(content: &str) -> Vec<ModuleInvocationOccurrence> {
     let bytes = content.as_bytes();
     let mut out = Vec::new();
@@ -473,6 +617,13 @@ fn extract_templatestyles_occurrences(content: &str) -> Vec<TemplateStylesOccurr
 }
 
 fn split_template_segments(inner: &str) -> Vec<String> {
+    split_template_segment_ranges(inner)
+        .into_iter()
+        .map(|(start, end)| inner[start..end].to_string())
+        .collect()
+}
+
+fn split_template_segment_ranges(inner: &str) -> Vec<(usize, usize)> {
     let bytes = inner.as_bytes();
     let mut out = Vec::new();
     let mut cursor = 0usize;
@@ -502,7 +653,7 @@ fn split_template_segments(inner: &str) -> Vec<String> {
             continue;
         }
         if bytes[cursor] == b'|' && template_depth == 0 && link_depth == 0 {
-            out.push(inner[segment_start..cursor].to_string());
+            out.push((segment_start, cursor));
             cursor += 1;
             segment_start = cursor;
             continue;
@@ -510,11 +661,19 @@ fn split_template_segments(inner: &str) -> Vec<String> {
         cursor += 1;
     }
 
-    out.push(inner[segment_start..].to_string());
+    out.push((segment_start, inner.len()));
     out
 }
 
 fn split_once_top_level_equals(value: &str) -> Option<(String, String)> {
+    let index = top_level_equals_index(value)?;
+    Some((
+        value[..index].trim().to_string(),
+        value[index + 1..].trim().to_string(),
+    ))
+}
+
+fn top_level_equals_index(value: &str) -> Option<usize> {
     let bytes = value.as_bytes();
     let mut cursor = 0usize;
     let mut template_depth = 0usize;
@@ -542,10 +701,7 @@ fn split_once_top_level_equals(value: &str) -> Option<(String, String)> {
             continue;
         }
         if bytes[cursor] == b'=' && template_depth == 0 && link_depth == 0 {
-            return Some((
-                value[..cursor].trim().to_string(),
-                value[cursor + 1..].trim().to_string(),
-            ));
+            return Some(cursor);
         }
         cursor += 1;
     }
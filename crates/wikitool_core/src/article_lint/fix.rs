@@ -7,6 +7,20 @@ pub(crate) struct TextEdit {
     pub(crate) replacement: String,
 }
 
+/// Whether two edits touch the same text. An insertion conflicts only when it
+/// lands strictly inside the other edit's range.
+pub(crate) fn edits_overlap(left: &TextEdit, right: &TextEdit) -> bool {
+    if left.start == left.end || right.start == right.end {
+        let (point, range) = if left.start == left.end {
+            (left.start, right)
+        } else {
+            (right.start, left)
+        };
+        return range.start < point && point < range.end;
+    }
+    left.start < right.end && right.start < left.end
+}
+
 pub(crate) fn apply_text_edits(content: &str, edits: &[TextEdit]) -> Result<String> {
     if edits.is_empty() {
         return Ok(content.to_string());
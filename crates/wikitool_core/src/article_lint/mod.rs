@@ -1,3 +1,5 @@
+mod baseline;
+mod batch;
 mod document;
 mod fix;
 mod model;
@@ -10,15 +12,24 @@ use std::path::Path;
 
 use anyhow::{Context, Result};
 
+use crate::knowledge::quality::{load_inbound_link_count, page_quality_metrics};
 use crate::runtime::ResolvedPaths;
 
+pub use baseline::{LintBaselineWriteSummary, write_lint_baseline};
+pub use batch::{
+    ArticleLintTargetFilter, aggregate_article_lint, collect_article_lint_targets,
+    lint_articles_with_resources,
+};
 pub use model::{
-    AppliedFixRecord, ArticleFixApplyMode, ArticleFixResult, ArticleLintIssue, ArticleLintReport,
-    ArticleLintResourcesStatus, ArticleLintSeverity, SuggestedFix, SuggestedFixKind, TextSpan,
+    AppliedFixRecord, ArticleFixApplyMode, ArticleFixResult, ArticleLintAggregate,
+    ArticleLintIssue, ArticleLintPageSummary, ArticleLintReport, ArticleLintResourcesStatus,
+    ArticleLintRuleCount, ArticleLintSeverity, ArticleQualityMetrics, FixEdit, SuggestedFix,
+    SuggestedFixKind, TextSpan,
 };
 
+use baseline::issue_fingerprint;
 use document::{ParsedArticleDocument, load_article_document_with_title};
-use fix::apply_text_edits;
+use fix::{apply_text_edits, edits_overlap};
 use resources::{LoadedResources, load_resources};
 use rules::{IssueMatch, SafeFixEdit, collect_issue_matches};
 
@@ -47,6 +58,28 @@ pub fn load_article_lint_resources(paths: &ResolvedPaths) -> Result<ArticleLintR
     })
 }
 
+impl ArticleLintResources {
+    /// Report baselined issues again, as `article lint --write-baseline` needs
+    /// the full issue set to record.
+    pub fn without_baseline(mut self) -> Self {
+        self.inner.baseline = None;
+        self
+    }
+
+    /// Age stale claims against `unix_seconds` instead of today.
+    pub fn with_reference_time(mut self, unix_seconds: u64) -> Self {
+        self.inner.reference_time = Some(unix_seconds);
+        self
+    }
+
+    /// Count inbound links for each report's quality metrics. This costs one
+    /// index query per page, so only callers that show the metrics ask for it.
+    pub fn with_inbound_links(mut self) -> Self {
+        self.inner.inbound_links = true;
+        self
+    }
+}
+
 pub fn lint_article(paths: &ResolvedPaths, article_path: &Path) -> Result<ArticleLintReport> {
     lint_article_with_title(paths, article_path, None)
 }
@@ -68,7 +101,7 @@ pub fn lint_article_with_resources(
 ) -> Result<ArticleLintReport> {
     let document = load_article_document_with_title(paths, article_path, title_override)?;
     let matches = collect_issue_matches(paths, &document, &resources.inner)?;
-    Ok(build_report(&document, &resources.inner, matches))
+    build_report(&document, &resources.inner, matches)
 }
 
 pub fn fix_article(
@@ -87,7 +120,10 @@ pub fn fix_article_with_title(
 ) -> Result<ArticleFixResult> {
     let document = load_article_document_with_title(paths, article_path, title_override)?;
     let resources = load_article_lint_resources(paths)?;
-    let matches = collect_issue_matches(paths, &document, &resources.inner)?;
+    let (matches, _) = drop_suppressed(
+        &document,
+        collect_issue_matches(paths, &document, &resources.inner)?,
+    );
     let safe_fixes = collect_safe_fixes(&matches);
     let changed = apply_mode == ArticleFixApplyMode::Safe && !safe_fixes.is_empty();
     if changed {
@@ -134,10 +170,41 @@ fn build_report(
     document: &ParsedArticleDocument,
     resources: &LoadedResources,
     matches: Vec<IssueMatch>,
-) -> ArticleLintReport {
+) -> Result<ArticleLintReport> {
+    let inbound_links = resources
+        .index_connection
+        .as_ref()
+        .filter(|_| resources.inbound_links)
+        .map(|connection| load_inbound_link_count(connection, &document.title))
+        .transpose()?;
+    let quality = page_quality_metrics(
+        &document.title,
+        &document.namespace,
+        &document.content,
+        inbound_links,
+    )
+    .into();
+    let (matches, suppressed) = drop_suppressed(document, matches);
+    let mut allowances = resources
+        .baseline
+        .as_ref()
+        .map(|baseline| baseline.allowances_for(&document.relative_path))
+        .unwrap_or_default();
+    let mut baselined = 0usize;
     let issues = matches
         .into_iter()
         .map(|item| item.issue)
+        .filter(|issue| {
+            let key = (issue.rule_id.clone(), issue_fingerprint(issue));
+            match allowances.get_mut(&key) {
+                Some(remaining) if *remaining > 0 => {
+                    *remaining -= 1;
+                    baselined += 1;
+                    false
+                }
+                _ => true,
+            }
+        })
         .collect::<Vec<_>>();
     let errors = issues
         .iter()
@@ -152,7 +219,7 @@ fn build_report(
         .filter(|issue| issue.severity == ArticleLintSeverity::Suggestion)
         .count();
 
-    ArticleLintReport {
+    Ok(ArticleLintReport {
         schema_version: ARTICLE_LINT_SCHEMA_VERSION.to_string(),
         profile_id: REMILIA_PROFILE_ID.to_string(),
         relative_path: document.relative_path.clone(),
@@ -162,29 +229,64 @@ fn build_report(
         errors,
         warnings,
         suggestions,
+        suppressed,
+        baselined,
         resources: ArticleLintResourcesStatus {
             capabilities_loaded: resources.capabilities.is_some(),
             template_catalog_loaded: resources.template_catalog.is_some(),
             index_ready: resources.index_connection.is_some(),
             graph_ready: resources.index_connection.is_some(),
         },
+        quality,
         issues,
-    }
+    })
 }
 
+/// Drop issues silenced by inline `wikitool-disable` comments, returning how
+/// many were dropped. Their safe fixes go with them.
+fn drop_suppressed(
+    document: &ParsedArticleDocument,
+    matches: Vec<IssueMatch>,
+) -> (Vec<IssueMatch>, usize) {
+    let before = matches.len();
+    let kept = matches
+        .into_iter()
+        .filter(|item| {
+            !document.is_suppressed(
+                &item.issue.rule_id,
+                item.issue.span.as_ref().map(|span| span.line),
+            )
+        })
+        .collect::<Vec<_>>();
+    let suppressed = before - kept.len();
+    (kept, suppressed)
+}
+
+/// Gather safe fixes to apply, skipping any edit that overlaps one already
+/// accepted so the rest of the run still applies; the skipped edit is left for
+/// the next fix run. An issue's atomic edits are taken together or not at all,
+/// so multi-edit fixes never apply halfway.
 fn collect_safe_fixes(matches: &[IssueMatch]) -> Vec<SafeFixEdit> {
     let mut seen = BTreeSet::new();
-    let mut out = Vec::new();
+    let mut out: Vec<SafeFixEdit> = Vec::new();
     for issue in matches {
-        for fix in &issue.safe_fixes {
-            let key = (
-                fix.edit.start,
-                fix.edit.end,
-                fix.edit.replacement.clone(),
-                fix.rule_id.clone(),
-                fix.label.clone(),
-            );
-            if seen.insert(key) {
+        let fresh = issue
+            .safe_fixes
+            .iter()
+            .filter(|fix| !seen.contains(&safe_fix_key(fix)))
+            .collect::<Vec<_>>();
+        let overlaps_accepted = |fix: &SafeFixEdit, out: &[SafeFixEdit]| {
+            out.iter()
+                .any(|accepted| edits_overlap(&accepted.edit, &fix.edit))
+        };
+        let atomic_blocked = fresh
+            .iter()
+            .any(|fix| fix.atomic && overlaps_accepted(fix, &out));
+        for fix in fresh {
+            if (fix.atomic && atomic_blocked) || (!fix.atomic && overlaps_accepted(fix, &out)) {
+                continue;
+            }
+            if seen.insert(safe_fix_key(fix)) {
                 out.push(fix.clone());
             }
         }
@@ -199,5 +301,15 @@ fn collect_safe_fixes(matches: &[IssueMatch]) -> Vec<SafeFixEdit> {
     out
 }
 
+fn safe_fix_key(fix: &SafeFixEdit) -> (usize, usize, String, String, String) {
+    (
+        fix.edit.start,
+        fix.edit.end,
+        fix.edit.replacement.clone(),
+        fix.rule_id.clone(),
+        fix.label.clone(),
+    )
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::knowledge::quality::PageQualityMetrics;
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "snake_case")]
 pub enum ArticleLintSeverity {
@@ -43,6 +45,17 @@ pub struct SuggestedFix {
     pub replacement_preview: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub patch: Option<String>,
+    /// The exact edits a safe autofix makes, for editors and SARIF consumers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub edits: Vec<FixEdit>,
+}
+
+/// Replace the text inside `span` (end column exclusive) with `replacement`;
+/// an empty span is an insertion.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FixEdit {
+    pub span: TextSpan,
+    pub replacement: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -79,10 +92,66 @@ pub struct ArticleLintReport {
     pub errors: usize,
     pub warnings: usize,
     pub suggestions: usize,
+    /// Issues silenced by `wikitool-disable` comments; not counted above.
+    #[serde(default)]
+    pub suppressed: usize,
+    /// Issues already recorded in the project lint baseline; not counted above.
+    #[serde(default)]
+    pub baselined: usize,
     pub resources: ArticleLintResourcesStatus,
+    /// Content quality metrics; inbound links are only counted when requested.
+    pub quality: ArticleQualityMetrics,
     pub issues: Vec<ArticleLintIssue>,
 }
 
+/// [`PageQualityMetrics`] for a lint report, with the ratios kept in
+/// thousandths so reports stay comparable with `Eq`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArticleQualityMetrics {
+    pub prose_words: usize,
+    pub reference_count: usize,
+    pub references_per_100_words_milli: u64,
+    pub uncited_paragraphs: usize,
+    pub paragraph_count: usize,
+    pub section_count: usize,
+    pub largest_section_share_milli: u64,
+    pub lead_words: usize,
+    pub image_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbound_links: Option<usize>,
+}
+
+impl ArticleQualityMetrics {
+    pub fn references_per_100_words(&self) -> f64 {
+        self.references_per_100_words_milli as f64 / 1000.0
+    }
+
+    pub fn largest_section_share(&self) -> f64 {
+        self.largest_section_share_milli as f64 / 1000.0
+    }
+}
+
+impl From<PageQualityMetrics> for ArticleQualityMetrics {
+    fn from(metrics: PageQualityMetrics) -> Self {
+        Self {
+            prose_words: metrics.prose_words,
+            reference_count: metrics.reference_count,
+            references_per_100_words_milli: to_milli(metrics.references_per_100_words),
+            uncited_paragraphs: metrics.uncited_paragraphs,
+            paragraph_count: metrics.paragraph_count,
+            section_count: metrics.section_count,
+            largest_section_share_milli: to_milli(metrics.largest_section_share),
+            lead_words: metrics.lead_words,
+            image_count: metrics.image_count,
+            inbound_links: metrics.inbound_links,
+        }
+    }
+}
+
+fn to_milli(value: f64) -> u64 {
+    (value * 1000.0).round() as u64
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ArticleFixApplyMode {
@@ -120,3 +189,39 @@ pub struct ArticleFixResult {
     pub applied_fixes: Vec<AppliedFixRecord>,
     pub remaining_report: ArticleLintReport,
 }
+
+/// Issue totals for one rule across a batch lint run.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArticleLintRuleCount {
+    pub rule_id: String,
+    pub issue_count: usize,
+    pub page_count: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub suggestions: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArticleLintPageSummary {
+    pub relative_path: String,
+    pub title: String,
+    pub errors: usize,
+    pub warnings: usize,
+    pub suggestions: usize,
+    pub suppressed: usize,
+    pub baselined: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArticleLintAggregate {
+    pub target_count: usize,
+    pub total_errors: usize,
+    pub total_warnings: usize,
+    pub total_suggestions: usize,
+    pub total_suppressed: usize,
+    pub total_baselined: usize,
+    /// Most frequent rules first.
+    pub rules: Vec<ArticleLintRuleCount>,
+    /// Pages in lint order.
+    pub pages: Vec<ArticleLintPageSummary>,
+}
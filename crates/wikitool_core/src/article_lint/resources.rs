@@ -1,8 +1,15 @@
 use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Context, Result, bail};
+use regex::Regex;
 use rusqlite::Connection;
 
+use super::baseline::{LintBaseline, load_lint_baseline};
+use super::rules::BUILTIN_RULE_FAMILIES;
+use crate::config::{CustomLintMatcher, CustomLintRule, load_lint_rules};
 use crate::content_store::parsing::open_indexed_connection;
 use crate::filesystem::{ScanOptions, scan_files};
 use crate::profile::{
@@ -11,20 +18,45 @@ use crate::profile::{
     scan_local_module_functions, scan_local_module_titles,
 };
 use crate::runtime::ResolvedPaths;
+use crate::support::{table_exists, unix_timestamp};
+use crate::wikitext::spelling::{SpellingDictionary, WordList};
 
+const SPELLING_DICTIONARY_FILE_NAME: &str = "spelling-dictionary.txt";
+const SPELLING_WORDS_FILE_NAME: &str = "spelling-words.txt";
+/// Word list installed by most Unix systems, typically from SCOWL packages
+/// such as `wamerican` or `wbritish`, which carry their own license notes.
+const SYSTEM_WORDS_PATH: &str = "/usr/share/dict/words";
+
+/// Project data is read-only once loaded and sits behind `Arc`s, so batch
+/// workers share it; only the index connection is per worker.
 #[derive(Debug)]
 pub(super) struct LoadedResources {
-    pub(super) overlay: ProfileOverlay,
-    pub(super) capabilities: Option<WikiCapabilityManifest>,
-    pub(super) template_catalog: Option<TemplateCatalog>,
-    pub(super) local_module_titles: BTreeSet<String>,
-    pub(super) local_module_functions: BTreeMap<String, BTreeSet<String>>,
-    pub(super) local_asset_titles: BTreeSet<String>,
+    pub(super) overlay: Arc<ProfileOverlay>,
+    pub(super) capabilities: Option<Arc<WikiCapabilityManifest>>,
+    pub(super) template_catalog: Option<Arc<TemplateCatalog>>,
+    pub(super) local_module_titles: Arc<BTreeSet<String>>,
+    pub(super) local_module_functions: Arc<BTreeMap<String, BTreeSet<String>>>,
+    pub(super) local_asset_titles: Arc<BTreeSet<String>>,
     /// Lowercased single words drawn from local page/template titles and the profile's
     /// configured proper nouns. The sentence-case heading rule treats these as proper
     /// nouns that may stay capitalized mid-heading.
-    pub(super) proper_noun_words: BTreeSet<String>,
+    pub(super) proper_noun_words: Arc<BTreeSet<String>>,
     pub(super) index_connection: Option<Connection>,
+    pub(super) custom_rules: Arc<Vec<CompiledLintRule>>,
+    pub(super) baseline: Option<Arc<LintBaseline>>,
+    pub(super) spelling: Arc<SpellingDictionary>,
+    /// Fixed "now" for stale-claim ages, in UNIX seconds. When unset, ages
+    /// are measured from today.
+    pub(super) reference_time: Option<u64>,
+    /// Whether reports count inbound links for their quality metrics.
+    pub(super) inbound_links: bool,
+}
+
+/// A project-declared rule with its pattern compiled once per lint run.
+#[derive(Debug, Clone)]
+pub(super) struct CompiledLintRule {
+    pub(super) rule: CustomLintRule,
+    pub(super) pattern: Option<Regex>,
 }
 
 pub(super) fn load_resources(paths: &ResolvedPaths) -> Result<LoadedResources> {
@@ -48,19 +80,155 @@ pub(super) fn load_resources(paths: &ResolvedPaths) -> Result<LoadedResources> {
     let local_asset_titles = scan_local_asset_titles(paths)?;
     let proper_noun_words = build_proper_noun_words(paths, &overlay)?;
     let index_connection = open_indexed_connection(paths)?;
+    let custom_rules = compile_custom_rules(load_lint_rules(&paths.config_path)?)?;
+    let baseline = load_lint_baseline(paths)?;
+    let spelling = build_spelling_dictionary(paths, &overlay, index_connection.as_ref())?;
 
     Ok(LoadedResources {
-        overlay,
-        capabilities,
-        template_catalog,
-        local_module_titles,
-        local_module_functions,
-        local_asset_titles,
-        proper_noun_words,
+        overlay: Arc::new(overlay),
+        capabilities: capabilities.map(Arc::new),
+        template_catalog: template_catalog.map(Arc::new),
+        local_module_titles: Arc::new(local_module_titles),
+        local_module_functions: Arc::new(local_module_functions),
+        local_asset_titles: Arc::new(local_asset_titles),
+        proper_noun_words: Arc::new(proper_noun_words),
         index_connection,
+        custom_rules: Arc::new(custom_rules),
+        baseline: baseline.map(Arc::new),
+        spelling: Arc::new(spelling),
+        reference_time: None,
+        inbound_links: false,
     })
 }
 
+impl LoadedResources {
+    /// The same project data with its own index connection, for a batch lint
+    /// worker thread.
+    pub(super) fn fork(&self, paths: &ResolvedPaths) -> Result<Self> {
+        Ok(Self {
+            overlay: self.overlay.clone(),
+            capabilities: self.capabilities.clone(),
+            template_catalog: self.template_catalog.clone(),
+            local_module_titles: self.local_module_titles.clone(),
+            local_module_functions: self.local_module_functions.clone(),
+            local_asset_titles: self.local_asset_titles.clone(),
+            proper_noun_words: self.proper_noun_words.clone(),
+            index_connection: if self.index_connection.is_some() {
+                open_indexed_connection(paths)?
+            } else {
+                None
+            },
+            custom_rules: self.custom_rules.clone(),
+            baseline: self.baseline.clone(),
+            spelling: self.spelling.clone(),
+            reference_time: self.reference_time,
+            inbound_links: self.inbound_links,
+        })
+    }
+
+    /// Day, since the UNIX epoch, that stale claims are aged against: the
+    /// injected reference time, else today.
+    pub(super) fn reference_day(&self) -> Result<i64> {
+        let seconds = match self.reference_time {
+            Some(seconds) => seconds,
+            None => unix_timestamp()?,
+        };
+        Ok((seconds / 86_400) as i64)
+    }
+}
+
+fn compile_custom_rules(rules: Vec<CustomLintRule>) -> Result<Vec<CompiledLintRule>> {
+    rules
+        .into_iter()
+        .map(|rule| {
+            let family = rule.id.split('.').next().unwrap_or_default();
+            if BUILTIN_RULE_FAMILIES
+                .iter()
+                .any(|builtin| family.trim().eq_ignore_ascii_case(builtin))
+            {
+                bail!(
+                    "custom lint rule id `{}` uses the built-in `{family}.` prefix",
+                    rule.id
+                );
+            }
+            let pattern = match &rule.matcher {
+                CustomLintMatcher::Prose { pattern, .. }
+                | CustomLintMatcher::Heading { pattern, .. }
+                | CustomLintMatcher::LinkTarget { pattern, .. } => Some(
+                    Regex::new(pattern)
+                        .with_context(|| format!("invalid pattern in lint rule `{}`", rule.id))?,
+                ),
+                CustomLintMatcher::TemplatePresent { .. }
+                | CustomLintMatcher::TemplateAbsent { .. }
+                | CustomLintMatcher::TemplateParameter { .. } => None,
+            };
+            Ok(CompiledLintRule { rule, pattern })
+        })
+        .collect()
+}
+
+/// Project words the spelling rule accepts on top of the general word list:
+/// the profile's `proper_nouns`, indexed page titles and aliases, and
+/// `.wikitool/spelling-dictionary.txt` (one term per line, `#` comments).
+fn build_spelling_dictionary(
+    paths: &ResolvedPaths,
+    overlay: &ProfileOverlay,
+    connection: Option<&Connection>,
+) -> Result<SpellingDictionary> {
+    let mut terms = overlay.lint.proper_nouns.clone();
+    if let Some(connection) = connection {
+        for (table, column) in [
+            ("indexed_pages", "title"),
+            ("indexed_page_aliases", "alias_title"),
+        ] {
+            if !table_exists(connection, table)? {
+                continue;
+            }
+            let mut statement = connection
+                .prepare(&format!("SELECT {column} FROM {table}"))
+                .with_context(|| format!("failed to prepare {table} spelling query"))?;
+            let rows = statement
+                .query_map([], |row| row.get::<_, String>(0))
+                .with_context(|| format!("failed to run {table} spelling query"))?;
+            for row in rows {
+                terms.push(row.with_context(|| format!("failed to decode {table} row"))?);
+            }
+        }
+    }
+    let user_path = paths.state_dir.join(SPELLING_DICTIONARY_FILE_NAME);
+    if user_path.exists() {
+        let content = fs::read_to_string(&user_path)
+            .with_context(|| format!("failed to read {}", user_path.display()))?;
+        terms.extend(
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_string),
+        );
+    }
+    Ok(SpellingDictionary::new(
+        Arc::new(load_spelling_word_list(paths)?),
+        terms.iter().map(String::as_str),
+    ))
+}
+
+/// General English words for the spelling rule: `.wikitool/spelling-words.txt`
+/// when the project ships one, else the system word list. With neither, the
+/// list is empty and the rule reports nothing.
+fn load_spelling_word_list(paths: &ResolvedPaths) -> Result<WordList> {
+    let project_path = paths.state_dir.join(SPELLING_WORDS_FILE_NAME);
+    let system_path = Path::new(SYSTEM_WORDS_PATH);
+    let Some(path) = [project_path.as_path(), system_path]
+        .into_iter()
+        .find(|path| path.is_file())
+    else {
+        return Ok(WordList::default());
+    };
+    let bytes = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    Ok(WordList::parse(&String::from_utf8_lossy(&bytes)))
+}
+
 /// Build the proper-noun vocabulary the sentence-case rule consults. Sources, in order:
 /// the profile's configured `proper_nouns`, then local main/template titles. Title-derived
 /// words are intentionally narrower than profile terms: a MediaWiki title's first word is
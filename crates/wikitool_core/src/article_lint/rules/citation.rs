@@ -1,11 +1,31 @@
-use crate::article_lint::document::ParsedArticleDocument;
+use std::collections::{BTreeMap, BTreeSet};
+
+use sha2::{Digest, Sha256};
+
+use crate::article_lint::document::{
+    ParsedArticleDocument, TemplateOccurrence, TemplateParameterOccurrence,
+};
 use crate::article_lint::fix::TextEdit;
 use crate::article_lint::model::{ArticleLintIssue, ArticleLintSeverity};
-use crate::content_store::parsing::make_content_preview;
+use crate::article_lint::resources::LoadedResources;
+use crate::content_store::parsing::{
+    extract_reference_records_for_section, find_closing_html_tag, make_content_preview,
+    normalize_spaces, normalize_template_parameter_key, parse_html_attributes, parse_open_tag,
+};
+use crate::profile::{
+    TemplateCatalogEntryLookup, TemplateDataParameter, TemplateDataRecord,
+    find_template_catalog_entry,
+};
+use crate::support::days_in_month;
+use crate::wikitext::dates::{parse_day, parse_month_name};
 
-use super::common::safe_fix_for_edit;
+use super::common::{safe_fix_for_edit, safe_fix_for_edits};
 use super::{IssueMatch, SafeFixEdit};
 
+const GENERATED_NAME_MAX_LEN: usize = 40;
+const NAME_TITLE_STOPWORDS: &[&str] = &["the", "and", "for", "with", "from", "into", "about"];
+const DOMAIN_SUFFIX_LABELS: &[&str] = &["co", "com", "org", "net", "gov", "ac", "edu"];
+
 pub(super) fn lint_citation_after_punctuation(
     document: &ParsedArticleDocument,
     matches: &mut Vec<IssueMatch>,
@@ -70,8 +90,701 @@ pub(super) fn lint_citation_after_punctuation(
                     .line_for_offset(first_reference.start)
                     .map(|line| line.number),
                 edit,
+                atomic: false,
             }],
         });
         index = cluster_end + 1;
     }
 }
+
+#[derive(Debug, Clone)]
+struct RefBody {
+    start: usize,
+    end: usize,
+    /// Offset just past `<ref`, where a `name=` attribute can be inserted.
+    name_insert_at: usize,
+    name: Option<String>,
+    group: Option<String>,
+    wikitext: String,
+}
+
+/// Repeated `<ref>` bodies become one named reference: the first occurrence
+/// gains a generated `name=` and later ones collapse to `<ref name="..." />`.
+pub(super) fn lint_duplicate_references(
+    document: &ParsedArticleDocument,
+    resources: &LoadedResources,
+    matches: &mut Vec<IssueMatch>,
+) {
+    if !resources.overlay.citations.use_named_references {
+        return;
+    }
+    let mut used_names = BTreeSet::new();
+    let mut groups = BTreeMap::<(Option<String>, String), Vec<RefBody>>::new();
+    for reference in &document.references {
+        let Some((tag_end, tag_body, self_closing)) =
+            parse_open_tag(&document.content, reference.start, "ref")
+        else {
+            continue;
+        };
+        let attributes = parse_html_attributes(&tag_body);
+        let name = attributes
+            .get("name")
+            .map(|value| normalize_spaces(value))
+            .filter(|value| !value.is_empty());
+        if let Some(name) = &name {
+            used_names.insert(name.clone());
+        }
+        if self_closing {
+            continue;
+        }
+        let Some((close_start, close_end)) =
+            find_closing_html_tag(&document.content, tag_end, "ref")
+        else {
+            continue;
+        };
+        let body = normalize_spaces(&document.content[tag_end..close_start]);
+        if body.is_empty() {
+            continue;
+        }
+        let group = attributes
+            .get("group")
+            .map(|value| normalize_spaces(value))
+            .filter(|value| !value.is_empty());
+        groups
+            .entry((group.clone(), body))
+            .or_default()
+            .push(RefBody {
+                start: reference.start,
+                end: close_end,
+                name_insert_at: reference.start + "<ref".len(),
+                name,
+                group,
+                wikitext: document.content[reference.start..close_end].to_string(),
+            });
+    }
+
+    let mut duplicates = groups
+        .into_values()
+        .filter(|occurrences| occurrences.len() > 1)
+        .collect::<Vec<_>>();
+    duplicates.sort_by_key(|occurrences| occurrences[0].start);
+    for occurrences in duplicates {
+        let existing = occurrences
+            .iter()
+            .filter_map(|occurrence| occurrence.name.clone())
+            .collect::<BTreeSet<_>>();
+        // Two different names already point at this body; merging would orphan
+        // the self-closing uses of one of them.
+        if existing.len() > 1 {
+            continue;
+        }
+        let first = &occurrences[0];
+        let name = match existing.into_iter().next() {
+            Some(name) => name,
+            None => {
+                let name =
+                    unique_reference_name(&generated_reference_name(&first.wikitext), &used_names);
+                used_names.insert(name.clone());
+                name
+            }
+        };
+        let group_attribute = first
+            .group
+            .as_deref()
+            .map(|group| format!(" group=\"{group}\""))
+            .unwrap_or_default();
+        let short_form = format!("<ref name=\"{name}\"{group_attribute} />");
+
+        let mut edits = Vec::new();
+        if first.name.is_none() {
+            edits.push(TextEdit {
+                start: first.name_insert_at,
+                end: first.name_insert_at,
+                replacement: format!(" name=\"{name}\""),
+            });
+        }
+        for occurrence in &occurrences[1..] {
+            edits.push(TextEdit {
+                start: occurrence.start,
+                end: occurrence.end,
+                replacement: short_form.clone(),
+            });
+        }
+        let label = format!("Merge repeated reference into name=\"{name}\"");
+        matches.push(IssueMatch {
+            issue: ArticleLintIssue {
+                rule_id: "citation.duplicate_reference".to_string(),
+                severity: ArticleLintSeverity::Warning,
+                message: format!(
+                    "The same reference body is repeated {} times; define it once and reuse it by name.",
+                    occurrences.len()
+                ),
+                span: document.span_for_range(occurrences[1].start, occurrences[1].end),
+                evidence: Some(make_content_preview(&first.wikitext, 96)),
+                suggested_remediation: Some(format!(
+                    "Name the first occurrence and replace later copies with {short_form}."
+                )),
+                suggested_fixes: vec![safe_fix_for_edits(document, &edits, &label, &short_form)],
+            },
+            safe_fixes: edits
+                .into_iter()
+                .map(|edit| SafeFixEdit {
+                    rule_id: "citation.duplicate_reference".to_string(),
+                    label: label.clone(),
+                    line: document.line_for_offset(edit.start).map(|line| line.number),
+                    edit,
+                    atomic: true,
+                })
+                .collect(),
+        });
+    }
+}
+
+/// Citations whose URL, domain or publisher matches a source the profile
+/// lists as unreliable.
+pub(super) fn lint_unreliable_sources(
+    document: &ParsedArticleDocument,
+    resources: &LoadedResources,
+    matches: &mut Vec<IssueMatch>,
+) {
+    let rules = &resources.overlay.citations.unreliable_sources;
+    if rules.is_empty() {
+        return;
+    }
+    for reference in &document.references {
+        let Some((tag_end, _, self_closing)) =
+            parse_open_tag(&document.content, reference.start, "ref")
+        else {
+            continue;
+        };
+        if self_closing {
+            continue;
+        }
+        let Some((_, close_end)) = find_closing_html_tag(&document.content, tag_end, "ref") else {
+            continue;
+        };
+        let wikitext = &document.content[reference.start..close_end];
+        let Some(record) = extract_reference_records_for_section(None, wikitext)
+            .into_iter()
+            .next()
+        else {
+            continue;
+        };
+        let mut urls = record.source_urls.clone();
+        urls.push(record.canonical_url.clone());
+        let Some(rule) = rules.iter().find(|rule| {
+            rule.matches_citation(&record.source_domain, &urls, &record.source_container)
+        }) else {
+            continue;
+        };
+        let reason = if rule.reason.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", rule.reason)
+        };
+        matches.push(IssueMatch {
+            issue: ArticleLintIssue {
+                rule_id: "citation.unreliable_source".to_string(),
+                severity: ArticleLintSeverity::Error,
+                message: format!(
+                    "Reference cites {}, which the style guide lists as unreliable{reason}.",
+                    rule.label
+                ),
+                span: document.span_for_range(reference.start, close_end),
+                evidence: Some(make_content_preview(wikitext, 96)),
+                suggested_remediation: Some(
+                    "Replace it with a reliable source, or note the lack of reliable sourcing instead of citing it."
+                        .to_string(),
+                ),
+                suggested_fixes: Vec::new(),
+            },
+            safe_fixes: Vec::new(),
+        });
+    }
+}
+
+/// Stable reference name from author surname (or site), then leading title words.
+fn generated_reference_name(reference_wikitext: &str) -> String {
+    let record = extract_reference_records_for_section(None, reference_wikitext)
+        .into_iter()
+        .next();
+    let mut parts = Vec::new();
+    if let Some(record) = &record {
+        if let Some(surname) = author_surname(&record.source_author) {
+            parts.push(surname);
+        } else if let Some(site) = domain_label(&record.source_domain) {
+            parts.push(site);
+        }
+        parts.extend(
+            record
+                .reference_title
+                .split_whitespace()
+                .map(slug_word)
+                .filter(|word| word.len() >= 3 && !NAME_TITLE_STOPWORDS.contains(&word.as_str()))
+                .take(2),
+        );
+    }
+    let mut name = String::new();
+    for part in parts.into_iter().filter(|part| !part.is_empty()) {
+        if !name.is_empty() && name.len() + part.len() + 1 > GENERATED_NAME_MAX_LEN {
+            break;
+        }
+        if !name.is_empty() {
+            name.push('-');
+        }
+        name.push_str(&part);
+    }
+    if name.is_empty() {
+        let digest = Sha256::digest(normalize_spaces(reference_wikitext).as_bytes());
+        let hex = digest
+            .iter()
+            .take(4)
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+        name = format!("ref-{hex}");
+    }
+    name
+}
+
+fn unique_reference_name(base: &str, used: &BTreeSet<String>) -> String {
+    if !used.contains(base) {
+        return base.to_string();
+    }
+    (2..)
+        .map(|index| format!("{base}-{index}"))
+        .find(|candidate| !used.contains(candidate))
+        .unwrap_or_else(|| base.to_string())
+}
+
+fn author_surname(author: &str) -> Option<String> {
+    let first_author = author
+        .split([';', '&'])
+        .next()?
+        .split(" and ")
+        .next()?
+        .trim();
+    let surname = match first_author.split_once(',') {
+        Some((last, _)) => last.trim(),
+        None => first_author.split_whitespace().last()?,
+    };
+    Some(slug_word(surname)).filter(|slug| !slug.is_empty())
+}
+
+fn domain_label(domain: &str) -> Option<String> {
+    let mut labels = domain.split('.').collect::<Vec<_>>();
+    labels.pop();
+    while labels.len() > 1
+        && labels
+            .last()
+            .is_some_and(|label| DOMAIN_SUFFIX_LABELS.contains(label))
+    {
+        labels.pop();
+    }
+    labels
+        .last()
+        .map(|label| slug_word(label))
+        .filter(|slug| !slug.is_empty())
+}
+
+fn slug_word(word: &str) -> String {
+    word.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect()
+}
+
+/// A core field and the parameter keys that satisfy it.
+type CoreField = (&'static str, &'static [&'static str]);
+
+/// Core fields each citation family must carry when TemplateData does not
+/// say otherwise.
+const CITATION_CORE_FIELDS: &[(&str, &[CoreField])] = &[
+    (
+        "web",
+        &[
+            ("title", &["title", "script-title"]),
+            ("url", &["url"]),
+            ("access-date", &["access-date", "accessdate", "access date"]),
+        ],
+    ),
+    (
+        "news",
+        &[
+            ("title", &["title", "script-title"]),
+            (
+                "work",
+                &[
+                    "work",
+                    "newspaper",
+                    "website",
+                    "magazine",
+                    "periodical",
+                    "agency",
+                ],
+            ),
+        ],
+    ),
+    (
+        "journal",
+        &[
+            ("title", &["title", "script-title"]),
+            ("journal", &["journal", "work", "periodical"]),
+        ],
+    ),
+    ("book", &[("title", &["title", "script-title"])]),
+];
+/// Parameter keys are normalized, so `access_date` arrives as `access date`.
+const CITATION_DATE_KEYS: &[&str] = &[
+    "date",
+    "publication-date",
+    "publication date",
+    "access-date",
+    "accessdate",
+    "access date",
+    "archive-date",
+    "archivedate",
+    "archive date",
+];
+/// Access and archive dates may stay in ISO form whatever style the other
+/// citation dates use.
+const ISO_ALLOWED_DATE_KEYS: &[&str] = &[
+    "access-date",
+    "accessdate",
+    "access date",
+    "archive-date",
+    "archivedate",
+    "archive date",
+];
+const MONTH_TITLES: &[&str] = &[
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Preferred citation templates, and any other `{{Cite ...}}` template, that
+/// omit the core fields for their family.
+pub(super) fn lint_citation_core_fields(
+    document: &ParsedArticleDocument,
+    resources: &LoadedResources,
+    matches: &mut Vec<IssueMatch>,
+) {
+    for template in &document.templates {
+        let Some(family) = citation_family(&template.template_title, resources) else {
+            continue;
+        };
+        let templatedata =
+            resources.template_catalog.as_ref().and_then(
+                |catalog| match find_template_catalog_entry(catalog, &template.template_title) {
+                    TemplateCatalogEntryLookup::Found(entry) => entry.templatedata,
+                    _ => None,
+                },
+            );
+        let missing = missing_core_fields(template, &family, templatedata.as_ref());
+        if missing.is_empty() {
+            continue;
+        }
+        matches.push(IssueMatch {
+            issue: ArticleLintIssue {
+                rule_id: "citation.missing_core_field".to_string(),
+                severity: ArticleLintSeverity::Warning,
+                message: format!(
+                    "Citation template is missing core fields: {}.",
+                    missing.join(", ")
+                ),
+                span: document.span_for_range(template.start, template.end),
+                evidence: Some(format!(
+                    "{} missing_fields={}",
+                    template.template_title,
+                    missing.join(", ")
+                )),
+                suggested_remediation: Some(
+                    "Fill the missing fields from the source itself; do not guess titles, publications, or access dates."
+                        .to_string(),
+                ),
+                suggested_fixes: Vec::new(),
+            },
+            safe_fixes: Vec::new(),
+        });
+    }
+}
+
+/// Citation dates written in more than one full-date style. Each date outside
+/// the article's style gets a safe fix; `{{Use dmy dates}}` and
+/// `{{Use mdy dates}}` pick the style, otherwise the most common one wins.
+pub(super) fn lint_citation_date_formats(
+    document: &ParsedArticleDocument,
+    resources: &LoadedResources,
+    matches: &mut Vec<IssueMatch>,
+) {
+    let mut dates = Vec::new();
+    for template in &document.templates {
+        if citation_family(&template.template_title, resources).is_none() {
+            continue;
+        }
+        for parameter in &template.parameters {
+            if !CITATION_DATE_KEYS.contains(&parameter.key.as_str()) {
+                continue;
+            }
+            let Some(date) = parse_citation_date(&parameter.value) else {
+                continue;
+            };
+            if date.style == CitationDateStyle::Iso
+                && ISO_ALLOWED_DATE_KEYS.contains(&parameter.key.as_str())
+            {
+                continue;
+            }
+            dates.push((parameter, date));
+        }
+    }
+    let Some(style) = declared_date_style(document).or_else(|| dominant_date_style(&dates)) else {
+        return;
+    };
+    if dates.iter().all(|(_, date)| date.style == style) {
+        return;
+    }
+
+    for (parameter, date) in dates {
+        if date.style == style {
+            continue;
+        }
+        let replacement = date.render(style);
+        let edit = TextEdit {
+            start: parameter.value_start,
+            end: parameter.value_end,
+            replacement: replacement.clone(),
+        };
+        let label = format!("Rewrite {} as {}", parameter.value, replacement);
+        matches.push(IssueMatch {
+            issue: ArticleLintIssue {
+                rule_id: "citation.mixed_date_format".to_string(),
+                severity: ArticleLintSeverity::Warning,
+                message: format!(
+                    "Citation date uses {} format while the article's citations use {}.",
+                    date.style.as_str(),
+                    style.as_str()
+                ),
+                span: document.span_for_range(parameter.value_start, parameter.value_end),
+                evidence: Some(format!("{}={}", parameter.key, parameter.value)),
+                suggested_remediation: Some(format!(
+                    "Write every citation date in {} format.",
+                    style.as_str()
+                )),
+                suggested_fixes: vec![safe_fix_for_edit(document, &edit, &label)],
+            },
+            safe_fixes: vec![SafeFixEdit {
+                rule_id: "citation.mixed_date_format".to_string(),
+                label,
+                line: document
+                    .line_for_offset(parameter.value_start)
+                    .map(|line| line.number),
+                edit,
+                atomic: false,
+            }],
+        });
+    }
+}
+
+fn citation_family(template_title: &str, resources: &LoadedResources) -> Option<String> {
+    if let Some(rule) = resources
+        .overlay
+        .citations
+        .preferred_templates
+        .iter()
+        .find(|rule| rule.template_title.eq_ignore_ascii_case(template_title))
+    {
+        return Some(rule.family.clone());
+    }
+    let name = template_title.strip_prefix("Template:")?;
+    let family = name
+        .get(..5)?
+        .eq_ignore_ascii_case("cite ")
+        .then(|| &name[5..])?;
+    Some(family.trim().to_ascii_lowercase())
+}
+
+/// Family core fields the invocation does not set. With TemplateData, fields
+/// the template does not declare are dropped and its required fields added.
+fn missing_core_fields(
+    template: &TemplateOccurrence,
+    family: &str,
+    templatedata: Option<&TemplateDataRecord>,
+) -> Vec<String> {
+    let mut fields = CITATION_CORE_FIELDS
+        .iter()
+        .find(|(name, _)| *name == family)
+        .map(|(_, fields)| fields.iter())
+        .into_iter()
+        .flatten()
+        .map(|(field, keys)| (field.to_string(), normalized_keys(keys.iter().copied())))
+        .collect::<Vec<_>>();
+    if let Some(templatedata) = templatedata {
+        let parameter_keys = |parameter: &TemplateDataParameter| {
+            normalized_keys(
+                std::iter::once(parameter.name.as_str())
+                    .chain(parameter.aliases.iter().map(String::as_str)),
+            )
+        };
+        let declared = templatedata
+            .parameters
+            .iter()
+            .flat_map(parameter_keys)
+            .collect::<BTreeSet<_>>();
+        fields.retain(|(_, keys)| !keys.is_disjoint(&declared));
+        for parameter in templatedata.parameters.iter().filter(|item| item.required) {
+            let keys = parameter_keys(parameter);
+            if fields
+                .iter()
+                .all(|(_, existing)| existing.is_disjoint(&keys))
+            {
+                fields.push((parameter.name.clone(), keys));
+            }
+        }
+    }
+
+    let present = template
+        .parameters
+        .iter()
+        .filter(|parameter| !parameter.value.is_empty())
+        .map(|parameter| parameter.key.clone())
+        .collect::<BTreeSet<_>>();
+    fields
+        .into_iter()
+        .filter(|(_, keys)| keys.is_disjoint(&present))
+        .map(|(field, _)| field)
+        .collect()
+}
+
+fn normalized_keys<'a>(keys: impl IntoIterator<Item = &'a str>) -> BTreeSet<String> {
+    keys.into_iter()
+        .map(normalize_template_parameter_key)
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum CitationDateStyle {
+    Dmy,
+    Mdy,
+    Iso,
+}
+
+impl CitationDateStyle {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Dmy => "dmy",
+            Self::Mdy => "mdy",
+            Self::Iso => "ISO",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CitationDate {
+    style: CitationDateStyle,
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+impl CitationDate {
+    /// `None` for dates the calendar does not have, such as 31 February.
+    fn valid(self) -> Option<Self> {
+        (self.day <= days_in_month(i64::from(self.year), self.month)).then_some(self)
+    }
+
+    fn render(self, style: CitationDateStyle) -> String {
+        let month = MONTH_TITLES[self.month as usize - 1];
+        match style {
+            CitationDateStyle::Dmy => format!("{} {month} {}", self.day, self.year),
+            CitationDateStyle::Mdy => format!("{month} {}, {}", self.day, self.year),
+            CitationDateStyle::Iso => {
+                format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+            }
+        }
+    }
+}
+
+/// Full dates only: `2023-03-05`, `5 March 2023`, or `March 5, 2023`.
+/// Partial dates have no day to reorder and are left alone.
+fn parse_citation_date(value: &str) -> Option<CitationDate> {
+    let is_year = |token: &str| token.len() == 4 && token.bytes().all(|byte| byte.is_ascii_digit());
+    let is_day = |token: &str| {
+        token
+            .bytes()
+            .all(|byte| byte.is_ascii_digit())
+            .then(|| parse_day(token))
+            .flatten()
+    };
+    let iso = value.split('-').collect::<Vec<_>>();
+    if let [year, month, day] = iso.as_slice()
+        && is_year(year)
+        && month.len() == 2
+        && day.len() == 2
+    {
+        let month = month
+            .parse::<u32>()
+            .ok()
+            .filter(|month| (1..=12).contains(month))?;
+        return CitationDate {
+            style: CitationDateStyle::Iso,
+            year: year.parse().ok()?,
+            month,
+            day: is_day(day)?,
+        }
+        .valid();
+    }
+
+    let tokens = value.split_whitespace().collect::<Vec<_>>();
+    let [first, second, year] = tokens.as_slice() else {
+        return None;
+    };
+    if !is_year(year) {
+        return None;
+    }
+    let (style, month, day) = if let Some(day) = is_day(first) {
+        (CitationDateStyle::Dmy, parse_month_name(second)?, day)
+    } else {
+        let day = is_day(second.strip_suffix(',')?)?;
+        (CitationDateStyle::Mdy, parse_month_name(first)?, day)
+    };
+    CitationDate {
+        style,
+        year: year.parse().ok()?,
+        month,
+        day,
+    }
+    .valid()
+}
+
+fn declared_date_style(document: &ParsedArticleDocument) -> Option<CitationDateStyle> {
+    document.templates.iter().find_map(|template| {
+        match template.template_title.to_ascii_lowercase().as_str() {
+            "template:use dmy dates" => Some(CitationDateStyle::Dmy),
+            "template:use mdy dates" => Some(CitationDateStyle::Mdy),
+            _ => None,
+        }
+    })
+}
+
+/// Most frequent style; ties go to the style that appears first.
+fn dominant_date_style(
+    dates: &[(&TemplateParameterOccurrence, CitationDate)],
+) -> Option<CitationDateStyle> {
+    let mut counts = BTreeMap::<CitationDateStyle, (usize, usize)>::new();
+    for (index, (_, date)) in dates.iter().enumerate() {
+        let entry = counts.entry(date.style).or_insert((0, index));
+        entry.0 += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|left, right| left.1.0.cmp(&right.1.0).then(right.1.1.cmp(&left.1.1)))
+        .map(|(style, _)| style)
+}
@@ -2,9 +2,10 @@ use std::collections::BTreeSet;
 
 use crate::article_lint::document::{ArticleSection, ParsedArticleDocument, TemplateOccurrence};
 use crate::article_lint::fix::TextEdit;
-use crate::article_lint::model::{SuggestedFix, SuggestedFixKind};
-use crate::content_store::parsing::{make_content_preview, normalize_spaces};
-use crate::profile::ProfileOverlay;
+use crate::article_lint::model::{FixEdit, SuggestedFix, SuggestedFixKind};
+use crate::article_lint::resources::LoadedResources;
+use crate::content_store::parsing::{extract_wikilinks, make_content_preview, normalize_spaces};
+use crate::profile::{ProfileOverlay, TemplateCatalogEntryLookup, find_template_catalog_entry};
 
 const COMMON_SENTENCE_CASE_HEADINGS: &[(&str, &str)] = &[
     ("see also", "See also"),
@@ -181,9 +182,43 @@ pub(super) fn safe_fix_for_edit(
         kind: SuggestedFixKind::SafeAutofix,
         replacement_preview: Some(make_content_preview(&edit.replacement, 96)),
         patch: Some(patch),
+        edits: fix_edits(document, std::slice::from_ref(edit)),
     }
 }
 
+/// One safe fix made of several edits that must be applied together.
+pub(super) fn safe_fix_for_edits(
+    document: &ParsedArticleDocument,
+    edits: &[TextEdit],
+    label: &str,
+    replacement_preview: &str,
+) -> SuggestedFix {
+    let patch = edits
+        .iter()
+        .map(|edit| patch_preview(document, edit))
+        .collect::<Vec<_>>()
+        .join("\n");
+    SuggestedFix {
+        label: label.to_string(),
+        kind: SuggestedFixKind::SafeAutofix,
+        replacement_preview: Some(make_content_preview(replacement_preview, 96)),
+        patch: Some(patch),
+        edits: fix_edits(document, edits),
+    }
+}
+
+fn fix_edits(document: &ParsedArticleDocument, edits: &[TextEdit]) -> Vec<FixEdit> {
+    edits
+        .iter()
+        .filter_map(|edit| {
+            Some(FixEdit {
+                span: document.span_for_range(edit.start, edit.end)?,
+                replacement: edit.replacement.clone(),
+            })
+        })
+        .collect()
+}
+
 fn patch_preview(document: &ParsedArticleDocument, edit: &TextEdit) -> String {
     let line = document
         .line_for_offset(edit.start)
@@ -201,3 +236,36 @@ fn patch_preview(document: &ParsedArticleDocument, edit: &TextEdit) -> String {
     };
     format!("@@ line {line} @@\n- {before}\n+ {after}")
 }
+
+/// The page's direct category memberships followed by those added through
+/// catalogued templates, deduplicated by [`category_key`].
+pub(super) fn document_categories(
+    document: &ParsedArticleDocument,
+    resources: &LoadedResources,
+) -> Vec<String> {
+    let mut categories = Vec::new();
+    let mut seen = BTreeSet::new();
+    for link in extract_wikilinks(&document.content) {
+        if link.is_category_membership && seen.insert(category_key(&link.target_title)) {
+            categories.push(link.target_title);
+        }
+    }
+    if let Some(catalog) = resources.template_catalog.as_ref() {
+        for template in &document.templates {
+            if let TemplateCatalogEntryLookup::Found(entry) =
+                find_template_catalog_entry(catalog, &template.template_title)
+            {
+                for category in &entry.added_categories {
+                    if seen.insert(category_key(category)) {
+                        categories.push(category.clone());
+                    }
+                }
+            }
+        }
+    }
+    categories
+}
+
+pub(super) fn category_key(title: &str) -> String {
+    normalize_spaces(&title.replace('_', " ")).to_ascii_lowercase()
+}
@@ -0,0 +1,298 @@
+use regex::Regex;
+
+use crate::article_lint::document::ParsedArticleDocument;
+use crate::article_lint::fix::TextEdit;
+use crate::article_lint::model::{ArticleLintIssue, ArticleLintSeverity};
+use crate::article_lint::resources::{CompiledLintRule, LoadedResources};
+use crate::config::{CustomLintMatcher, CustomLintSeverity};
+use crate::content_store::parsing::make_content_preview;
+use crate::knowledge::templates::normalize_template_lookup_title;
+use crate::wikitext::phrases::prose_exclusions;
+use crate::wikitext::spans::mask_ranges;
+
+use super::common::{category_key, document_categories, safe_fix_for_edit};
+use super::{IssueMatch, SafeFixEdit};
+
+/// One place a custom rule fired, with the rewrite for its safe fix if the
+/// rule declares a replacement.
+struct CustomHit {
+    start: usize,
+    end: usize,
+    evidence: String,
+    replacement: Option<TextEdit>,
+}
+
+pub(super) fn lint_custom_rules(
+    document: &ParsedArticleDocument,
+    resources: &LoadedResources,
+    matches: &mut Vec<IssueMatch>,
+) {
+    if resources.custom_rules.is_empty() {
+        return;
+    }
+    let categories = document_categories(document, resources)
+        .iter()
+        .map(|category| category_key(category))
+        .collect::<Vec<_>>();
+    for compiled in resources.custom_rules.iter() {
+        let rule = &compiled.rule;
+        if !rule.namespaces.is_empty()
+            && !rule
+                .namespaces
+                .iter()
+                .any(|namespace| namespace.eq_ignore_ascii_case(&document.namespace))
+        {
+            continue;
+        }
+        if !rule.categories.is_empty()
+            && !rule.categories.iter().any(|category| {
+                let key = category_key(category);
+                let key = if key.starts_with("category:") {
+                    key
+                } else {
+                    format!("category:{key}")
+                };
+                categories.contains(&key)
+            })
+        {
+            continue;
+        }
+
+        for hit in custom_rule_hits(document, compiled) {
+            let label = format!("Apply {}", rule.id);
+            let mut safe_fixes = Vec::new();
+            let mut suggested_fixes = Vec::new();
+            if let Some(edit) = hit.replacement {
+                suggested_fixes.push(safe_fix_for_edit(document, &edit, &label));
+                safe_fixes.push(SafeFixEdit {
+                    rule_id: rule.id.clone(),
+                    label,
+                    line: document.line_for_offset(edit.start).map(|line| line.number),
+                    edit,
+                    atomic: false,
+                });
+            }
+            matches.push(IssueMatch {
+                issue: ArticleLintIssue {
+                    rule_id: rule.id.clone(),
+                    severity: lint_severity(rule.severity),
+                    message: rule.message.clone(),
+                    span: document.span_for_range(hit.start, hit.end),
+                    evidence: Some(hit.evidence),
+                    suggested_remediation: rule.remediation.clone(),
+                    suggested_fixes,
+                },
+                safe_fixes,
+            });
+        }
+    }
+}
+
+fn lint_severity(severity: CustomLintSeverity) -> ArticleLintSeverity {
+    match severity {
+        CustomLintSeverity::Error => ArticleLintSeverity::Error,
+        CustomLintSeverity::Warning => ArticleLintSeverity::Warning,
+        CustomLintSeverity::Suggestion => ArticleLintSeverity::Suggestion,
+    }
+}
+
+fn custom_rule_hits(
+    document: &ParsedArticleDocument,
+    compiled: &CompiledLintRule,
+) -> Vec<CustomHit> {
+    let content = &document.content;
+    match (&compiled.rule.matcher, &compiled.pattern) {
+        (CustomLintMatcher::Prose { replacement, .. }, Some(pattern)) => {
+            // Masking keeps offsets but not boundaries, so a match may run
+            // across a masked reference or template; those are dropped rather
+            // than rewritten.
+            let exclusions = prose_exclusions(content);
+            let prose = mask_ranges(content, &exclusions);
+            pattern_hits(pattern, &prose, content, 0, replacement.as_deref())
+                .into_iter()
+                .filter(|hit| {
+                    !exclusions
+                        .iter()
+                        .any(|&(from, to)| from < hit.end && hit.start < to)
+                })
+                .collect()
+        }
+        (CustomLintMatcher::Heading { replacement, .. }, Some(pattern)) => document
+            .sections
+            .iter()
+            .filter_map(|section| section.heading.as_ref())
+            .flat_map(|heading| {
+                let line = &content[heading.start..heading.end];
+                let Some(offset) = line.find(&heading.text) else {
+                    return Vec::new();
+                };
+                let start = heading.start + offset;
+                let text = &content[start..start + heading.text.len()];
+                whole_text_hit(pattern, text, start, replacement.as_deref())
+            })
+            .collect(),
+        (CustomLintMatcher::LinkTarget { replacement, .. }, Some(pattern)) => {
+            wikilink_targets(content)
+                .into_iter()
+                .flat_map(|(start, end)| {
+                    whole_text_hit(pattern, &content[start..end], start, replacement.as_deref())
+                })
+                .collect()
+        }
+        (CustomLintMatcher::TemplatePresent { template }, _) => {
+            let title = normalize_template_lookup_title(template);
+            document
+                .templates
+                .iter()
+                .filter(|occurrence| occurrence.template_title.eq_ignore_ascii_case(&title))
+                .map(|occurrence| CustomHit {
+                    start: occurrence.start,
+                    end: occurrence.end,
+                    evidence: make_content_preview(&occurrence.raw_wikitext, 96),
+                    replacement: None,
+                })
+                .collect()
+        }
+        (CustomLintMatcher::TemplateAbsent { template }, _) => {
+            let title = normalize_template_lookup_title(template);
+            if document
+                .templates
+                .iter()
+                .any(|occurrence| occurrence.template_title.eq_ignore_ascii_case(&title))
+            {
+                return Vec::new();
+            }
+            vec![CustomHit {
+                start: 0,
+                end: 0,
+                evidence: format!("{{{{{}}}}}", title.trim_start_matches("Template:")),
+                replacement: None,
+            }]
+        }
+        (
+            CustomLintMatcher::TemplateParameter {
+                template,
+                parameter,
+            },
+            _,
+        ) => {
+            let title = normalize_template_lookup_title(template);
+            document
+                .templates
+                .iter()
+                .filter(|occurrence| {
+                    occurrence.template_title.eq_ignore_ascii_case(&title)
+                        && !occurrence
+                            .parameter_keys
+                            .iter()
+                            .any(|key| key.trim().eq_ignore_ascii_case(parameter.trim()))
+                })
+                .map(|occurrence| CustomHit {
+                    start: occurrence.start,
+                    end: occurrence.end,
+                    evidence: make_content_preview(&occurrence.raw_wikitext, 96),
+                    replacement: None,
+                })
+                .collect()
+        }
+        (_, None) => Vec::new(),
+    }
+}
+
+/// Every match of `pattern` in `haystack`, whose offsets line up with
+/// `content` shifted by `base`.
+fn pattern_hits(
+    pattern: &Regex,
+    haystack: &str,
+    content: &str,
+    base: usize,
+    replacement: Option<&str>,
+) -> Vec<CustomHit> {
+    pattern
+        .captures_iter(haystack)
+        .filter_map(|captures| {
+            let found = captures.get(0)?;
+            if found.is_empty() {
+                return None;
+            }
+            let (start, end) = (base + found.start(), base + found.end());
+            let replacement = replacement.map(|template| {
+                let mut expanded = String::new();
+                captures.expand(template, &mut expanded);
+                TextEdit {
+                    start,
+                    end,
+                    replacement: expanded,
+                }
+            });
+            Some(CustomHit {
+                start,
+                end,
+                evidence: content[start..end].to_string(),
+                replacement: replacement.filter(|edit| edit.replacement != content[start..end]),
+            })
+        })
+        .collect()
+}
+
+/// Heading text and link targets are reported whole; a replacement rewrites
+/// each match inside them.
+fn whole_text_hit(
+    pattern: &Regex,
+    text: &str,
+    start: usize,
+    replacement: Option<&str>,
+) -> Vec<CustomHit> {
+    if !pattern.is_match(text) {
+        return Vec::new();
+    }
+    let replacement = replacement
+        .map(|template| pattern.replace_all(text, template).into_owned())
+        .filter(|rewritten| rewritten != text)
+        .map(|rewritten| TextEdit {
+            start,
+            end: start + text.len(),
+            replacement: rewritten,
+        });
+    vec![CustomHit {
+        start,
+        end: start + text.len(),
+        evidence: text.to_string(),
+        replacement,
+    }]
+}
+
+/// Byte ranges of `[[target|label]]` targets, outside comments and
+/// `<nowiki>`; the leading colon of `[[:Category:X]]` stays outside.
+fn wikilink_targets(content: &str) -> Vec<(usize, usize)> {
+    let excluded = prose_exclusions(content);
+    let mut out = Vec::new();
+    let mut cursor = 0usize;
+    while let Some(found) = content[cursor..].find("[[") {
+        let open = cursor + found;
+        let start = open + 2;
+        cursor = start;
+        if excluded
+            .iter()
+            .any(|&(from, to)| (from..to).contains(&open) && !(from..to).contains(&(start)))
+        {
+            continue;
+        }
+        let Some(close) = content[start..].find("]]").map(|index| start + index) else {
+            break;
+        };
+        let inner = &content[start..close];
+        if inner.contains("[[") {
+            continue;
+        }
+        let target_len = inner.find('|').unwrap_or(inner.len());
+        let target = &inner[..target_len];
+        let leading = target.len() - target.trim_start().len();
+        let leading = leading + usize::from(target.trim_start().starts_with(':'));
+        let trimmed_end = target.trim_end().len();
+        if trimmed_end > leading {
+            out.push((start + leading, start + trimmed_end));
+        }
+    }
+    out
+}
@@ -10,6 +10,7 @@ use super::resources::LoadedResources;
 mod asset;
 mod citation;
 mod common;
+mod custom;
 mod extension;
 mod integration;
 mod module;
@@ -18,12 +19,32 @@ mod style;
 mod template;
 mod wikitext;
 
+/// Rule id prefixes used by built-in rules. Project rules may not use them, so
+/// their ids can never collide with a built-in rule, present or future.
+pub(super) const BUILTIN_RULE_FAMILIES: &[&str] = &[
+    "asset",
+    "capability",
+    "citation",
+    "extension",
+    "graph",
+    "integration",
+    "module",
+    "profile",
+    "structure",
+    "style",
+    "template",
+    "wikitext",
+];
+
 #[derive(Debug, Clone)]
 pub(super) struct SafeFixEdit {
     pub(super) rule_id: String,
     pub(super) label: String,
     pub(super) line: Option<usize>,
     pub(super) edit: TextEdit,
+    /// Part of a multi-edit fix whose edits only make sense together, such as
+    /// naming a reference and pointing its copies at the name.
+    pub(super) atomic: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -48,9 +69,17 @@ pub(super) fn collect_issue_matches(
     structure::lint_sentence_case_headings(document, resources, &mut matches);
     structure::lint_missing_references_section(document, resources, &mut matches);
     structure::lint_missing_reflist(document, resources, &mut matches);
+    structure::lint_category_rules(document, resources, &mut matches)?;
     citation::lint_citation_after_punctuation(document, &mut matches);
+    citation::lint_duplicate_references(document, resources, &mut matches);
+    citation::lint_unreliable_sources(document, resources, &mut matches);
+    citation::lint_citation_core_fields(document, resources, &mut matches);
+    citation::lint_citation_date_formats(document, resources, &mut matches);
     style::lint_curly_quotes(document, &mut matches);
     style::lint_placeholder_fragments(document, resources, &mut matches);
+    style::lint_phrase_lists(document, resources, &mut matches);
+    style::lint_stale_claims(document, resources, &mut matches)?;
+    style::lint_spelling(document, resources, &mut matches);
     template::lint_citation_needed(document, &mut matches);
     template::lint_remilia_parent_group(document, resources, &mut matches);
     template::lint_template_availability(document, resources, &mut matches);
@@ -59,6 +88,7 @@ pub(super) fn collect_issue_matches(
     integration::lint_red_links_in_see_also(document, resources, &mut matches)?;
     integration::lint_capability_rules(document, resources, &mut matches);
     integration::lint_graph_rules(paths, document, resources, &mut matches)?;
+    custom::lint_custom_rules(document, resources, &mut matches);
 
     matches.sort_by(compare_issue_matches);
     Ok(matches)
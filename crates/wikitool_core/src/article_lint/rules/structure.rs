@@ -1,17 +1,22 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use anyhow::{Context, Result};
+use rusqlite::{Connection, params};
 
 use crate::article_lint::document::ParsedArticleDocument;
 use crate::article_lint::fix::TextEdit;
 use crate::article_lint::model::{
     ArticleLintIssue, ArticleLintSeverity, SuggestedFix, SuggestedFixKind,
 };
-use crate::content_store::parsing::{make_content_preview, normalize_spaces, parse_heading_line};
+use crate::content_store::parsing::{
+    extract_wikilinks, make_content_preview, normalize_spaces, parse_heading_line,
+};
 use crate::filesystem::Namespace;
 
 use super::common::{
-    canonical_sentence_case_heading, line_has_short_description, parse_markdown_heading,
-    preferred_short_description_snippet, safe_fix_for_edit, safe_heading_rewrite_available,
-    section_body_contains_template,
+    canonical_sentence_case_heading, category_key, document_categories, line_has_short_description,
+    parse_markdown_heading, preferred_short_description_snippet, safe_fix_for_edit,
+    safe_heading_rewrite_available, section_body_contains_template,
 };
 use super::{IssueMatch, SafeFixEdit};
 use crate::article_lint::resources::LoadedResources;
@@ -51,6 +56,7 @@ pub(super) fn lint_missing_short_description(
                 kind: SuggestedFixKind::AssistedFix,
                 replacement_preview: Some(preferred_short_description_snippet(&resources.overlay)),
                 patch: None,
+                edits: Vec::new(),
             }],
         },
         safe_fixes: Vec::new(),
@@ -114,6 +120,7 @@ pub(super) fn lint_article_quality_banner(
                 label: "Insert article quality banner".to_string(),
                 line: document.first_nonblank_line().map(|line| line.number),
                 edit,
+                atomic: false,
             }],
         });
     }
@@ -159,6 +166,7 @@ pub(super) fn lint_markdown_headings(
                 label: "Convert Markdown heading to MediaWiki heading".to_string(),
                 line: Some(line.number),
                 edit,
+                atomic: false,
             }],
         });
     }
@@ -274,6 +282,7 @@ pub(super) fn lint_sentence_case_headings(
                 label: "Normalize heading to sentence case".to_string(),
                 line: Some(heading.line),
                 edit,
+                atomic: false,
             });
         } else {
             suggested_fixes.push(SuggestedFix {
@@ -281,6 +290,7 @@ pub(super) fn lint_sentence_case_headings(
                 kind: SuggestedFixKind::AssistedFix,
                 replacement_preview: Some(canonical),
                 patch: None,
+                edits: Vec::new(),
             });
         }
 
@@ -344,6 +354,7 @@ pub(super) fn lint_missing_references_section(
                 kind: SuggestedFixKind::AssistedFix,
                 replacement_preview: Some("== References ==\n{{Reflist}}".to_string()),
                 patch: None,
+                edits: Vec::new(),
             }],
         },
         safe_fixes: Vec::new(),
@@ -379,6 +390,7 @@ pub(super) fn lint_missing_reflist(
             .as_ref()
             .map(|heading| heading.line.saturating_add(1)),
         edit: edit.clone(),
+        atomic: false,
     }];
     let suggested_fixes = vec![safe_fix_for_edit(
         document,
@@ -404,3 +416,232 @@ pub(super) fn lint_missing_reflist(
         safe_fixes,
     });
 }
+
+/// Category memberships against the profile's per-article bounds and preferred
+/// root categories. Categories added by catalogued templates count as well.
+pub(super) fn lint_category_rules(
+    document: &ParsedArticleDocument,
+    resources: &LoadedResources,
+    matches: &mut Vec<IssueMatch>,
+) -> Result<()> {
+    if document.namespace != Namespace::Main.as_str() || document.is_redirect {
+        return Ok(());
+    }
+    let rules = &resources.overlay.categories;
+    if rules.min_per_article == 0
+        && rules.max_per_article == 0
+        && rules.preferred_categories.is_empty()
+    {
+        return Ok(());
+    }
+
+    let categories = document_categories(document, resources);
+    let seen = categories
+        .iter()
+        .map(|category| category_key(category))
+        .collect::<BTreeSet<_>>();
+
+    let first_category = document
+        .content
+        .to_ascii_lowercase()
+        .find("[[category:")
+        .and_then(|start| {
+            let end = document.content[start..]
+                .find("]]")
+                .map(|index| start + index + 2)?;
+            Some((start, end))
+        });
+    let span = first_category.and_then(|(start, end)| document.span_for_range(start, end));
+    let connection = resources.index_connection.as_ref();
+    let count = categories.len();
+    let bounds = match (rules.min_per_article, rules.max_per_article) {
+        (min, 0) => format!("at least {min}"),
+        (0, max) => format!("at most {max}"),
+        (min, max) => format!("{min}-{max}"),
+    };
+
+    if rules.min_per_article > 0 && count < rules.min_per_article {
+        let suggestions = match connection {
+            Some(connection) => comparable_page_categories(connection, document, &seen)?,
+            None => Vec::new(),
+        };
+        let remediation = if suggestions.is_empty() {
+            "Add categories that describe what the subject is.".to_string()
+        } else {
+            format!(
+                "Add categories used by comparable pages, such as {}.",
+                suggestions.join(", ")
+            )
+        };
+        matches.push(IssueMatch {
+            issue: ArticleLintIssue {
+                rule_id: "structure.category_count".to_string(),
+                severity: ArticleLintSeverity::Warning,
+                message: format!("Article is in {count} categories; the profile expects {bounds}."),
+                span: span.clone(),
+                evidence: Some(categories.join(", ")).filter(|value| !value.is_empty()),
+                suggested_remediation: Some(remediation),
+                suggested_fixes: category_fix("Add suggested categories", &suggestions),
+            },
+            safe_fixes: Vec::new(),
+        });
+    }
+    if rules.max_per_article > 0 && count > rules.max_per_article {
+        matches.push(IssueMatch {
+            issue: ArticleLintIssue {
+                rule_id: "structure.category_count".to_string(),
+                severity: ArticleLintSeverity::Warning,
+                message: format!("Article is in {count} categories; the profile expects {bounds}."),
+                span: span.clone(),
+                evidence: Some(categories.join(", ")),
+                suggested_remediation: Some(
+                    "Keep the most specific categories and drop parents already implied by them."
+                        .to_string(),
+                ),
+                suggested_fixes: Vec::new(),
+            },
+            safe_fixes: Vec::new(),
+        });
+    }
+
+    if !rules.preferred_categories.is_empty()
+        && !reaches_preferred_category(connection, &categories, &rules.preferred_categories)?
+    {
+        let preferred = rules.preferred_categories.join(" or ");
+        matches.push(IssueMatch {
+            issue: ArticleLintIssue {
+                rule_id: "structure.preferred_category".to_string(),
+                severity: ArticleLintSeverity::Warning,
+                message: format!("Article is not in {preferred} or any of its subcategories."),
+                span,
+                evidence: Some(categories.join(", ")).filter(|value| !value.is_empty()),
+                suggested_remediation: Some(format!(
+                    "Add [[{}]] or one of its subcategories.",
+                    rules.preferred_categories[0]
+                )),
+                suggested_fixes: category_fix(
+                    "Add preferred root category",
+                    &rules.preferred_categories[..1],
+                ),
+            },
+            safe_fixes: Vec::new(),
+        });
+    }
+    Ok(())
+}
+
+fn category_fix(label: &str, categories: &[String]) -> Vec<SuggestedFix> {
+    if categories.is_empty() {
+        return Vec::new();
+    }
+    vec![SuggestedFix {
+        label: label.to_string(),
+        kind: SuggestedFixKind::AssistedFix,
+        replacement_preview: Some(
+            categories
+                .iter()
+                .map(|category| format!("[[{category}]]"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+        patch: None,
+        edits: Vec::new(),
+    }]
+}
+
+/// Categories shared by at least two pages that this article links to or is
+/// linked from, most common first.
+fn comparable_page_categories(
+    connection: &Connection,
+    document: &ParsedArticleDocument,
+    present: &BTreeSet<String>,
+) -> Result<Vec<String>> {
+    const SUGGESTION_LIMIT: usize = 5;
+    let mut comparable = BTreeSet::new();
+    for link in extract_wikilinks(&document.content) {
+        if !link.is_category_membership && link.target_namespace == Namespace::Main.as_str() {
+            comparable.insert(link.target_title);
+        }
+    }
+    let mut statement = connection
+        .prepare(
+            "SELECT DISTINCT l.source_title
+             FROM indexed_links l
+             JOIN indexed_pages p ON p.relative_path = l.source_relative_path
+             WHERE l.target_title = ?1 AND l.is_category_membership = 0
+               AND p.namespace = 'Main' AND p.is_redirect = 0",
+        )
+        .context("failed to prepare comparable page query")?;
+    let rows = statement
+        .query_map(params![document.title], |row| row.get::<_, String>(0))
+        .context("failed to run comparable page query")?;
+    for row in rows {
+        comparable.insert(row.context("failed to decode comparable page row")?);
+    }
+    comparable.remove(&document.title);
+
+    let mut counts = BTreeMap::<String, usize>::new();
+    for title in &comparable {
+        for category in page_categories(connection, title)? {
+            if !present.contains(&category_key(&category)) {
+                *counts.entry(category).or_default() += 1;
+            }
+        }
+    }
+    let mut ranked = counts
+        .into_iter()
+        .filter(|(_, count)| *count >= 2)
+        .collect::<Vec<_>>();
+    ranked.sort_by(|left, right| right.1.cmp(&left.1).then_with(|| left.0.cmp(&right.0)));
+    Ok(ranked
+        .into_iter()
+        .take(SUGGESTION_LIMIT)
+        .map(|(category, _)| category)
+        .collect())
+}
+
+/// Whether any category is a preferred one or, walking the indexed category
+/// tree upwards, one of its descendants.
+fn reaches_preferred_category(
+    connection: Option<&Connection>,
+    categories: &[String],
+    preferred: &[String],
+) -> Result<bool> {
+    const ANCESTOR_LIMIT: usize = 500;
+    let preferred = preferred
+        .iter()
+        .map(|category| category_key(category))
+        .collect::<BTreeSet<_>>();
+    let mut visited = BTreeSet::new();
+    let mut queue = categories.iter().cloned().collect::<VecDeque<_>>();
+    while let Some(category) = queue.pop_front() {
+        let key = category_key(&category);
+        if preferred.contains(&key) {
+            return Ok(true);
+        }
+        if !visited.insert(key) || visited.len() > ANCESTOR_LIMIT {
+            continue;
+        }
+        if let Some(connection) = connection {
+            queue.extend(page_categories(connection, &category)?);
+        }
+    }
+    Ok(false)
+}
+
+fn page_categories(connection: &Connection, title: &str) -> Result<Vec<String>> {
+    let mut statement = connection
+        .prepare_cached(
+            "SELECT target_title FROM indexed_links
+             WHERE source_title = ?1 AND is_category_membership = 1",
+        )
+        .context("failed to prepare page category query")?;
+    let rows = statement
+        .query_map(params![title], |row| row.get::<_, String>(0))
+        .context("failed to run page category query")?;
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row.context("failed to decode page category row")?);
+    }
+    Ok(out)
+}
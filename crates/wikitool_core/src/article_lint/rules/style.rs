@@ -1,12 +1,20 @@
 use std::collections::BTreeMap;
 
+use anyhow::Result;
+
 use crate::article_lint::document::ParsedArticleDocument;
 use crate::article_lint::fix::TextEdit;
-use crate::article_lint::model::{ArticleLintIssue, ArticleLintSeverity};
+use crate::article_lint::model::{
+    ArticleLintIssue, ArticleLintSeverity, SuggestedFix, SuggestedFixKind,
+};
 
 use super::common::{safe_fix_for_edit, straight_quote_for};
 use super::{IssueMatch, SafeFixEdit};
 use crate::article_lint::resources::LoadedResources;
+use crate::wikitext::phrases::find_phrase_matches;
+use crate::wikitext::staleness::{
+    STALE_CLAIM_AFTER_DAYS, TemporalClaim, TemporalClaimKind, find_temporal_claims,
+};
 
 pub(super) fn lint_curly_quotes(document: &ParsedArticleDocument, matches: &mut Vec<IssueMatch>) {
     let mut grouped = BTreeMap::<usize, Vec<(usize, char)>>::new();
@@ -41,6 +49,7 @@ pub(super) fn lint_curly_quotes(document: &ParsedArticleDocument, matches: &mut
                 label: "Replace curly quotes with straight quotes".to_string(),
                 line: Some(line.number),
                 edit: edit.clone(),
+                atomic: false,
             });
             suggested_fixes.push(safe_fix_for_edit(
                 document,
@@ -93,3 +102,224 @@ pub(super) fn lint_placeholder_fragments(
         });
     }
 }
+
+/// Banned phrases (including the bad side of the style guide's rewrite table)
+/// and watch-list terms, matched together so a watch-list word inside a
+/// banned phrase is reported once, as the phrase.
+pub(super) fn lint_phrase_lists(
+    document: &ParsedArticleDocument,
+    resources: &LoadedResources,
+    matches: &mut Vec<IssueMatch>,
+) {
+    let lint = &resources.overlay.lint;
+    let mut phrases = Vec::<&str>::new();
+    for phrase in lint
+        .banned_phrases
+        .iter()
+        .chain(lint.phrase_rewrites.iter().map(|rewrite| &rewrite.phrase))
+    {
+        if !phrases
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(phrase))
+        {
+            phrases.push(phrase);
+        }
+    }
+    let banned_count = phrases.len();
+    phrases.extend(lint.watchlist_terms.iter().map(String::as_str));
+
+    for found in find_phrase_matches(&document.content, &phrases) {
+        let phrase = phrases[found.phrase];
+        let text = &document.content[found.start..found.end];
+        let issue = if found.phrase < banned_count {
+            let rewrite = lint
+                .phrase_rewrites
+                .iter()
+                .find(|rewrite| rewrite.phrase.eq_ignore_ascii_case(phrase));
+            let (severity, message) = if found.exact {
+                (
+                    ArticleLintSeverity::Error,
+                    format!("Article prose uses the banned phrase \"{phrase}\"."),
+                )
+            } else {
+                (
+                    ArticleLintSeverity::Warning,
+                    format!("\"{text}\" is a variant of the banned phrase \"{phrase}\"."),
+                )
+            };
+            let suggested_fixes = rewrite
+                .filter(|_| found.exact)
+                .map(|rewrite| SuggestedFix {
+                    label: format!("Rewrite as \"{}\"", rewrite.replacement),
+                    kind: SuggestedFixKind::AssistedFix,
+                    replacement_preview: Some(match_leading_case(text, &rewrite.replacement)),
+                    patch: None,
+                    edits: Vec::new(),
+                })
+                .into_iter()
+                .collect();
+            ArticleLintIssue {
+                rule_id: "style.banned_phrase".to_string(),
+                severity,
+                message,
+                span: document.span_for_range(found.start, found.end),
+                evidence: Some(text.to_string()),
+                suggested_remediation: Some(match rewrite {
+                    Some(rewrite) => format!("Rewrite as \"{}\".", rewrite.replacement),
+                    None => "State the specific fact instead of the stock phrase.".to_string(),
+                }),
+                suggested_fixes,
+            }
+        } else {
+            ArticleLintIssue {
+                rule_id: "style.watchlist_term".to_string(),
+                severity: ArticleLintSeverity::Suggestion,
+                message: format!("\"{text}\" is on the style guide's watch list."),
+                span: document.span_for_range(found.start, found.end),
+                evidence: Some(text.to_string()),
+                suggested_remediation: Some(
+                    "Use it sparingly; prefer plain connectives, concrete verbs and specific nouns."
+                        .to_string(),
+                ),
+                suggested_fixes: Vec::new(),
+            }
+        };
+        matches.push(IssueMatch {
+            issue,
+            safe_fixes: Vec::new(),
+        });
+    }
+}
+
+fn match_leading_case(original: &str, replacement: &str) -> String {
+    let mut chars = replacement.chars();
+    match (original.chars().next(), chars.next()) {
+        (Some(lead), Some(first)) if lead.is_uppercase() => {
+            first.to_uppercase().chain(chars).collect()
+        }
+        _ => replacement.to_string(),
+    }
+}
+
+/// Dated statements older than [`STALE_CLAIM_AFTER_DAYS`], aged against the
+/// page's reference day, plus one nudge per page for unanchored "currently"
+/// style wording. Messages leave out the running age so baseline
+/// fingerprints survive from one day to the next.
+pub(super) fn lint_stale_claims(
+    document: &ParsedArticleDocument,
+    resources: &LoadedResources,
+    matches: &mut Vec<IssueMatch>,
+) -> Result<()> {
+    let today = resources.reference_day()?;
+    let (relative, claims): (Vec<_>, Vec<_>) = find_temporal_claims(&document.content, today)
+        .into_iter()
+        .filter(|claim| claim.is_stale(STALE_CLAIM_AFTER_DAYS))
+        .partition(|claim| claim.kind == TemporalClaimKind::RelativePhrase);
+    for claim in claims {
+        let (severity, message, remediation) = match claim.age_days {
+            Some(_) => (
+                ArticleLintSeverity::Warning,
+                format!(
+                    "Dated statement ({}) is more than {STALE_CLAIM_AFTER_DAYS} days old.",
+                    claim.kind.as_str()
+                ),
+                "Re-verify the statement against a current source and update its date.",
+            ),
+            None => (
+                ArticleLintSeverity::Suggestion,
+                format!(
+                    "Time-relative wording ({}) has no date anchor.",
+                    claim.kind.as_str()
+                ),
+                "Anchor the statement with {{As of}} or a specific date so readers can judge its age.",
+            ),
+        };
+        matches.push(stale_claim_match(
+            document,
+            &claim,
+            severity,
+            message,
+            remediation,
+        ));
+    }
+    if let Some(first) = relative.first() {
+        let message = match relative.len() {
+            1 => format!(
+                "Time-relative wording ({}) has no date anchor.",
+                first.kind.as_str()
+            ),
+            count => format!(
+                "Time-relative wording ({}) has no date anchor; the page has {count} such phrases.",
+                first.kind.as_str()
+            ),
+        };
+        matches.push(stale_claim_match(
+            document,
+            first,
+            ArticleLintSeverity::Suggestion,
+            message,
+            "Anchor statements like this with {{As of}} or a specific date so readers can judge their age.",
+        ));
+    }
+    Ok(())
+}
+
+fn stale_claim_match(
+    document: &ParsedArticleDocument,
+    claim: &TemporalClaim,
+    severity: ArticleLintSeverity,
+    message: String,
+    remediation: &str,
+) -> IssueMatch {
+    IssueMatch {
+        issue: ArticleLintIssue {
+            rule_id: "style.stale_claim".to_string(),
+            severity,
+            message,
+            span: document.span_for_range(claim.start, claim.end),
+            evidence: Some(claim.text.clone()),
+            suggested_remediation: Some(remediation.to_string()),
+            suggested_fixes: Vec::new(),
+        },
+        safe_fixes: Vec::new(),
+    }
+}
+
+/// Prose words missing from the word list and the project dictionary
+/// that sit within an edit or two of a known word.
+pub(super) fn lint_spelling(
+    document: &ParsedArticleDocument,
+    resources: &LoadedResources,
+    matches: &mut Vec<IssueMatch>,
+) {
+    for found in resources.spelling.find_misspellings(&document.content) {
+        matches.push(IssueMatch {
+            issue: ArticleLintIssue {
+                rule_id: "style.spelling".to_string(),
+                severity: ArticleLintSeverity::Suggestion,
+                message: format!(
+                    "\"{}\" may be misspelled; did you mean \"{}\"?",
+                    found.word, found.suggestions[0]
+                ),
+                span: document.span_for_range(found.start, found.end),
+                evidence: Some(found.word.clone()),
+                suggested_remediation: Some(
+                    "Fix the spelling, or add the term to .wikitool/spelling-dictionary.txt if it is correct."
+                        .to_string(),
+                ),
+                suggested_fixes: found
+                    .suggestions
+                    .iter()
+                    .map(|suggestion| SuggestedFix {
+                        label: format!("Replace with \"{suggestion}\""),
+                        kind: SuggestedFixKind::AssistedFix,
+                        replacement_preview: Some(suggestion.clone()),
+                        patch: None,
+                        edits: Vec::new(),
+                    })
+                    .collect(),
+            },
+            safe_fixes: Vec::new(),
+        });
+    }
+}
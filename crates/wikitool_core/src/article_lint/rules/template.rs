@@ -1,16 +1,21 @@
 use std::collections::BTreeSet;
 
-use crate::article_lint::document::ParsedArticleDocument;
+use crate::article_lint::document::{
+    ParsedArticleDocument, TemplateOccurrence, TemplateParameterOccurrence,
+};
+use crate::article_lint::fix::TextEdit;
 use crate::article_lint::model::{
-    ArticleLintIssue, ArticleLintSeverity, SuggestedFix, SuggestedFixKind,
+    ArticleLintIssue, ArticleLintSeverity, SuggestedFix, SuggestedFixKind, TextSpan,
 };
-use crate::content_store::parsing::make_content_preview;
+use crate::content_store::parsing::{make_content_preview, normalize_template_parameter_key};
 use crate::profile::{
-    TemplateCatalogEntry, TemplateCatalogEntryLookup, find_template_catalog_entry,
-    unknown_template_parameter_keys,
+    TemplateCatalogEntry, TemplateCatalogEntryLookup, TemplateDataParameter, TemplateDataRecord,
+    find_template_catalog_entry, unknown_template_parameter_keys,
 };
+use crate::wikitext::dates::{parse_day, parse_month_name};
 
-use super::IssueMatch;
+use super::common::safe_fix_for_edit;
+use super::{IssueMatch, SafeFixEdit};
 use crate::article_lint::resources::LoadedResources;
 
 pub(super) fn lint_citation_needed(
@@ -87,6 +92,7 @@ pub(super) fn lint_remilia_parent_group(
                     kind: SuggestedFixKind::AssistedFix,
                     replacement_preview: Some("| parent_group = Remilia".to_string()),
                     patch: None,
+                    edits: Vec::new(),
                 }],
             },
             safe_fixes: Vec::new(),
@@ -135,7 +141,81 @@ pub(super) fn lint_template_availability(
 
 fn lint_template_parameters(
     document: &ParsedArticleDocument,
-    template: &crate::article_lint::document::TemplateOccurrence,
+    template: &TemplateOccurrence,
+    entry: &TemplateCatalogEntry,
+    matches: &mut Vec<IssueMatch>,
+) {
+    lint_unknown_template_parameters(document, template, entry, matches);
+    // Types, flags, and aliases come straight from TemplateData; the catalog's
+    // merged parameter list only keeps spelling variants as aliases.
+    let Some(templatedata) = entry.templatedata.as_ref() else {
+        return;
+    };
+    lint_missing_required_parameters(document, template, templatedata, matches);
+    for occurrence in &template.parameters {
+        let Some(parameter) = templatedata
+            .parameters
+            .iter()
+            .find(|parameter| parameter_accepts_key(parameter, &occurrence.key))
+        else {
+            continue;
+        };
+        if parameter.deprecated {
+            matches.push(IssueMatch {
+                issue: ArticleLintIssue {
+                    rule_id: "template.deprecated_parameter".to_string(),
+                    severity: ArticleLintSeverity::Warning,
+                    message: "Template invocation uses a parameter that TemplateData marks as deprecated."
+                        .to_string(),
+                    span: parameter_span(document, template, occurrence),
+                    evidence: Some(format!(
+                        "{} parameter={}",
+                        template.template_title, occurrence.key
+                    )),
+                    suggested_remediation: Some(
+                        "Run `wikitool templates show` for the template and move the value to the parameter that replaces it, or drop it."
+                            .to_string(),
+                    ),
+                    suggested_fixes: Vec::new(),
+                },
+                safe_fixes: Vec::new(),
+            });
+        }
+        if normalize_template_parameter_key(&parameter.name) != occurrence.key {
+            lint_parameter_alias(document, template, occurrence, parameter, matches);
+        }
+        if let Some(param_type) = parameter.param_type.as_deref()
+            && !parameter_value_matches_type(param_type, &occurrence.value)
+        {
+            matches.push(IssueMatch {
+                issue: ArticleLintIssue {
+                    rule_id: "template.parameter_type".to_string(),
+                    severity: ArticleLintSeverity::Warning,
+                    message: "Template parameter value does not match the type declared in TemplateData."
+                        .to_string(),
+                    span: parameter_span(document, template, occurrence),
+                    evidence: Some(format!(
+                        "{} parameter={} type={} value={}",
+                        template.template_title,
+                        occurrence.key,
+                        param_type,
+                        make_content_preview(&occurrence.value, 80)
+                    )),
+                    suggested_remediation: Some(
+                        "Rewrite the value in the declared format, or move it to a parameter that accepts free text."
+                            .to_string(),
+                    ),
+                    suggested_fixes: Vec::new(),
+                },
+                safe_fixes: Vec::new(),
+            });
+        }
+    }
+}
+
+fn lint_unknown_template_parameters(
+    document: &ParsedArticleDocument,
+    template: &TemplateOccurrence,
     entry: &TemplateCatalogEntry,
     matches: &mut Vec<IssueMatch>,
 ) {
@@ -165,3 +245,199 @@ fn lint_template_parameters(
         safe_fixes: Vec::new(),
     });
 }
+
+fn lint_missing_required_parameters(
+    document: &ParsedArticleDocument,
+    template: &TemplateOccurrence,
+    templatedata: &TemplateDataRecord,
+    matches: &mut Vec<IssueMatch>,
+) {
+    let missing = templatedata
+        .parameters
+        .iter()
+        .filter(|parameter| parameter.required)
+        .filter(|parameter| {
+            !template
+                .parameter_keys
+                .iter()
+                .any(|key| parameter_accepts_key(parameter, key))
+        })
+        .map(|parameter| parameter.name.clone())
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return;
+    }
+
+    matches.push(IssueMatch {
+        issue: ArticleLintIssue {
+            rule_id: "template.missing_required_parameter".to_string(),
+            severity: ArticleLintSeverity::Warning,
+            message: "Template invocation omits parameters that TemplateData marks as required."
+                .to_string(),
+            span: document.span_for_range(template.start, template.end),
+            evidence: Some(format!(
+                "{} missing_parameters={}",
+                template.template_title,
+                missing.join(", ")
+            )),
+            suggested_remediation: Some(
+                "Supply a value for each required parameter, or switch to a template whose contract fits the content."
+                    .to_string(),
+            ),
+            suggested_fixes: Vec::new(),
+        },
+        safe_fixes: Vec::new(),
+    });
+}
+
+fn lint_parameter_alias(
+    document: &ParsedArticleDocument,
+    template: &TemplateOccurrence,
+    occurrence: &TemplateParameterOccurrence,
+    parameter: &TemplateDataParameter,
+    matches: &mut Vec<IssueMatch>,
+) {
+    let canonical = normalize_template_parameter_key(&parameter.name);
+    let mut issue_match = IssueMatch {
+        issue: ArticleLintIssue {
+            rule_id: "template.parameter_alias".to_string(),
+            severity: ArticleLintSeverity::Suggestion,
+            message: "Template invocation uses a parameter alias instead of its canonical TemplateData name."
+                .to_string(),
+            span: parameter_span(document, template, occurrence),
+            evidence: Some(format!(
+                "{} parameter={} canonical={}",
+                template.template_title, occurrence.key, parameter.name
+            )),
+            suggested_remediation: Some(
+                "Rename the parameter to its canonical name so invocations stay consistent."
+                    .to_string(),
+            ),
+            suggested_fixes: Vec::new(),
+        },
+        safe_fixes: Vec::new(),
+    };
+    // Renaming is only safe while the canonical key is not already set;
+    // otherwise the invocation would end up with a duplicate argument.
+    if template.parameter_keys.contains(&canonical) {
+        matches.push(issue_match);
+        return;
+    }
+    let label = format!("Rename {} to {}", occurrence.key, parameter.name);
+    let edit = TextEdit {
+        start: occurrence.key_start,
+        end: occurrence.key_end,
+        replacement: parameter.name.clone(),
+    };
+    issue_match
+        .issue
+        .suggested_fixes
+        .push(safe_fix_for_edit(document, &edit, &label));
+    issue_match.safe_fixes.push(SafeFixEdit {
+        rule_id: "template.parameter_alias".to_string(),
+        label,
+        line: document
+            .line_for_offset(occurrence.key_start)
+            .map(|line| line.number),
+        edit,
+        atomic: false,
+    });
+    matches.push(issue_match);
+}
+
+/// The parameter key's span, or the whole invocation when the key has none.
+fn parameter_span(
+    document: &ParsedArticleDocument,
+    template: &TemplateOccurrence,
+    occurrence: &TemplateParameterOccurrence,
+) -> Option<TextSpan> {
+    document
+        .span_for_range(occurrence.key_start, occurrence.key_end)
+        .or_else(|| document.span_for_range(template.start, template.end))
+}
+
+/// Whether `key` (already normalized) names this parameter or one of its
+/// aliases. Positional parameters are recorded as `$1`, `$2`, and so on.
+fn parameter_accepts_key(parameter: &TemplateDataParameter, key: &str) -> bool {
+    let key = key.strip_prefix('$').unwrap_or(key);
+    std::iter::once(&parameter.name)
+        .chain(&parameter.aliases)
+        .any(|name| normalize_template_parameter_key(name) == key)
+}
+
+/// Checks a value against a TemplateData parameter type. Empty values and
+/// values built from nested templates or markup cannot be judged statically.
+fn parameter_value_matches_type(param_type: &str, value: &str) -> bool {
+    if value.is_empty() || value.contains("{{") || value.contains('<') {
+        return true;
+    }
+    match param_type {
+        "number" => is_number_value(value),
+        "boolean" => matches!(
+            value.to_ascii_lowercase().as_str(),
+            "0" | "1" | "yes" | "no" | "y" | "n" | "true" | "false"
+        ),
+        "url" => {
+            (value.starts_with("https://")
+                || value.starts_with("http://")
+                || value.starts_with("//"))
+                && !value.chars().any(char::is_whitespace)
+        }
+        "date" => is_date_value(value),
+        "wiki-page-name" => !value.contains(['[', ']', '{', '}', '|', '<', '>']),
+        _ => true,
+    }
+}
+
+fn is_number_value(value: &str) -> bool {
+    let digits = value
+        .strip_prefix(['-', '+', '\u{2212}'])
+        .unwrap_or(value)
+        .replace(',', "");
+    let mut parts = digits.splitn(2, '.');
+    let whole = parts.next().unwrap_or("");
+    let fraction = parts.next();
+    let all_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+    (!whole.is_empty() || fraction.is_some_and(|part| !part.is_empty()))
+        && all_digits(whole)
+        && fraction.is_none_or(all_digits)
+}
+
+/// Accepts ISO `YYYY`, `YYYY-MM`, `YYYY-MM-DD` and the prose forms
+/// `5 March 2023`, `March 5, 2023` and `March 2023`.
+fn is_date_value(value: &str) -> bool {
+    let is_year = |token: &str| {
+        (1..=4).contains(&token.len()) && token.bytes().all(|byte| byte.is_ascii_digit())
+    };
+    let iso = value.split('-').collect::<Vec<_>>();
+    if iso
+        .iter()
+        .all(|part| part.bytes().all(|byte| byte.is_ascii_digit()))
+    {
+        let month_ok = iso.get(1).is_none_or(|month| {
+            month.len() == 2
+                && month
+                    .parse::<u32>()
+                    .is_ok_and(|month| (1..=12).contains(&month))
+        });
+        let day_ok = iso
+            .get(2)
+            .is_none_or(|day| day.len() == 2 && parse_day(day).is_some());
+        return iso.len() <= 3 && is_year(iso[0]) && month_ok && day_ok;
+    }
+    let tokens = value
+        .split(|ch: char| ch.is_whitespace() || ch == ',')
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>();
+    let is_day =
+        |token: &str| token.bytes().all(|byte| byte.is_ascii_digit()) && parse_day(token).is_some();
+    match tokens.as_slice() {
+        [month, year] => parse_month_name(month).is_some() && is_year(year),
+        [first, second, year] => {
+            is_year(year)
+                && ((is_day(first) && parse_month_name(second).is_some())
+                    || (parse_month_name(first).is_some() && is_day(second)))
+        }
+        _ => false,
+    }
+}
@@ -17,6 +17,8 @@ fn paths(project_root: &Path) -> ResolvedPaths {
     fs::create_dir_all(&data_dir).expect("data");
     fs::create_dir_all(project_root.join("tools/wikitool/ai-pack/writing_context"))
         .expect("instructions");
+    // An empty project word list keeps the host's system list out of results.
+    fs::write(state_dir.join("spelling-words.txt"), "").expect("spelling words");
     ResolvedPaths {
         project_root: project_root.to_path_buf(),
         wiki_content_dir: project_root.join("wiki_content"),
@@ -591,6 +593,105 @@ fn detects_citation_after_punctuation_and_applies_safe_fix() {
     assert!(content.contains("page.<ref>{{Cite web|title=Source}}</ref>"));
 }
 
+#[test]
+fn merges_repeated_references_into_named_reference() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_common_templates(&paths);
+    let guide_path = paths
+        .project_root
+        .join("tools/wikitool/ai-pack/writing_context/writing_guide.md");
+    let guide = fs::read_to_string(&guide_path).expect("read guide");
+    write_file(
+        &guide_path,
+        &format!("{guide}First use: <ref name=\"x\">...</ref>\nLater: <ref name=\"x\" />\n"),
+    );
+    let article_path = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    let source =
+        "{{Cite web|url=https://www.example.com/a|title=The Milady Story|last=Smith|first=Jane}}";
+    write_file(
+        &article_path,
+        &format!(
+            "{{{{SHORTDESC:Alpha}}}}\n{{{{Article quality|unverified}}}}\n\n'''Alpha''' is a page.<ref>{source}</ref> It grew.<ref name=\"other\">{{{{Cite web|title=Other}}}}</ref>\n\nIt grew again.<ref>{source}</ref> And again.<ref> {source} </ref>\n\n== References ==\n{{{{Reflist}}}}\n"
+        ),
+    );
+
+    let report = lint_article(&paths, &article_path).expect("lint");
+    let issue = report
+        .issues
+        .iter()
+        .find(|issue| issue.rule_id == "citation.duplicate_reference")
+        .expect("duplicate reference issue");
+    assert!(issue.message.contains("repeated 3 times"));
+    let patch = issue.suggested_fixes[0].patch.as_deref().expect("patch");
+    assert_eq!(patch.matches("@@ line").count(), 3);
+    assert!(patch.contains("+ <ref name=\"smith-milady-story\" />"));
+
+    let preview = fix_article(&paths, &article_path, ArticleFixApplyMode::None).expect("preview");
+    assert!(!preview.changed);
+
+    let fixed = fix_article(&paths, &article_path, ArticleFixApplyMode::Safe).expect("safe fix");
+    assert!(fixed.changed);
+    assert_eq!(fixed.applied_fix_count, 3);
+    let content = fs::read_to_string(&article_path).expect("read article");
+    assert!(content.contains(&format!("<ref name=\"smith-milady-story\">{source}</ref>")));
+    assert_eq!(
+        content
+            .matches("<ref name=\"smith-milady-story\" />")
+            .count(),
+        2
+    );
+    assert!(content.contains("<ref name=\"other\">"));
+    assert!(!has_rule(
+        &fixed.remaining_report,
+        "citation.duplicate_reference"
+    ));
+}
+
+fn safe_fix_match(rule_id: &str, edits: &[(usize, usize)], atomic: bool) -> IssueMatch {
+    IssueMatch {
+        issue: ArticleLintIssue {
+            rule_id: rule_id.to_string(),
+            severity: ArticleLintSeverity::Warning,
+            message: rule_id.to_string(),
+            span: None,
+            evidence: None,
+            suggested_remediation: None,
+            suggested_fixes: Vec::new(),
+        },
+        safe_fixes: edits
+            .iter()
+            .map(|&(start, end)| SafeFixEdit {
+                rule_id: rule_id.to_string(),
+                label: rule_id.to_string(),
+                line: None,
+                edit: fix::TextEdit {
+                    start,
+                    end,
+                    replacement: "x".to_string(),
+                },
+                atomic,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn conflicting_safe_fixes_skip_only_the_overlapping_edit_unless_atomic() {
+    let accepted = safe_fix_match("first", &[(0, 4)], false);
+    let independent = safe_fix_match("quotes", &[(2, 3), (10, 11)], false);
+    let atomic = safe_fix_match("merge", &[(3, 5), (20, 22)], true);
+
+    let fixes = collect_safe_fixes(&[accepted, independent, atomic]);
+    let applied = fixes
+        .iter()
+        .map(|fix| (fix.rule_id.as_str(), fix.edit.start))
+        .collect::<Vec<_>>();
+    assert_eq!(applied, vec![("first", 0), ("quotes", 10)]);
+}
+
 #[test]
 fn clustered_citations_move_punctuation_before_the_whole_cluster() {
     let temp = tempdir().expect("tempdir");
@@ -649,6 +750,500 @@ fn rejects_citation_needed_templates() {
     assert!(has_rule(&report, "profile.no_citation_needed"));
 }
 
+#[test]
+fn detects_stale_dated_and_relative_claims() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_common_templates(&paths);
+    let article_path = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    write_file(
+        &article_path,
+        "{{SHORTDESC:Alpha}}\n{{Article quality|unverified}}\n\n'''Alpha''' is currently a page. As of March 2001, it had two editors. It recently moved.\n\n== References ==\n{{Reflist}}\n",
+    );
+
+    let stale_issues = |reference_time: u64| {
+        let resources = load_article_lint_resources(&paths)
+            .expect("resources")
+            .with_reference_time(reference_time);
+        lint_article_with_resources(&paths, &article_path, None, &resources)
+            .expect("lint")
+            .issues
+            .into_iter()
+            .filter(|issue| issue.rule_id == "style.stale_claim")
+            .collect::<Vec<_>>()
+    };
+    // 2026-06-01 UTC.
+    let stale = stale_issues(1_780_272_000);
+    assert_eq!(stale.len(), 2);
+    assert!(stale.iter().any(|issue| {
+        issue.severity == ArticleLintSeverity::Warning
+            && issue.evidence.as_deref() == Some("As of March 2001")
+    }));
+    let relative = stale
+        .iter()
+        .find(|issue| issue.severity == ArticleLintSeverity::Suggestion)
+        .expect("relative wording issue");
+    assert_eq!(relative.evidence.as_deref(), Some("currently"));
+    assert!(relative.message.contains("2 such phrases"));
+    // Three months later the findings, and so their baseline fingerprints,
+    // are unchanged.
+    assert_eq!(stale, stale_issues(1_788_220_800));
+
+    // 2001-06-01 UTC: the dated claim is still fresh.
+    let stale = stale_issues(991_353_600);
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale[0].severity, ArticleLintSeverity::Suggestion);
+}
+
+#[test]
+fn detects_banned_phrases_and_watchlist_terms_outside_quotations() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_file(
+        &paths
+            .project_root
+            .join("tools/wikitool/ai-pack/writing_context/style_rules.md"),
+        "**Never use:**\n- \"stands as\", \"pivotal role\"\n\n| Bad (AI pattern) | Good (natural) |\n|---|---|\n| serves as the headquarters | is the headquarters |\n\n**Watch list — use sparingly or not at all:**\n- notably, tapestry\n",
+    );
+    write_common_templates(&paths);
+    let article_path = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    write_file(
+        &article_path,
+        "{{SHORTDESC:Alpha}}\n{{Article quality|unverified}}\n\n'''Alpha''' Stands As a studio. It played pivotal roles in art. Serves as the headquarters of Beta. Notably, a critic called it \"a pivotal role\".<ref>{{Cite web|title=Alpha stands as a studio}}</ref>\n\n== References ==\n{{Reflist}}\n",
+    );
+
+    let report = lint_article(&paths, &article_path).expect("lint");
+    let banned = report
+        .issues
+        .iter()
+        .filter(|issue| issue.rule_id == "style.banned_phrase")
+        .collect::<Vec<_>>();
+    assert_eq!(banned.len(), 3);
+    assert!(banned.iter().any(|issue| {
+        issue.severity == ArticleLintSeverity::Error
+            && issue.evidence.as_deref() == Some("Stands As")
+            && issue.span.is_some()
+    }));
+    assert!(banned.iter().any(|issue| {
+        issue.severity == ArticleLintSeverity::Warning
+            && issue.evidence.as_deref() == Some("pivotal roles")
+    }));
+    let rewrite = banned
+        .iter()
+        .find(|issue| issue.evidence.as_deref() == Some("Serves as the headquarters"))
+        .expect("rewrite issue");
+    assert_eq!(
+        rewrite.suggested_fixes[0].replacement_preview.as_deref(),
+        Some("Is the headquarters")
+    );
+
+    let watchlist = report
+        .issues
+        .iter()
+        .filter(|issue| issue.rule_id == "style.watchlist_term")
+        .collect::<Vec<_>>();
+    assert_eq!(watchlist.len(), 1);
+    assert_eq!(watchlist[0].severity, ArticleLintSeverity::Suggestion);
+    assert_eq!(watchlist[0].evidence.as_deref(), Some("Notably"));
+}
+
+#[test]
+fn detects_citations_to_unreliable_sources() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_file(
+        &paths
+            .project_root
+            .join("tools/wikitool/ai-pack/writing_context/style_rules.md"),
+        "## VI. Unreliable sources\n\n- Know Your Meme (knowyourmeme.com) — tertiary source, quality issues\n",
+    );
+    write_common_templates(&paths);
+    let article_path = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    write_file(
+        &article_path,
+        "{{SHORTDESC:Alpha}}\n{{Article quality|unverified}}\n\n'''Alpha''' is a meme.<ref>{{Cite web|url=https://knowyourmeme.com/memes/alpha|title=Alpha}}</ref> It spread.<ref>{{Cite web|url=https://example.com/alpha|title=Alpha spreads}}</ref>\n\n== References ==\n{{Reflist}}\n",
+    );
+
+    let report = lint_article(&paths, &article_path).expect("lint");
+    let unreliable = report
+        .issues
+        .iter()
+        .filter(|issue| issue.rule_id == "citation.unreliable_source")
+        .collect::<Vec<_>>();
+    assert_eq!(unreliable.len(), 1);
+    assert_eq!(unreliable[0].severity, ArticleLintSeverity::Error);
+    assert!(unreliable[0].message.contains("Know Your Meme"));
+    assert!(
+        unreliable[0]
+            .message
+            .contains("tertiary source, quality issues")
+    );
+}
+
+#[test]
+fn category_rules_count_template_categories_and_suggest_from_comparable_pages() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_common_templates(&paths);
+    write_file(
+        &paths
+            .templates_dir
+            .join("navbox")
+            .join("Template_Navbox_art.wiki"),
+        "<includeonly>[[Category:Art]]</includeonly><noinclude>[[Category:Navboxes]]</noinclude>",
+    );
+    let main = paths.wiki_content_dir.join("Main");
+    write_file(
+        &paths.wiki_content_dir.join("Category").join("Ideas.wiki"),
+        "Ideas.\n[[Category:Remilia]]",
+    );
+    write_file(&main.join("Beta.wiki"), "'''Beta'''.\n[[Category:Ideas]]");
+    write_file(&main.join("Gamma.wiki"), "'''Gamma'''.\n[[Category:Ideas]]");
+    let body = "\n\n== References ==\n{{Reflist}}\n";
+    let alpha = main.join("Alpha.wiki");
+    write_file(
+        &alpha,
+        &format!(
+            "{{{{SHORTDESC:Alpha}}}}\n{{{{Article quality|unverified}}}}\n\n'''Alpha''' relates to [[Beta]].{body}{{{{Navbox art}}}}\n[[Category:Ideas]]\n"
+        ),
+    );
+    let delta = main.join("Delta.wiki");
+    write_file(
+        &delta,
+        &format!(
+            "{{{{SHORTDESC:Delta}}}}\n{{{{Article quality|unverified}}}}\n\n'''Delta''' relates to [[Beta]] and [[Gamma]].{body}"
+        ),
+    );
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+
+    let report = lint_article(&paths, &alpha).expect("lint");
+    assert!(!has_rule(&report, "structure.category_count"));
+    assert!(!has_rule(&report, "structure.preferred_category"));
+
+    let report = lint_article(&paths, &delta).expect("lint");
+    let count = report
+        .issues
+        .iter()
+        .find(|issue| issue.rule_id == "structure.category_count")
+        .expect("category count issue");
+    assert!(count.message.contains("expects 2-4"));
+    assert_eq!(
+        count.suggested_fixes[0].replacement_preview.as_deref(),
+        Some("[[Category:Ideas]]")
+    );
+    assert!(has_rule(&report, "structure.preferred_category"));
+}
+
+#[test]
+fn custom_rules_from_config_run_with_scoping_and_safe_fixes() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_common_templates(&paths);
+    write_file(
+        &paths.config_path,
+        r#"
+[[lint.rules]]
+id = "project.milady_case"
+severity = "warning"
+message = "Write Milady with a capital M."
+match = { kind = "prose", pattern = "\\bmilady\\b", replacement = "Milady" }
+
+[[lint.rules]]
+id = "project.infobox_required"
+severity = "suggestion"
+message = "Art articles need an infobox."
+categories = ["Art"]
+match = { kind = "template_absent", template = "Infobox artwork" }
+"#,
+    );
+    write_file(
+        &paths.state_dir.join("lint-rules.toml"),
+        r#"
+[[rules]]
+id = "project.no_trivia"
+severity = "error"
+message = "Fold trivia into the body."
+match = { kind = "heading", pattern = "(?i)^trivia$" }
+
+[[rules]]
+id = "project.old_page"
+severity = "warning"
+message = "Link the renamed page."
+namespaces = ["Main"]
+match = { kind = "link_target", pattern = "^Old Page$", replacement = "New Page" }
+
+[[rules]]
+id = "project.quote_source"
+severity = "warning"
+message = "Quote boxes need a source."
+match = { kind = "template_parameter", template = "Quote", parameter = "source" }
+"#,
+    );
+    let alpha = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    write_file(
+        &alpha,
+        "{{SHORTDESC:Alpha}}\n{{Article quality|unverified}}\n\n'''Alpha''' is a milady piece, see [[Old Page|the page]].\n{{Quote|text=Hi}}\n\n== Trivia ==\nText.\n\n== References ==\n{{Reflist}}\n[[Category:Art]]\n",
+    );
+
+    let report = lint_article(&paths, &alpha).expect("lint");
+    for rule_id in [
+        "project.milady_case",
+        "project.infobox_required",
+        "project.no_trivia",
+        "project.old_page",
+        "project.quote_source",
+    ] {
+        assert!(has_rule(&report, rule_id), "missing {rule_id}");
+    }
+    let trivia = report
+        .issues
+        .iter()
+        .find(|issue| issue.rule_id == "project.no_trivia")
+        .expect("heading issue");
+    assert_eq!(trivia.severity, ArticleLintSeverity::Error);
+    assert_eq!(trivia.evidence.as_deref(), Some("Trivia"));
+    let milady = report
+        .issues
+        .iter()
+        .find(|issue| issue.rule_id == "project.milady_case")
+        .expect("prose issue");
+    let edit = &milady.suggested_fixes[0].edits[0];
+    assert_eq!(edit.replacement, "Milady");
+    assert_eq!(edit.span.line, 4);
+    assert_eq!(
+        edit.span.end_column.map(|end| end - edit.span.column),
+        Some(6)
+    );
+
+    let fixed = fix_article(&paths, &alpha, ArticleFixApplyMode::Safe).expect("safe fix");
+    assert!(fixed.changed);
+    let content = fs::read_to_string(&alpha).expect("read article");
+    assert!(content.contains("a Milady piece"));
+    assert!(content.contains("[[New Page|the page]]"));
+
+    let beta = paths.wiki_content_dir.join("Main").join("Beta.wiki");
+    write_file(
+        &beta,
+        "{{SHORTDESC:Beta}}\n{{Article quality|unverified}}\n\n'''Beta''' is text.\n\n== References ==\n{{Reflist}}\n[[Category:Ideas]]\n",
+    );
+    let report = lint_article(&paths, &beta).expect("lint");
+    assert!(!has_rule(&report, "project.infobox_required"));
+}
+
+#[test]
+fn custom_prose_rules_skip_matches_across_masked_markup_and_reserve_builtin_ids() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_common_templates(&paths);
+    write_file(
+        &paths.config_path,
+        r#"
+[[lint.rules]]
+id = "project.milady_piece"
+severity = "warning"
+message = "Say Milady artwork."
+match = { kind = "prose", pattern = "milady\\s+piece", replacement = "Milady artwork" }
+"#,
+    );
+    let alpha = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    write_file(
+        &alpha,
+        "{{SHORTDESC:Alpha}}\n{{Article quality|unverified}}\n\n'''Alpha''' is a milady<ref>{{Cite web|title=Source}}</ref> piece. Beta is a milady piece.\n\n== References ==\n{{Reflist}}\n",
+    );
+
+    let report = lint_article(&paths, &alpha).expect("lint");
+    let hits = report
+        .issues
+        .iter()
+        .filter(|issue| issue.rule_id == "project.milady_piece")
+        .collect::<Vec<_>>();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].evidence.as_deref(), Some("milady piece"));
+
+    fix_article(&paths, &alpha, ArticleFixApplyMode::Safe).expect("safe fix");
+    let content = fs::read_to_string(&alpha).expect("read article");
+    assert!(content.contains("a milady<ref>{{Cite web|title=Source}}</ref> piece."));
+    assert!(content.contains("Beta is a Milady artwork."));
+
+    write_file(
+        &paths.config_path,
+        r#"
+[[lint.rules]]
+id = "style.spelling"
+severity = "warning"
+message = "Shadow a built-in rule."
+match = { kind = "prose", pattern = "milady" }
+"#,
+    );
+    let error = lint_article(&paths, &alpha).expect_err("reserved id");
+    assert!(error.to_string().contains("built-in `style.` prefix"));
+}
+
+#[test]
+fn inline_suppressions_silence_matching_rules_and_are_counted() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_common_templates(&paths);
+    let article_path = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    write_file(
+        &article_path,
+        "{{SHORTDESC:Alpha}}\n{{Article quality|unverified}}\n\n<!-- wikitool-disable-next-line structure.markdown_heading -->\n## History\nText.\n\n## Legacy\nText.\n<!-- wikitool-disable structure.* -->\n## Notes\n<!-- wikitool-enable structure.* -->\n\n== References ==\n{{Reflist}}\n",
+    );
+
+    let report = lint_article(&paths, &article_path).expect("lint");
+    let headings = report
+        .issues
+        .iter()
+        .filter(|issue| issue.rule_id == "structure.markdown_heading")
+        .collect::<Vec<_>>();
+    assert_eq!(headings.len(), 1);
+    assert_eq!(headings[0].evidence.as_deref(), Some("## Legacy"));
+    assert_eq!(report.suppressed, 2);
+
+    fix_article(&paths, &article_path, ArticleFixApplyMode::Safe).expect("safe fix");
+    let content = fs::read_to_string(&article_path).expect("read article");
+    assert!(content.contains("## History"));
+    assert!(content.contains("== Legacy =="));
+    assert!(content.contains("## Notes"));
+}
+
+#[test]
+fn lint_baseline_hides_recorded_issues_but_reports_new_ones() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_common_templates(&paths);
+    let article_path = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    let body = "{{SHORTDESC:Alpha}}\n{{Article quality|unverified}}\n\n## History\nText.\n\n== References ==\n{{Reflist}}\n";
+    write_file(&article_path, body);
+
+    let resources = load_article_lint_resources(&paths)
+        .expect("resources")
+        .without_baseline();
+    let report =
+        lint_article_with_resources(&paths, &article_path, None, &resources).expect("lint");
+    assert!(report.issue_count > 0);
+    let summary = write_lint_baseline(&paths, std::slice::from_ref(&report)).expect("baseline");
+    assert_eq!(summary.issue_count, report.issue_count);
+    assert!(paths.state_dir.join("lint-baseline.json").exists());
+
+    write_file(&article_path, &format!("\n\n{body}"));
+    let report = lint_article(&paths, &article_path).expect("lint");
+    assert_eq!(report.issue_count, 0);
+    assert!(report.baselined > 0);
+
+    write_file(
+        &article_path,
+        &body.replace("Text.\n", "Text.\n\n## Legacy\nMore.\n"),
+    );
+    let report = lint_article(&paths, &article_path).expect("lint");
+    let headings = report
+        .issues
+        .iter()
+        .filter(|issue| issue.rule_id == "structure.markdown_heading")
+        .collect::<Vec<_>>();
+    assert_eq!(headings.len(), 1);
+    assert_eq!(headings[0].evidence.as_deref(), Some("## Legacy"));
+}
+
+#[test]
+fn batch_lint_selects_tree_targets_and_aggregates_parallel_reports() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_common_templates(&paths);
+    let main = paths.wiki_content_dir.join("Main");
+    let header = "{{SHORTDESC:Page}}\n{{Article quality|unverified}}\n\n";
+    let footer = "\n\n== References ==\n{{Reflist}}\n";
+    write_file(
+        &main.join("Alpha.wiki"),
+        &format!("{header}'''Alpha'''.\n\n## History\nText.{footer}[[Category:Ideas]]\n"),
+    );
+    write_file(
+        &main.join("Beta.wiki"),
+        &format!("{header}'''Beta'''.\n\n## History\nText.\n\n## Legacy\nText.{footer}"),
+    );
+    write_file(
+        &main.join("Gamma.wiki"),
+        &format!("{header}'''Gamma'''.{footer}[[Category:Ideas]]\n"),
+    );
+    write_file(&main.join("Redirect.wiki"), "#REDIRECT [[Alpha]]\n");
+    write_file(
+        &paths.wiki_content_dir.join("Category").join("Ideas.wiki"),
+        "Ideas.\n[[Category:Remilia]]",
+    );
+
+    let filter = ArticleLintTargetFilter {
+        namespaces: vec!["main".to_string()],
+        categories: Vec::new(),
+    };
+    let targets = collect_article_lint_targets(&paths, &filter).expect("namespace targets");
+    assert_eq!(
+        targets,
+        vec![
+            "wiki_content/Main/Alpha.wiki".to_string(),
+            "wiki_content/Main/Beta.wiki".to_string(),
+            "wiki_content/Main/Gamma.wiki".to_string(),
+        ]
+    );
+    let category_filter = ArticleLintTargetFilter {
+        namespaces: Vec::new(),
+        categories: vec!["Ideas".to_string()],
+    };
+    assert!(collect_article_lint_targets(&paths, &category_filter).is_err());
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+    assert_eq!(
+        collect_article_lint_targets(&paths, &category_filter).expect("category targets"),
+        vec![
+            "wiki_content/Main/Alpha.wiki".to_string(),
+            "wiki_content/Main/Gamma.wiki".to_string(),
+        ]
+    );
+
+    let resources = load_article_lint_resources(&paths).expect("resources");
+    let sequential = lint_articles_with_resources(&paths, &targets, &resources, 1).expect("lint");
+    let parallel = lint_articles_with_resources(&paths, &targets, &resources, 3).expect("lint");
+    assert_eq!(sequential, parallel);
+
+    let aggregate = aggregate_article_lint(&parallel);
+    assert_eq!(aggregate.target_count, 3);
+    assert_eq!(
+        aggregate.pages[1].relative_path,
+        "wiki_content/Main/Beta.wiki"
+    );
+    let headings = aggregate
+        .rules
+        .iter()
+        .find(|rule| rule.rule_id == "structure.markdown_heading")
+        .expect("heading rule count");
+    assert_eq!(headings.issue_count, 3);
+    assert_eq!(headings.page_count, 2);
+    assert_eq!(
+        aggregate.total_errors,
+        aggregate
+            .pages
+            .iter()
+            .map(|page| page.errors)
+            .sum::<usize>()
+    );
+}
+
 #[test]
 fn detects_red_links_in_see_also() {
     let temp = tempdir().expect("tempdir");
@@ -723,6 +1318,198 @@ fn detects_unknown_parameters_for_templatedata_backed_templates() {
     assert!(has_rule(&report, "template.unknown_parameter"));
 }
 
+#[test]
+fn validates_templatedata_parameter_types_requirements_and_aliases() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_common_templates(&paths);
+    write_file(
+        &paths
+            .templates_dir
+            .join("infobox")
+            .join("Template_Profile_box.wiki"),
+        r#"<includeonly>{{{name|}}}</includeonly><noinclude>
+<templatedata>
+{
+  "description": "Profile box",
+  "params": {
+    "name": {"label": "Name", "required": true},
+    "image": {"aliases": ["photo"], "type": "wiki-file-name"},
+    "born": {"type": "date"},
+    "website": {"type": "url"},
+    "count": {"type": "number"},
+    "active": {"type": "boolean"},
+    "home": {"type": "wiki-page-name"},
+    "legacy": {"deprecated": "Use home instead."}
+  }
+}
+</templatedata>
+</noinclude>"#,
+    );
+    let article_path = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    write_file(
+        &article_path,
+        "{{SHORTDESC:Alpha}}\n{{Article quality|unverified}}\n{{Profile box|photo = Alpha.png|born=sometime|website=example.com|count=12 items|active=maybe|home=[[Alpha]]|legacy=1}}\n{{Profile box|name=Beta|born=5 March 2023|website=https://example.com|count=1,200|active=yes|home=Beta}}\n\n'''Alpha''' is a page.\n\n== References ==\n{{Reflist}}\n",
+    );
+
+    let report = lint_article(&paths, &article_path).expect("lint");
+    let evidence_for = |rule_id: &str| {
+        report
+            .issues
+            .iter()
+            .filter(|issue| issue.rule_id == rule_id)
+            .filter_map(|issue| issue.evidence.clone())
+            .collect::<Vec<_>>()
+    };
+    let type_evidence = evidence_for("template.parameter_type");
+    assert_eq!(type_evidence.len(), 5, "{type_evidence:?}");
+    for parameter in ["born", "website", "count", "active", "home"] {
+        assert!(
+            type_evidence
+                .iter()
+                .any(|evidence| evidence.contains(&format!("parameter={parameter} "))),
+            "{parameter}: {type_evidence:?}"
+        );
+    }
+    assert_eq!(
+        evidence_for("template.missing_required_parameter"),
+        vec!["Template:Profile box missing_parameters=name".to_string()]
+    );
+    assert_eq!(
+        evidence_for("template.deprecated_parameter"),
+        vec!["Template:Profile box parameter=legacy".to_string()]
+    );
+    assert!(!has_rule(&report, "template.unknown_parameter"));
+    assert_eq!(
+        evidence_for("template.parameter_alias"),
+        vec!["Template:Profile box parameter=photo canonical=image".to_string()]
+    );
+
+    let fixed = fix_article(&paths, &article_path, ArticleFixApplyMode::Safe).expect("safe fix");
+    assert!(fixed.changed);
+    let content = fs::read_to_string(&article_path).expect("read article");
+    assert!(content.contains("{{Profile box|image = Alpha.png|born=sometime"));
+}
+
+#[test]
+fn checks_citation_core_fields_and_normalizes_mixed_dates() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_common_templates(&paths);
+    let article_path = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    write_file(
+        &article_path,
+        "{{SHORTDESC:Alpha}}\n{{Article quality|unverified}}\n\n'''Alpha''' is a page.<ref>{{Cite web|url=https://example.com/a|title=A|access-date=5 March 2023}}</ref> It grew.<ref>{{Cite news|title=B|date=7 April 2023}}</ref> It shrank.<ref>{{Cite web|url=https://example.com/c|title=C|date=2023-05-09|access-date=June 1, 2023|archive_date=June 3, 2023}}</ref> It held.<ref>{{Cite web|url=https://example.com/d|title=D|date=10 June 2023|access_date=2023-06-02|archive-date=February 31, 2023}}</ref>\n\n== References ==\n{{Reflist}}\n",
+    );
+
+    let report = lint_article(&paths, &article_path).expect("lint");
+    let evidence_for = |rule_id: &str| {
+        report
+            .issues
+            .iter()
+            .filter(|issue| issue.rule_id == rule_id)
+            .filter_map(|issue| issue.evidence.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        evidence_for("citation.missing_core_field"),
+        vec!["Template:Cite news missing_fields=work".to_string()]
+    );
+    let mut mixed = evidence_for("citation.mixed_date_format");
+    mixed.sort();
+    assert_eq!(
+        mixed,
+        vec![
+            "access-date=June 1, 2023".to_string(),
+            "archive date=June 3, 2023".to_string(),
+            "date=2023-05-09".to_string()
+        ]
+    );
+
+    let fixed = fix_article(&paths, &article_path, ArticleFixApplyMode::Safe).expect("safe fix");
+    assert!(fixed.changed);
+    let content = fs::read_to_string(&article_path).expect("read article");
+    assert!(
+        content.contains(
+            "|title=C|date=9 May 2023|access-date=1 June 2023|archive_date=3 June 2023}}"
+        )
+    );
+    assert!(content.contains("|access_date=2023-06-02|archive-date=February 31, 2023}}"));
+
+    write_file(
+        &article_path,
+        "{{SHORTDESC:Alpha}}\n{{Article quality|unverified}}\n{{Use mdy dates}}\n\n'''Alpha''' is a page.<ref>{{Cite web|url=https://example.com/a|title=A|access-date=5 March 2023}}</ref>\n\n== References ==\n{{Reflist}}\n",
+    );
+    let report = lint_article(&paths, &article_path).expect("lint");
+    let issue = report
+        .issues
+        .iter()
+        .find(|issue| issue.rule_id == "citation.mixed_date_format")
+        .expect("declared date style");
+    assert_eq!(
+        issue.suggested_fixes[0].replacement_preview.as_deref(),
+        Some("March 5, 2023")
+    );
+}
+
+#[test]
+fn suggests_spelling_fixes_in_prose_only() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_common_templates(&paths);
+    let article_path = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    write_file(
+        &article_path,
+        "{{SHORTDESC:Alpha}}\n{{Article quality|unverified}}\n\n'''Alpha''' recieved a visit from the cattel.<ref>{{Cite web|url=https://example.com/a|title=Recieved}} recieved</ref> See [[Recieved letters|recieved letters]] and <code>recieved</code>.\n\n== References ==\n{{Reflist}}\n",
+    );
+
+    write_file(
+        &paths.state_dir.join("spelling-words.txt"),
+        "the\na\nfrom\nsee\nand\nvisit\nreceive\nletter\ncattle\n",
+    );
+
+    let spelling_issues = |paths: &ResolvedPaths| {
+        lint_article(paths, &article_path)
+            .expect("lint")
+            .issues
+            .into_iter()
+            .filter(|issue| issue.rule_id == "style.spelling")
+            .collect::<Vec<_>>()
+    };
+    let issues = spelling_issues(&paths);
+    assert_eq!(
+        issues
+            .iter()
+            .filter_map(|issue| issue.evidence.as_deref())
+            .collect::<Vec<_>>(),
+        vec!["recieved", "cattel"]
+    );
+    assert_eq!(issues[0].severity, ArticleLintSeverity::Suggestion);
+    assert_eq!(
+        issues[0].suggested_fixes[0].replacement_preview.as_deref(),
+        Some("received")
+    );
+
+    write_file(
+        &paths.state_dir.join("spelling-dictionary.txt"),
+        "# house terms\nCattel\n",
+    );
+    let issues = spelling_issues(&paths);
+    assert_eq!(
+        issues
+            .iter()
+            .filter_map(|issue| issue.evidence.as_deref())
+            .collect::<Vec<_>>(),
+        vec!["recieved"]
+    );
+}
+
 #[test]
 fn detects_unavailable_modules_for_direct_invoke() {
     let temp = tempdir().expect("tempdir");
@@ -956,3 +1743,41 @@ fn detects_suspicious_html_tags_even_when_they_are_not_known_extensions() {
     let report = lint_article(&paths, &article_path).expect("lint");
     assert!(has_rule(&report, "capability.unsupported_extension_tag"));
 }
+
+#[test]
+fn report_includes_quality_metrics() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    let paths = paths(&project_root);
+    write_instruction_sources(&paths);
+    write_common_templates(&paths);
+    let article_path = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    write_file(
+        &article_path,
+        "{{SHORTDESC:Alpha}}\n{{Article quality|unverified}}\n\n'''Alpha''' is a page about things.\n\n== History ==\nIt was founded in 2020.<ref>{{Cite web|title=Founding}}</ref>\n\nIt then grew without any source.\n\n== References ==\n{{Reflist}}\n",
+    );
+
+    let report = lint_article(&paths, &article_path).expect("lint");
+    let quality = &report.quality;
+    assert_eq!(quality.prose_words, 17);
+    assert_eq!(quality.lead_words, 6);
+    assert_eq!(quality.reference_count, 1);
+    assert_eq!(quality.uncited_paragraphs, 1);
+    assert_eq!(quality.section_count, 2);
+    assert_eq!(quality.references_per_100_words_milli, 5882);
+    assert_eq!(quality.inbound_links, None);
+
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Beta.wiki"),
+        "'''Beta''' links to [[Alpha]].\n",
+    );
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+    let report = lint_article(&paths, &article_path).expect("lint");
+    assert_eq!(report.quality.inbound_links, None);
+    let resources = load_article_lint_resources(&paths)
+        .expect("resources")
+        .with_inbound_links();
+    let report =
+        lint_article_with_resources(&paths, &article_path, None, &resources).expect("lint");
+    assert_eq!(report.quality.inbound_links, Some(1));
+}
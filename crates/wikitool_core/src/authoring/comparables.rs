@@ -1,6 +1,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use crate::content_store::parsing;
+use crate::knowledge::centrality::load_pagerank_scores;
 use crate::knowledge::prelude::{
     TermProfilePageHit, candidate_limit, query_page_records_from_aliases_for_connection,
     query_page_records_from_sections_for_connection,
@@ -172,10 +173,21 @@ pub(crate) fn collect_related_pages_for_authoring(
     }
 
     let mut ranked = candidates.into_values().collect::<Vec<_>>();
+    let pagerank = load_pagerank_scores(
+        connection,
+        ranked.iter().map(|candidate| candidate.title.as_str()),
+    )?;
+    let centrality = |title: &str| {
+        pagerank
+            .get(&title.to_ascii_lowercase())
+            .copied()
+            .unwrap_or(0.0)
+    };
     ranked.sort_by(|left, right| {
         right
             .score
             .cmp(&left.score)
+            .then_with(|| centrality(&right.title).total_cmp(&centrality(&left.title)))
             .then_with(|| left.title.cmp(&right.title))
     });
     ranked.truncate(inputs.limit);
@@ -149,6 +149,7 @@ pub fn build_authoring_knowledge_pack(
             term_profile_page_weights,
             authority_page_weights,
             identifier_page_weights,
+            ..ChunkRerankSignals::default()
         },
     )?;
     let mut retrieval_mode = chunk_report.retrieval_mode;
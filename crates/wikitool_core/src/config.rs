@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::env;
 use std::fs;
 use std::path::Path;
@@ -26,6 +27,8 @@ pub const ENV_WIKITOOL_ARTICLE_PATH: &str = "WIKITOOL_ARTICLE_PATH";
 pub struct WikiConfig {
     #[serde(default)]
     pub wiki: WikiSection,
+    #[serde(default, skip_serializing_if = "LintSection::is_empty")]
+    pub lint: LintSection,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq)]
@@ -45,6 +48,79 @@ pub struct CustomNamespace {
     pub folder: Option<String>,
 }
 
+/// Project lint rules, from `[[lint.rules]]` in config.toml or `[[rules]]` in
+/// `lint-rules.toml` beside it.
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq)]
+pub struct LintSection {
+    #[serde(default)]
+    pub rules: Vec<CustomLintRule>,
+}
+
+impl LintSection {
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CustomLintRule {
+    pub id: String,
+    pub severity: CustomLintSeverity,
+    pub message: String,
+    pub remediation: Option<String>,
+    /// Only lint pages in these namespaces (e.g. `Main`); empty means all.
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+    /// Only lint pages in at least one of these categories; empty means all.
+    #[serde(default)]
+    pub categories: Vec<String>,
+    #[serde(rename = "match")]
+    pub matcher: CustomLintMatcher,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomLintSeverity {
+    Error,
+    Warning,
+    Suggestion,
+}
+
+/// What a custom rule looks for. Patterns are regular expressions; a
+/// `replacement` (which may use `$1` capture references) makes the rule
+/// offer a safe fix.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CustomLintMatcher {
+    /// Pattern over article prose, outside templates, references, comments
+    /// and quotations.
+    Prose {
+        pattern: String,
+        replacement: Option<String>,
+    },
+    TemplatePresent {
+        template: String,
+    },
+    TemplateAbsent {
+        template: String,
+    },
+    /// Every use of `template` must set `parameter`.
+    TemplateParameter {
+        template: String,
+        parameter: String,
+    },
+    /// Pattern over section heading text.
+    Heading {
+        pattern: String,
+        replacement: Option<String>,
+    },
+    /// Pattern over wikilink targets.
+    LinkTarget {
+        pattern: String,
+        replacement: Option<String>,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ResolvedConfigValue {
     pub value: Option<String>,
@@ -280,6 +356,33 @@ pub fn load_config(config_path: &Path) -> Result<WikiConfig> {
     Ok(parsed)
 }
 
+/// Custom lint rules from `config.toml` followed by `lint-rules.toml` in the
+/// same directory. Rule ids must be unique across both files.
+pub fn load_lint_rules(config_path: &Path) -> Result<Vec<CustomLintRule>> {
+    let mut rules = load_config(config_path)?.lint.rules;
+    let rules_path = config_path.with_file_name("lint-rules.toml");
+    if rules_path.exists() {
+        let content = fs::read_to_string(&rules_path)
+            .with_context(|| format!("failed to read {}", rules_path.display()))?;
+        let parsed: LintSection = toml::from_str(&content)
+            .with_context(|| format!("failed to parse {}", rules_path.display()))?;
+        rules.extend(parsed.rules);
+    }
+    let mut seen = BTreeSet::new();
+    for rule in &rules {
+        if rule.id.trim().is_empty() {
+            bail!("custom lint rule is missing an id");
+        }
+        if !seen.insert(rule.id.as_str()) {
+            bail!(
+                "custom lint rule id `{}` is declared more than once",
+                rule.id
+            );
+        }
+    }
+    Ok(rules)
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct WikiConfigPatch {
     pub set_url: Option<String>,
@@ -400,6 +503,52 @@ mod tests {
         assert!(config.wiki_url().is_none());
     }
 
+    #[test]
+    fn load_lint_rules_merges_rule_file_and_rejects_duplicate_ids() {
+        let temp = tempdir().expect("tempdir");
+        let config_path = temp.path().join("config.toml");
+        fs::write(
+            &config_path,
+            r#"
+[[lint.rules]]
+id = "project.a"
+severity = "warning"
+message = "A"
+match = { kind = "template_present", template = "Stub" }
+"#,
+        )
+        .expect("write config");
+        let rules_file = r#"
+[[rules]]
+id = "project.b"
+severity = "suggestion"
+message = "B"
+namespaces = ["Main"]
+match = { kind = "prose", pattern = "foo" }
+"#;
+        fs::write(temp.path().join("lint-rules.toml"), rules_file).expect("write rules");
+
+        let rules = load_lint_rules(&config_path).expect("load rules");
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[1].id, "project.b");
+        assert_eq!(rules[1].namespaces, vec!["Main".to_string()]);
+        assert!(matches!(
+            rules[1].matcher,
+            CustomLintMatcher::Prose {
+                replacement: None,
+                ..
+            }
+        ));
+
+        fs::write(
+            temp.path().join("lint-rules.toml"),
+            rules_file.replace("project.b", "project.a"),
+        )
+        .expect("write rules");
+        let error = load_lint_rules(&config_path).expect_err("duplicate id");
+        assert!(error.to_string().contains("project.a"));
+    }
+
     #[test]
     fn load_config_parses_wiki_section() {
         let temp = tempdir().expect("tempdir");
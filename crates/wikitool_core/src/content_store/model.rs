@@ -44,6 +44,14 @@ pub(crate) struct ParsedLink {
     pub(crate) is_category_membership: bool,
 }
 
+/// A wikilink carrying a `#fragment`; `target` is `None` for same-page links
+/// such as `[[#History]]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParsedSectionLink {
+    pub(crate) target: Option<ParsedLink>,
+    pub(crate) anchor: String,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct IndexedPageRecord {
     pub(crate) title: String,
@@ -209,6 +217,8 @@ pub(crate) struct ChunkRerankSignals {
     pub(crate) term_profile_page_weights: BTreeMap<String, usize>,
     pub(crate) authority_page_weights: BTreeMap<String, usize>,
     pub(crate) identifier_page_weights: BTreeMap<String, usize>,
+    /// Link-graph PageRank keyed by lowercased title; breaks score ties only.
+    pub(crate) centrality_page_scores: BTreeMap<String, f64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
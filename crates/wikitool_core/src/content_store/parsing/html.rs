@@ -93,6 +93,48 @@ pub(crate) fn find_closing_html_tag(
     None
 }
 
+/// Byte ranges of every `<tag_name>` element, including its tags. A
+/// self-closing tag covers only itself; an unclosed element runs to the end
+/// of `content`.
+pub(crate) fn html_element_ranges(content: &str, tag_name: &str) -> Vec<(usize, usize)> {
+    let mut out = Vec::new();
+    let mut cursor = 0usize;
+    while let Some(found) = content[cursor..].find('<') {
+        let start = cursor + found;
+        let Some((tag_end, _, self_closing)) = parse_open_tag(content, start, tag_name) else {
+            cursor = start + 1;
+            continue;
+        };
+        let end = if self_closing {
+            tag_end
+        } else {
+            find_closing_html_tag(content, tag_end, tag_name)
+                .map(|(_, close_end)| close_end)
+                .unwrap_or(content.len())
+        };
+        out.push((start, end));
+        cursor = end;
+    }
+    out
+}
+
+/// Byte ranges of every `<!-- ... -->` comment; an unclosed comment runs to
+/// the end of `content`.
+pub(crate) fn html_comment_ranges(content: &str) -> Vec<(usize, usize)> {
+    let mut out = Vec::new();
+    let mut cursor = 0usize;
+    while let Some(found) = content[cursor..].find("<!--") {
+        let start = cursor + found;
+        let end = content[start + 4..]
+            .find("-->")
+            .map(|index| start + 4 + index + 3)
+            .unwrap_or(content.len());
+        out.push((start, end));
+        cursor = end;
+    }
+    out
+}
+
 pub(crate) fn parse_html_attributes(value: &str) -> BTreeMap<String, String> {
     let chars = value.chars().collect::<Vec<_>>();
     let mut cursor = 0usize;
@@ -5,12 +5,34 @@ pub(crate) fn extract_wikilinks(content: &str) -> Vec<ParsedLink> {
 }
 
 pub(crate) fn extract_wikilinks_for_namespace(content: &str, namespace: &str) -> Vec<ParsedLink> {
-    if namespace.eq_ignore_ascii_case(Namespace::Module.as_str()) {
+    let Some(ignored_tags) = wikilink_ignored_tags(namespace) else {
+        return Vec::new();
+    };
+    extract_wikilinks_scoped(content, ignored_tags, true)
+}
+
+/// Wikilinks that point at a section, with the fragment kept verbatim.
+/// Fragments built from templates or magic words are skipped.
+pub(crate) fn extract_section_links_for_namespace(
+    content: &str,
+    namespace: &str,
+) -> Vec<ParsedSectionLink> {
+    let Some(ignored_tags) = wikilink_ignored_tags(namespace) else {
         return Vec::new();
+    };
+    wikilink_inners_scoped(content, ignored_tags, true)
+        .into_iter()
+        .filter_map(parse_section_link)
+        .collect()
+}
+
+fn wikilink_ignored_tags(namespace: &str) -> Option<&'static [&'static str]> {
+    if namespace.eq_ignore_ascii_case(Namespace::Module.as_str()) {
+        return None;
     }
 
-    let ignored_tags = if namespace.eq_ignore_ascii_case(Namespace::Template.as_str()) {
-        &[
+    if namespace.eq_ignore_ascii_case(Namespace::Template.as_str()) {
+        Some(&[
             "nowiki",
             "pre",
             "syntaxhighlight",
@@ -18,19 +40,17 @@ pub(crate) fn extract_wikilinks_for_namespace(content: &str, namespace: &str) ->
             "code",
             "templatedata",
             "noinclude",
-        ][..]
+        ])
     } else {
-        &[
+        Some(&[
             "nowiki",
             "pre",
             "syntaxhighlight",
             "source",
             "code",
             "templatedata",
-        ][..]
-    };
-
-    extract_wikilinks_scoped(content, ignored_tags, true)
+        ])
+    }
 }
 
 fn extract_wikilinks_scoped(
@@ -38,6 +58,37 @@ fn extract_wikilinks_scoped(
     ignored_tags: &[&str],
     skip_html_comments: bool,
 ) -> Vec<ParsedLink> {
+    wikilink_inners_scoped(content, ignored_tags, skip_html_comments)
+        .into_iter()
+        .filter_map(parse_wikilink)
+        .collect()
+}
+
+/// Byte ranges of every `[[...]]` wikilink, including its brackets.
+pub(crate) fn wikilink_ranges(content: &str) -> Vec<(usize, usize)> {
+    wikilink_spans_scoped(content, &[], false)
+        .into_iter()
+        .map(|(start, end)| (start - 2, end + 2))
+        .collect()
+}
+
+fn wikilink_inners_scoped<'a>(
+    content: &'a str,
+    ignored_tags: &[&str],
+    skip_html_comments: bool,
+) -> Vec<&'a str> {
+    wikilink_spans_scoped(content, ignored_tags, skip_html_comments)
+        .into_iter()
+        .map(|(start, end)| &content[start..end])
+        .collect()
+}
+
+/// Byte ranges of wikilink inner text, between the brackets.
+fn wikilink_spans_scoped(
+    content: &str,
+    ignored_tags: &[&str],
+    skip_html_comments: bool,
+) -> Vec<(usize, usize)> {
     let bytes = content.as_bytes();
     let mut out = Vec::new();
     let mut cursor = 0usize;
@@ -62,10 +113,7 @@ fn extract_wikilinks_scoped(
                 break;
             }
 
-            let inner = &content[start..end];
-            if let Some(link) = parse_wikilink(inner) {
-                out.push(link);
-            }
+            out.push((start, end));
             cursor = end + 2;
             continue;
         }
@@ -224,6 +272,30 @@ pub(crate) fn parse_wikilink(inner: &str) -> Option<ParsedLink> {
     })
 }
 
+fn parse_section_link(inner: &str) -> Option<ParsedSectionLink> {
+    let target_part = inner.split('|').next().unwrap_or("").trim();
+    let (page, anchor) = target_part.split_once('#')?;
+    let anchor = anchor.trim();
+    if anchor.is_empty() || anchor.contains('{') || page.contains('{') {
+        return None;
+    }
+    let target = if page.trim_start_matches(':').trim().is_empty() {
+        None
+    } else {
+        Some(parse_wikilink(page)?)
+    };
+    if target
+        .as_ref()
+        .is_some_and(|link| link.is_category_membership)
+    {
+        return None;
+    }
+    Some(ParsedSectionLink {
+        target,
+        anchor: anchor.to_string(),
+    })
+}
+
 pub(crate) fn is_parser_placeholder_title(value: &str) -> bool {
     let trimmed = value.trim();
     let title = trimmed
@@ -5,7 +5,10 @@ mod signals;
 mod source;
 mod templates;
 
-pub(crate) use extract::{extract_reference_records, extract_reference_records_from_sections};
+pub(crate) use extract::{
+    extract_reference_records, extract_reference_records_for_section,
+    extract_reference_records_from_sections,
+};
 pub(crate) use identifiers::{
     build_reference_authority_key, build_reference_authority_retrieval_text,
     normalize_reference_identifier_token, normalize_reference_identifier_value,
@@ -24,6 +24,15 @@ pub(crate) fn summarize_template_invocations(
 }
 
 pub(crate) fn extract_template_invocations(content: &str) -> Vec<ParsedTemplateInvocation> {
+    template_spans(content)
+        .into_iter()
+        .filter_map(|(start, end)| parse_template_invocation(&content[start + 2..end - 2]))
+        .collect()
+}
+
+/// Byte ranges of every `{{...}}` including its braces, nested ones too, in
+/// the order they close.
+pub(crate) fn template_spans(content: &str) -> Vec<(usize, usize)> {
     let bytes = content.as_bytes();
     let mut out = Vec::new();
     let mut cursor = 0usize;
@@ -31,18 +40,13 @@ pub(crate) fn extract_template_invocations(content: &str) -> Vec<ParsedTemplateI
 
     while cursor + 1 < bytes.len() {
         if bytes[cursor] == b'{' && bytes[cursor + 1] == b'{' {
-            stack.push(cursor + 2);
+            stack.push(cursor);
             cursor += 2;
             continue;
         }
         if bytes[cursor] == b'}' && bytes[cursor + 1] == b'}' {
-            if let Some(start) = stack.pop()
-                && cursor >= start
-            {
-                let inner = &content[start..cursor];
-                if let Some(invocation) = parse_template_invocation(inner) {
-                    out.push(invocation);
-                }
+            if let Some(start) = stack.pop() {
+                out.push((start, cursor + 2));
             }
             cursor += 2;
             continue;
@@ -0,0 +1,398 @@
+//! Tool-neutral lint findings and their SARIF 2.1.0 and checkstyle renderings,
+//! so code-scanning UIs and editors can read article and module lint results.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+
+use crate::article_lint::{ArticleLintReport, ArticleLintSeverity, FixEdit, TextSpan};
+use crate::filesystem::title_to_relative_path;
+use crate::lint::{LuaLintIssue, LuaLintReport, LuaLintSeverity};
+use crate::runtime::ResolvedPaths;
+use crate::support::normalize_path;
+
+const SARIF_SCHEMA_URI: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const SRCROOT_BASE_ID: &str = "%SRCROOT%";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FindingLevel {
+    Error,
+    Warning,
+    Note,
+}
+
+impl FindingLevel {
+    pub fn as_sarif(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Note => "note",
+        }
+    }
+
+    pub fn as_checkstyle(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Note => "info",
+        }
+    }
+}
+
+impl From<ArticleLintSeverity> for FindingLevel {
+    fn from(value: ArticleLintSeverity) -> Self {
+        match value {
+            ArticleLintSeverity::Error => Self::Error,
+            ArticleLintSeverity::Warning => Self::Warning,
+            ArticleLintSeverity::Suggestion => Self::Note,
+        }
+    }
+}
+
+impl From<LuaLintSeverity> for FindingLevel {
+    fn from(value: LuaLintSeverity) -> Self {
+        match value {
+            LuaLintSeverity::Error => Self::Error,
+            LuaLintSeverity::Warning => Self::Warning,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FindingFix {
+    pub description: String,
+    pub edits: Vec<FixEdit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub rule_id: String,
+    pub level: FindingLevel,
+    pub message: String,
+    /// Project-relative path with `/` separators.
+    pub relative_path: String,
+    pub span: Option<TextSpan>,
+    pub help: Option<String>,
+    /// Only fixes with concrete edits; assisted fixes stay in `help`.
+    pub fixes: Vec<FindingFix>,
+}
+
+pub fn findings_from_article_reports(reports: &[ArticleLintReport]) -> Vec<Finding> {
+    reports
+        .iter()
+        .flat_map(|report| {
+            report.issues.iter().map(|issue| Finding {
+                rule_id: issue.rule_id.clone(),
+                level: issue.severity.into(),
+                message: match &issue.evidence {
+                    Some(evidence) => format!("{} ({evidence})", issue.message),
+                    None => issue.message.clone(),
+                },
+                relative_path: report.relative_path.clone(),
+                span: issue.span.clone(),
+                help: issue.suggested_remediation.clone(),
+                fixes: issue
+                    .suggested_fixes
+                    .iter()
+                    .filter(|fix| !fix.edits.is_empty())
+                    .map(|fix| FindingFix {
+                        description: fix.label.clone(),
+                        edits: fix.edits.clone(),
+                    })
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+pub fn findings_from_lua_report(
+    paths: &ResolvedPaths,
+    report: &LuaLintReport,
+) -> Result<Vec<Finding>> {
+    let mut out = Vec::new();
+    for result in &report.results {
+        let relative_path = title_to_relative_path(paths, &result.title, false)?;
+        for issue in result.errors.iter().chain(&result.warnings) {
+            out.push(lua_finding(&relative_path, issue));
+        }
+    }
+    Ok(out)
+}
+
+fn lua_finding(relative_path: &str, issue: &LuaLintIssue) -> Finding {
+    Finding {
+        rule_id: issue.code.clone(),
+        level: issue.severity.into(),
+        message: issue.message.clone(),
+        relative_path: relative_path.to_string(),
+        span: Some(TextSpan {
+            line: issue.line,
+            column: issue.column,
+            end_line: issue.end_line,
+            end_column: issue.end_column,
+        }),
+        help: None,
+        fixes: Vec::new(),
+    }
+}
+
+/// One SARIF run per tool invocation. Artifact URIs are relative to
+/// `%SRCROOT%`, which points at `project_root`; columns count Unicode code
+/// points, matching [`TextSpan`].
+pub fn render_sarif(tool_name: &str, project_root: &Path, findings: &[Finding]) -> Result<String> {
+    let mut rule_indexes = BTreeMap::new();
+    for finding in findings {
+        let next = rule_indexes.len();
+        rule_indexes.entry(finding.rule_id.as_str()).or_insert(next);
+    }
+    let mut rules = rule_indexes.iter().collect::<Vec<_>>();
+    rules.sort_by_key(|(_, index)| **index);
+    let rules = rules
+        .into_iter()
+        .map(|(rule_id, _)| sarif_rule(rule_id, findings))
+        .collect::<Vec<_>>();
+    let results = findings
+        .iter()
+        .map(|finding| sarif_result(finding, rule_indexes[finding.rule_id.as_str()]))
+        .collect::<Vec<_>>();
+
+    let document = json!({
+        "$schema": SARIF_SCHEMA_URI,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": tool_name,
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "originalUriBaseIds": {
+                SRCROOT_BASE_ID: { "uri": directory_uri(project_root) }
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }]
+    });
+    serde_json::to_string_pretty(&document).context("failed to serialize SARIF report")
+}
+
+fn sarif_rule(rule_id: &str, findings: &[Finding]) -> Value {
+    let mut rule = json!({ "id": rule_id });
+    if let Some(category) = rule_id.split_once('.').map(|(category, _)| category) {
+        rule["properties"] = json!({ "category": category });
+    }
+    if let Some(help) = findings
+        .iter()
+        .filter(|finding| finding.rule_id == rule_id)
+        .find_map(|finding| finding.help.as_deref())
+    {
+        rule["help"] = json!({ "text": help });
+    }
+    rule
+}
+
+fn sarif_result(finding: &Finding, rule_index: usize) -> Value {
+    let mut physical_location = json!({
+        "artifactLocation": artifact_location(&finding.relative_path),
+    });
+    if let Some(span) = &finding.span {
+        physical_location["region"] = sarif_region(span);
+    }
+    let mut result = json!({
+        "ruleId": finding.rule_id,
+        "ruleIndex": rule_index,
+        "level": finding.level.as_sarif(),
+        "message": { "text": finding.message },
+        "locations": [{ "physicalLocation": physical_location }],
+    });
+    if !finding.fixes.is_empty() {
+        result["fixes"] = Value::Array(
+            finding
+                .fixes
+                .iter()
+                .map(|fix| {
+                    json!({
+                        "description": { "text": fix.description },
+                        "artifactChanges": [{
+                            "artifactLocation": artifact_location(&finding.relative_path),
+                            "replacements": fix
+                                .edits
+                                .iter()
+                                .map(|edit| json!({
+                                    "deletedRegion": sarif_region(&edit.span),
+                                    "insertedContent": { "text": edit.replacement },
+                                }))
+                                .collect::<Vec<_>>(),
+                        }],
+                    })
+                })
+                .collect(),
+        );
+    }
+    result
+}
+
+fn artifact_location(relative_path: &str) -> Value {
+    json!({ "uri": relative_path, "uriBaseId": SRCROOT_BASE_ID })
+}
+
+fn sarif_region(span: &TextSpan) -> Value {
+    let mut region = json!({ "startLine": span.line, "startColumn": span.column });
+    if let Some(end_line) = span.end_line {
+        region["endLine"] = json!(end_line);
+    }
+    if let Some(end_column) = span.end_column {
+        region["endColumn"] = json!(end_column);
+    }
+    region
+}
+
+fn directory_uri(path: &Path) -> String {
+    let mut normalized = normalize_path(path).replace(' ', "%20");
+    if !normalized.ends_with('/') {
+        normalized.push('/');
+    }
+    if normalized.starts_with('/') {
+        format!("file://{normalized}")
+    } else {
+        format!("file:///{normalized}")
+    }
+}
+
+/// Checkstyle 4.3 XML, grouped by file in first-seen order.
+pub fn render_checkstyle(findings: &[Finding]) -> String {
+    let mut files: Vec<(&str, Vec<&Finding>)> = Vec::new();
+    for finding in findings {
+        match files
+            .iter_mut()
+            .find(|(path, _)| *path == finding.relative_path)
+        {
+            Some((_, entries)) => entries.push(finding),
+            None => files.push((finding.relative_path.as_str(), vec![finding])),
+        }
+    }
+    let mut out =
+        String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n");
+    for (path, entries) in files {
+        out.push_str(&format!("  <file name=\"{}\">\n", escape_xml(path)));
+        for finding in entries {
+            let (line, column) = finding
+                .span
+                .as_ref()
+                .map(|span| (span.line, span.column))
+                .unwrap_or((1, 1));
+            out.push_str(&format!(
+                "    <error line=\"{line}\" column=\"{column}\" severity=\"{}\" message=\"{}\" source=\"{}\"/>\n",
+                finding.level.as_checkstyle(),
+                escape_xml(&finding.message),
+                escape_xml(&finding.rule_id)
+            ));
+        }
+        out.push_str("  </file>\n");
+    }
+    out.push_str("</checkstyle>\n");
+    out
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+        .replace('\n', "&#10;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_findings() -> Vec<Finding> {
+        vec![
+            Finding {
+                rule_id: "structure.markdown_heading".to_string(),
+                level: FindingLevel::Error,
+                message: "Markdown heading <##>".to_string(),
+                relative_path: "wiki_content/Main/Alpha.wiki".to_string(),
+                span: Some(TextSpan {
+                    line: 4,
+                    column: 1,
+                    end_line: Some(4),
+                    end_column: Some(11),
+                }),
+                help: Some("Use == Heading ==".to_string()),
+                fixes: vec![FindingFix {
+                    description: "Convert heading".to_string(),
+                    edits: vec![FixEdit {
+                        span: TextSpan {
+                            line: 4,
+                            column: 1,
+                            end_line: Some(4),
+                            end_column: Some(11),
+                        },
+                        replacement: "== History ==".to_string(),
+                    }],
+                }],
+            },
+            Finding {
+                rule_id: "unused_variable".to_string(),
+                level: FindingLevel::Warning,
+                message: "x is unused".to_string(),
+                relative_path: "templates/Module_A.lua".to_string(),
+                span: None,
+                help: None,
+                fixes: Vec::new(),
+            },
+        ]
+    }
+
+    #[test]
+    fn renders_sarif_rules_regions_and_fixes() {
+        let sarif = render_sarif("wikitool", Path::new("/work/my wiki"), &sample_findings())
+            .expect("sarif");
+        let value: Value = serde_json::from_str(&sarif).expect("parse sarif");
+        assert_eq!(value["version"], "2.1.0");
+        let run = &value["runs"][0];
+        assert_eq!(
+            run["originalUriBaseIds"]["%SRCROOT%"]["uri"],
+            "file:///work/my%20wiki/"
+        );
+        assert_eq!(
+            run["tool"]["driver"]["rules"][0]["id"],
+            "structure.markdown_heading"
+        );
+        assert_eq!(
+            run["tool"]["driver"]["rules"][0]["properties"]["category"],
+            "structure"
+        );
+        let first = &run["results"][0];
+        assert_eq!(first["level"], "error");
+        assert_eq!(
+            first["locations"][0]["physicalLocation"]["region"]["endColumn"],
+            11
+        );
+        assert_eq!(
+            first["fixes"][0]["artifactChanges"][0]["replacements"][0]["insertedContent"]["text"],
+            "== History =="
+        );
+        let second = &run["results"][1];
+        assert_eq!(second["ruleIndex"], 1);
+        assert!(second["locations"][0]["physicalLocation"]["region"].is_null());
+    }
+
+    #[test]
+    fn renders_checkstyle_grouped_by_file() {
+        let xml = render_checkstyle(&sample_findings());
+        assert!(xml.contains("<file name=\"wiki_content/Main/Alpha.wiki\">"));
+        assert!(xml.contains(
+            "<error line=\"4\" column=\"1\" severity=\"error\" message=\"Markdown heading &lt;##&gt;\" source=\"structure.markdown_heading\"/>"
+        ));
+        assert!(xml.contains("line=\"1\" column=\"1\" severity=\"warning\""));
+        assert!(xml.trim_end().ends_with("</checkstyle>"));
+    }
+}
@@ -0,0 +1,146 @@
+use super::model::DirectedGraph;
+
+const PAGERANK_DAMPING: f64 = 0.85;
+const MAX_ITERATIONS: usize = 100;
+const CONVERGENCE_EPSILON: f64 = 1e-10;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CentralityScores {
+    /// Indexed by `NodeId`; sums to 1 across the graph.
+    pub pagerank: Vec<f64>,
+    /// HITS hub scores, L2-normalized.
+    pub hub: Vec<f64>,
+    /// HITS authority scores, L2-normalized.
+    pub authority: Vec<f64>,
+}
+
+pub fn compute_centrality(graph: &DirectedGraph) -> CentralityScores {
+    let (hub, authority) = compute_hits(graph);
+    CentralityScores {
+        pagerank: compute_pagerank(graph),
+        hub,
+        authority,
+    }
+}
+
+/// Power-iteration PageRank. Dangling nodes spread their rank uniformly.
+pub fn compute_pagerank(graph: &DirectedGraph) -> Vec<f64> {
+    let node_count = graph.node_count();
+    if node_count == 0 {
+        return Vec::new();
+    }
+    let uniform = 1.0 / node_count as f64;
+    let mut ranks = vec![uniform; node_count];
+
+    for _ in 0..MAX_ITERATIONS {
+        let dangling_mass = graph
+            .adjacency
+            .iter()
+            .zip(&ranks)
+            .filter(|(targets, _)| targets.is_empty())
+            .map(|(_, rank)| rank)
+            .sum::<f64>();
+        let base = (1.0 - PAGERANK_DAMPING) * uniform + PAGERANK_DAMPING * dangling_mass * uniform;
+        let mut next = vec![base; node_count];
+        for (source, targets) in graph.adjacency.iter().enumerate() {
+            if targets.is_empty() {
+                continue;
+            }
+            let share = PAGERANK_DAMPING * ranks[source] / targets.len() as f64;
+            for target in targets {
+                next[target.0 as usize] += share;
+            }
+        }
+
+        let delta = next
+            .iter()
+            .zip(&ranks)
+            .map(|(left, right)| (left - right).abs())
+            .sum::<f64>();
+        ranks = next;
+        if delta < CONVERGENCE_EPSILON {
+            break;
+        }
+    }
+    ranks
+}
+
+/// Kleinberg HITS: returns `(hub, authority)` scores. Nodes without edges score 0.
+pub fn compute_hits(graph: &DirectedGraph) -> (Vec<f64>, Vec<f64>) {
+    let node_count = graph.node_count();
+    let mut hub = vec![1.0; node_count];
+    let mut authority = vec![1.0; node_count];
+    if graph.edges.is_empty() {
+        return (vec![0.0; node_count], vec![0.0; node_count]);
+    }
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut next_authority = vec![0.0; node_count];
+        for (source, targets) in graph.adjacency.iter().enumerate() {
+            for target in targets {
+                next_authority[target.0 as usize] += hub[source];
+            }
+        }
+        normalize_l2(&mut next_authority);
+
+        let mut next_hub = vec![0.0; node_count];
+        for (source, targets) in graph.adjacency.iter().enumerate() {
+            next_hub[source] = targets
+                .iter()
+                .map(|target| next_authority[target.0 as usize])
+                .sum();
+        }
+        normalize_l2(&mut next_hub);
+
+        let delta = next_hub
+            .iter()
+            .zip(&hub)
+            .chain(next_authority.iter().zip(&authority))
+            .map(|(left, right)| (left - right).abs())
+            .sum::<f64>();
+        hub = next_hub;
+        authority = next_authority;
+        if delta < CONVERGENCE_EPSILON {
+            break;
+        }
+    }
+    (hub, authority)
+}
+
+fn normalize_l2(values: &mut [f64]) {
+    let norm = values.iter().map(|value| value * value).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        for value in values.iter_mut() {
+            *value /= norm;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{EdgeKind, GraphKind};
+
+    #[test]
+    fn pagerank_and_hits_favor_linked_pages() {
+        let mut graph = DirectedGraph::new(GraphKind::ArticleLinksFiltered);
+        let hub = graph.add_node("Hub", "Main");
+        let target = graph.add_node("Target", "Main");
+        let other = graph.add_node("Other", "Main");
+        let isolated = graph.add_node("Isolated", "Main");
+        graph.add_edge(hub, target, EdgeKind::Link);
+        graph.add_edge(hub, other, EdgeKind::Link);
+        graph.add_edge(other, target, EdgeKind::Link);
+
+        let scores = compute_centrality(&graph);
+        let total = scores.pagerank.iter().sum::<f64>();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(scores.pagerank[target.0 as usize] > scores.pagerank[other.0 as usize]);
+        assert!(scores.pagerank[other.0 as usize] > scores.pagerank[isolated.0 as usize]);
+
+        assert!(scores.hub[hub.0 as usize] > scores.hub[other.0 as usize]);
+        assert_eq!(scores.hub[target.0 as usize], 0.0);
+        assert!(scores.authority[target.0 as usize] > scores.authority[other.0 as usize]);
+        assert_eq!(scores.authority[isolated.0 as usize], 0.0);
+    }
+}
@@ -0,0 +1,174 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use super::model::DirectedGraph;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphExportFormat {
+    Dot,
+    GraphMl,
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct GraphExportNode {
+    pub id: u32,
+    pub title: String,
+    pub namespace: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct GraphExportEdge {
+    pub source: u32,
+    pub target: u32,
+    pub kind: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct GraphExportDocument {
+    pub kind: String,
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub nodes: Vec<GraphExportNode>,
+    pub edges: Vec<GraphExportEdge>,
+}
+
+impl GraphExportDocument {
+    pub fn from_graph(graph: &DirectedGraph) -> Self {
+        Self {
+            kind: graph.kind.as_str().to_string(),
+            node_count: graph.node_count(),
+            edge_count: graph.edge_count(),
+            nodes: graph
+                .nodes
+                .iter()
+                .map(|node| GraphExportNode {
+                    id: node.id.0,
+                    title: node.title.clone(),
+                    namespace: node.namespace.clone(),
+                })
+                .collect(),
+            edges: graph
+                .edges
+                .iter()
+                .map(|edge| GraphExportEdge {
+                    source: edge.from.0,
+                    target: edge.to.0,
+                    kind: edge.kind.as_str().to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn render_graph(graph: &DirectedGraph, format: GraphExportFormat) -> Result<String> {
+    match format {
+        GraphExportFormat::Dot => Ok(render_dot(graph)),
+        GraphExportFormat::GraphMl => Ok(render_graphml(graph)),
+        GraphExportFormat::Json => {
+            serde_json::to_string_pretty(&GraphExportDocument::from_graph(graph))
+                .context("failed to serialize graph export")
+        }
+    }
+}
+
+pub fn render_dot(graph: &DirectedGraph) -> String {
+    let mut out = format!("digraph \"wikitool_{}\" {{\n", graph.kind.as_str());
+    for node in &graph.nodes {
+        out.push_str(&format!(
+            "  n{} [label=\"{}\", namespace=\"{}\"];\n",
+            node.id.0,
+            escape_dot(&node.title),
+            escape_dot(&node.namespace)
+        ));
+    }
+    for edge in &graph.edges {
+        out.push_str(&format!(
+            "  n{} -> n{} [kind=\"{}\"];\n",
+            edge.from.0,
+            edge.to.0,
+            edge.kind.as_str()
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+pub fn render_graphml(graph: &DirectedGraph) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n\
+         \x20 <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n\
+         \x20 <key id=\"namespace\" for=\"node\" attr.name=\"namespace\" attr.type=\"string\"/>\n\
+         \x20 <key id=\"kind\" for=\"edge\" attr.name=\"kind\" attr.type=\"string\"/>\n",
+    );
+    out.push_str(&format!(
+        "  <graph id=\"{}\" edgedefault=\"directed\">\n",
+        graph.kind.as_str()
+    ));
+    for node in &graph.nodes {
+        out.push_str(&format!(
+            "    <node id=\"n{}\"><data key=\"label\">{}</data><data key=\"namespace\">{}</data></node>\n",
+            node.id.0,
+            escape_xml(&node.title),
+            escape_xml(&node.namespace)
+        ));
+    }
+    for (index, edge) in graph.edges.iter().enumerate() {
+        out.push_str(&format!(
+            "    <edge id=\"e{index}\" source=\"n{}\" target=\"n{}\"><data key=\"kind\">{}</data></edge>\n",
+            edge.from.0,
+            edge.to.0,
+            edge.kind.as_str()
+        ));
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{EdgeKind, GraphKind};
+
+    fn sample_graph() -> DirectedGraph {
+        let mut graph = DirectedGraph::new(GraphKind::ArticleLinksFiltered);
+        let alpha = graph.add_node("Alpha \"A\"", "Main");
+        let beta = graph.add_node("Beta & <B>", "Main");
+        graph.add_edge(alpha, beta, EdgeKind::Link);
+        graph
+    }
+
+    #[test]
+    fn renders_escaped_dot_and_graphml() {
+        let graph = sample_graph();
+
+        let dot = render_graph(&graph, GraphExportFormat::Dot).expect("dot");
+        assert!(dot.starts_with("digraph \"wikitool_links\" {"));
+        assert!(dot.contains("n0 [label=\"Alpha \\\"A\\\"\", namespace=\"Main\"];"));
+        assert!(dot.contains("n0 -> n1 [kind=\"link\"];"));
+
+        let graphml = render_graph(&graph, GraphExportFormat::GraphMl).expect("graphml");
+        assert!(graphml.contains("<data key=\"label\">Beta &amp; &lt;B&gt;</data>"));
+        assert!(graphml.contains("<edge id=\"e0\" source=\"n0\" target=\"n1\">"));
+        assert!(graphml.trim_end().ends_with("</graphml>"));
+
+        let json = render_graph(&graph, GraphExportFormat::Json).expect("json");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("parse json");
+        assert_eq!(value["edge_count"], 1);
+        assert_eq!(value["edges"][0]["kind"], "link");
+    }
+}
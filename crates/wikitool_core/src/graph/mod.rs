@@ -1,13 +1,19 @@
 pub mod build;
+pub mod centrality;
 pub mod condensation;
+pub mod export;
 pub mod filters;
 pub mod model;
 pub mod scc;
+pub mod subgraph;
 
 pub use build::build_graph;
+pub use centrality::{CentralityScores, compute_centrality, compute_hits, compute_pagerank};
 pub use condensation::{CondensationNode, build_condensation};
+pub use export::{GraphExportDocument, GraphExportFormat, render_graph};
 pub use model::{
     DirectedGraph, EdgeKind, GraphEdge, GraphFilter, GraphKind, GraphNode, NodeId, SccComponent,
     SccIndex,
 };
 pub use scc::compute_scc;
+pub use subgraph::{connected_node_ids, ego_node_ids, extract_ego_graph, induced_subgraph};
@@ -11,6 +11,17 @@ pub enum GraphKind {
     Categories,
 }
 
+impl GraphKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Redirects => "redirects",
+            Self::Transclusion => "transclusion",
+            Self::ArticleLinksFiltered => "links",
+            Self::Categories => "categories",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum EdgeKind {
     Redirect,
@@ -20,6 +31,18 @@ pub enum EdgeKind {
     ModuleInvocation,
 }
 
+impl EdgeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Redirect => "redirect",
+            Self::Link => "link",
+            Self::CategoryMembership => "category_membership",
+            Self::TemplateTransclusion => "template_transclusion",
+            Self::ModuleInvocation => "module_invocation",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GraphNode {
     pub id: NodeId,
@@ -78,6 +101,17 @@ impl DirectedGraph {
     pub fn node_count(&self) -> usize {
         self.nodes.len()
     }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn node_by_title(&self, title: &str) -> Option<NodeId> {
+        self.nodes
+            .iter()
+            .find(|node| node.title == title)
+            .map(|node| node.id)
+    }
 }
 
 #[derive(Debug, Clone)]
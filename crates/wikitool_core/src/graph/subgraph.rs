@@ -0,0 +1,89 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use super::model::{DirectedGraph, NodeId};
+
+/// Copy the nodes in `keep` and the edges between them into a new graph,
+/// preserving the original node order.
+pub fn induced_subgraph(graph: &DirectedGraph, keep: &BTreeSet<NodeId>) -> DirectedGraph {
+    let mut subgraph = DirectedGraph::new(graph.kind);
+    let mut remapped = BTreeMap::<NodeId, NodeId>::new();
+    for node in &graph.nodes {
+        if keep.contains(&node.id) {
+            let id = subgraph.add_node(node.title.clone(), node.namespace.clone());
+            remapped.insert(node.id, id);
+        }
+    }
+    for edge in &graph.edges {
+        if let (Some(&from), Some(&to)) = (remapped.get(&edge.from), remapped.get(&edge.to)) {
+            subgraph.add_edge(from, to, edge.kind);
+        }
+    }
+    subgraph
+}
+
+/// Nodes within `depth` hops of `center`, following edges in either direction.
+pub fn ego_node_ids(graph: &DirectedGraph, center: NodeId, depth: usize) -> BTreeSet<NodeId> {
+    let mut neighbors = vec![Vec::<NodeId>::new(); graph.node_count()];
+    for edge in &graph.edges {
+        neighbors[edge.from.0 as usize].push(edge.to);
+        neighbors[edge.to.0 as usize].push(edge.from);
+    }
+
+    let mut seen = BTreeSet::from([center]);
+    let mut queue = VecDeque::from([(center, 0usize)]);
+    while let Some((current, distance)) = queue.pop_front() {
+        if distance >= depth {
+            continue;
+        }
+        for &next in &neighbors[current.0 as usize] {
+            if seen.insert(next) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    seen
+}
+
+pub fn extract_ego_graph(graph: &DirectedGraph, center: NodeId, depth: usize) -> DirectedGraph {
+    induced_subgraph(graph, &ego_node_ids(graph, center, depth))
+}
+
+/// Nodes that participate in at least one edge.
+pub fn connected_node_ids(graph: &DirectedGraph) -> BTreeSet<NodeId> {
+    graph
+        .edges
+        .iter()
+        .flat_map(|edge| [edge.from, edge.to])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::EdgeKind;
+
+    #[test]
+    fn ego_graph_follows_edges_in_both_directions_up_to_depth() {
+        let mut graph = DirectedGraph::new(crate::graph::GraphKind::ArticleLinksFiltered);
+        let a = graph.add_node("A", "Main");
+        let b = graph.add_node("B", "Main");
+        let c = graph.add_node("C", "Main");
+        let d = graph.add_node("D", "Main");
+        graph.add_edge(a, b, EdgeKind::Link);
+        graph.add_edge(c, b, EdgeKind::Link);
+        graph.add_edge(c, d, EdgeKind::Link);
+
+        let ego = extract_ego_graph(&graph, b, 1);
+        let titles = ego
+            .nodes
+            .iter()
+            .map(|node| node.title.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(titles, vec!["A", "B", "C"]);
+        assert_eq!(ego.edge_count(), 2);
+
+        let wider = extract_ego_graph(&graph, b, 2);
+        assert_eq!(wider.node_count(), 4);
+        assert_eq!(wider.edge_count(), 3);
+    }
+}
@@ -0,0 +1,433 @@
+use std::collections::VecDeque;
+
+use super::prelude::*;
+use crate::graph::{DirectedGraph, EdgeKind, GraphFilter, GraphKind, NodeId, build_graph};
+
+pub use super::model::{
+    CategorySizeIssue, CategoryTreeNode, CategoryTreeOptions, CategoryTreeReport,
+    PageOutsideCategoryTree, RedundantCategoryIssue,
+};
+
+pub fn inspect_category_tree(
+    paths: &ResolvedPaths,
+    options: &CategoryTreeOptions,
+) -> Result<Option<CategoryTreeReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    build_category_tree_report(&connection, options).map(Some)
+}
+
+pub(crate) fn build_category_tree_report(
+    connection: &Connection,
+    options: &CategoryTreeOptions,
+) -> Result<CategoryTreeReport> {
+    let mut graph = build_graph(connection, GraphKind::Categories, &GraphFilter::default())?;
+    let missing_categories = add_missing_category_nodes(connection, &mut graph)?;
+    let tree = CategoryTree::from_graph(&graph, &missing_categories);
+
+    let mut missing_root_categories = Vec::new();
+    let mut roots = Vec::new();
+    for root in &options.root_categories {
+        let title = normalize_category_title(root);
+        if title.is_empty() {
+            continue;
+        }
+        match tree.category_by_title.get(&title) {
+            Some(&id) => {
+                if !roots.contains(&id) {
+                    roots.push(id);
+                }
+            }
+            None => {
+                if !missing_root_categories.contains(&title) {
+                    missing_root_categories.push(title);
+                }
+            }
+        }
+    }
+    let inferred_roots = options.root_categories.is_empty();
+    if inferred_roots {
+        roots = tree
+            .categories
+            .iter()
+            .copied()
+            .filter(|id| {
+                tree.parents[id.0 as usize]
+                    .iter()
+                    .all(|parent| missing_categories.contains(parent))
+            })
+            .collect();
+    }
+
+    let (depths, predecessors) = tree.walk_from_roots(&roots);
+    let reachable = depths.keys().copied().collect::<BTreeSet<_>>();
+
+    let mut categories = tree
+        .categories
+        .iter()
+        .map(|&id| CategoryTreeNode {
+            title: graph.nodes[id.0 as usize].title.clone(),
+            depth: depths.get(&id).copied(),
+            parent_chain: parent_chain(&graph, &predecessors, id, depths.contains_key(&id)),
+            parent_categories: tree.parents[id.0 as usize]
+                .iter()
+                .map(|parent| graph.nodes[parent.0 as usize].title.clone())
+                .collect(),
+            page_member_count: tree.page_members[id.0 as usize].len(),
+            subcategory_count: tree.subcategories[id.0 as usize].len(),
+        })
+        .collect::<Vec<_>>();
+    let category_count = categories.len();
+    let max_depth = depths.values().copied().max().unwrap_or(0);
+    let unreachable_category_count = category_count - reachable.len();
+
+    let mut oversized = Vec::new();
+    let mut undersized = Vec::new();
+    for node in &categories {
+        let issue = CategorySizeIssue {
+            title: node.title.clone(),
+            page_member_count: node.page_member_count,
+            subcategory_count: node.subcategory_count,
+        };
+        if options.oversized_threshold > 0 && node.page_member_count > options.oversized_threshold {
+            oversized.push(issue);
+        } else if options.undersized_threshold > 0
+            && node.page_member_count + node.subcategory_count < options.undersized_threshold
+        {
+            undersized.push(issue);
+        }
+    }
+    oversized.sort_by(|left, right| {
+        right
+            .page_member_count
+            .cmp(&left.page_member_count)
+            .then_with(|| left.title.cmp(&right.title))
+    });
+    undersized.sort_by(|left, right| {
+        (left.page_member_count + left.subcategory_count)
+            .cmp(&(right.page_member_count + right.subcategory_count))
+            .then_with(|| left.title.cmp(&right.title))
+    });
+
+    let mut redundant = Vec::new();
+    for &id in &tree.categories {
+        if let Some(issue) = tree.redundant_categorization(&graph, id) {
+            redundant.push(issue);
+        }
+    }
+
+    let categorized_reach = tree.descendants_of(&roots);
+    let mut outside = Vec::new();
+    let mut under_missing = Vec::new();
+    for title in load_content_page_titles(connection)? {
+        let Some(&id) = tree.node_by_title.get(&title) else {
+            continue;
+        };
+        let page_categories = &tree.parents[id.0 as usize];
+        if page_categories
+            .iter()
+            .any(|category| categorized_reach.contains(category))
+        {
+            continue;
+        }
+        let page = PageOutsideCategoryTree {
+            title,
+            categories: page_categories
+                .iter()
+                .map(|category| graph.nodes[category.0 as usize].title.clone())
+                .collect(),
+        };
+        // Without a local page the category's own parents are unknown, so
+        // the page cannot be placed inside or outside the root tree.
+        if page_categories
+            .iter()
+            .any(|category| missing_categories.contains(category))
+        {
+            under_missing.push(page);
+        } else {
+            outside.push(page);
+        }
+    }
+
+    categories.sort_by(|left, right| {
+        left.depth
+            .unwrap_or(usize::MAX)
+            .cmp(&right.depth.unwrap_or(usize::MAX))
+            .then_with(|| left.title.cmp(&right.title))
+    });
+
+    let oversized_count = oversized.len();
+    let undersized_count = undersized.len();
+    let redundant_count = redundant.len();
+    let pages_outside_root_tree_count = outside.len();
+    let pages_under_missing_categories_count = under_missing.len();
+    categories.truncate(options.limit);
+    oversized.truncate(options.limit);
+    undersized.truncate(options.limit);
+    redundant.truncate(options.limit);
+    outside.truncate(options.limit);
+    under_missing.truncate(options.limit);
+
+    Ok(CategoryTreeReport {
+        root_categories: roots
+            .iter()
+            .map(|id| graph.nodes[id.0 as usize].title.clone())
+            .collect(),
+        inferred_roots,
+        missing_root_categories,
+        category_count,
+        max_depth,
+        unreachable_category_count,
+        categories,
+        oversized_threshold: options.oversized_threshold,
+        oversized_count,
+        oversized_categories: oversized,
+        undersized_threshold: options.undersized_threshold,
+        undersized_count,
+        undersized_categories: undersized,
+        redundant_count,
+        redundant_categories: redundant,
+        pages_outside_root_tree_count,
+        pages_outside_root_tree: outside,
+        pages_under_missing_categories_count,
+        pages_under_missing_categories: under_missing,
+    })
+}
+
+struct CategoryTree {
+    categories: Vec<NodeId>,
+    category_by_title: BTreeMap<String, NodeId>,
+    node_by_title: BTreeMap<String, NodeId>,
+    /// Category nodes each node is a member of.
+    parents: Vec<Vec<NodeId>>,
+    subcategories: Vec<Vec<NodeId>>,
+    page_members: Vec<Vec<NodeId>>,
+}
+
+impl CategoryTree {
+    fn from_graph(graph: &DirectedGraph, missing_categories: &BTreeSet<NodeId>) -> Self {
+        let node_count = graph.node_count();
+        let mut tree = Self {
+            categories: Vec::new(),
+            category_by_title: BTreeMap::new(),
+            node_by_title: BTreeMap::new(),
+            parents: vec![Vec::new(); node_count],
+            subcategories: vec![Vec::new(); node_count],
+            page_members: vec![Vec::new(); node_count],
+        };
+        for node in &graph.nodes {
+            tree.node_by_title.insert(node.title.clone(), node.id);
+            if node.namespace == Namespace::Category.as_str()
+                && !missing_categories.contains(&node.id)
+            {
+                tree.categories.push(node.id);
+                tree.category_by_title.insert(node.title.clone(), node.id);
+            }
+        }
+        for edge in &graph.edges {
+            if edge.from == edge.to
+                || graph.nodes[edge.to.0 as usize].namespace != Namespace::Category.as_str()
+            {
+                continue;
+            }
+            let parents = &mut tree.parents[edge.from.0 as usize];
+            if parents.contains(&edge.to) {
+                continue;
+            }
+            parents.push(edge.to);
+            if graph.nodes[edge.from.0 as usize].namespace == Namespace::Category.as_str() {
+                tree.subcategories[edge.to.0 as usize].push(edge.from);
+            } else {
+                tree.page_members[edge.to.0 as usize].push(edge.from);
+            }
+        }
+        for list in tree
+            .parents
+            .iter_mut()
+            .chain(tree.subcategories.iter_mut())
+            .chain(tree.page_members.iter_mut())
+        {
+            list.sort();
+        }
+        tree
+    }
+
+    fn walk_from_roots(
+        &self,
+        roots: &[NodeId],
+    ) -> (BTreeMap<NodeId, usize>, BTreeMap<NodeId, NodeId>) {
+        let mut depths = BTreeMap::new();
+        let mut predecessors = BTreeMap::new();
+        let mut queue = VecDeque::new();
+        for &root in roots {
+            if depths.insert(root, 0).is_none() {
+                queue.push_back(root);
+            }
+        }
+        while let Some(current) = queue.pop_front() {
+            let depth = depths[&current];
+            for &child in &self.subcategories[current.0 as usize] {
+                if depths.contains_key(&child) {
+                    continue;
+                }
+                depths.insert(child, depth + 1);
+                predecessors.insert(child, current);
+                queue.push_back(child);
+            }
+        }
+        (depths, predecessors)
+    }
+
+    fn descendants_of(&self, starts: &[NodeId]) -> BTreeSet<NodeId> {
+        let mut seen = BTreeSet::new();
+        let mut stack = starts.to_vec();
+        while let Some(current) = stack.pop() {
+            if !seen.insert(current) {
+                continue;
+            }
+            stack.extend(self.subcategories[current.0 as usize].iter().copied());
+        }
+        seen
+    }
+
+    fn redundant_categorization(
+        &self,
+        graph: &DirectedGraph,
+        category: NodeId,
+    ) -> Option<RedundantCategoryIssue> {
+        let members = &self.page_members[category.0 as usize];
+        if members.is_empty() {
+            return None;
+        }
+        let mut descendants = self.descendants_of(&self.subcategories[category.0 as usize]);
+        descendants.remove(&category);
+        if descendants.is_empty() {
+            return None;
+        }
+
+        let mut covering = BTreeSet::new();
+        for member in members {
+            let mut covered = false;
+            for parent in &self.parents[member.0 as usize] {
+                if descendants.contains(parent) {
+                    covering.insert(*parent);
+                    covered = true;
+                }
+            }
+            if !covered {
+                return None;
+            }
+        }
+        Some(RedundantCategoryIssue {
+            title: graph.nodes[category.0 as usize].title.clone(),
+            page_member_count: members.len(),
+            covering_subcategories: covering
+                .into_iter()
+                .map(|id| graph.nodes[id.0 as usize].title.clone())
+                .collect(),
+        })
+    }
+}
+
+/// Add nodes for categories that pages are filed in but that have no local
+/// `Category:` page, with their membership edges. The category graph only
+/// connects indexed pages, which would leave such members parentless.
+fn add_missing_category_nodes(
+    connection: &Connection,
+    graph: &mut DirectedGraph,
+) -> Result<BTreeSet<NodeId>> {
+    let mut node_by_title = graph
+        .nodes
+        .iter()
+        .map(|node| (node.title.clone(), node.id))
+        .collect::<BTreeMap<_, _>>();
+    let mut statement = connection
+        .prepare(
+            "SELECT DISTINCT source_title, target_title
+             FROM indexed_links
+             WHERE is_category_membership = 1
+             ORDER BY target_title ASC, source_title ASC",
+        )
+        .context("failed to prepare category tree membership query")?;
+    let rows = statement
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .context("failed to query category tree memberships")?;
+    let mut missing = BTreeSet::new();
+    for row in rows {
+        let (source_title, target_title) =
+            row.context("failed to decode category tree membership row")?;
+        let Some(&from) = node_by_title.get(&source_title) else {
+            continue;
+        };
+        let to = match node_by_title.get(&target_title) {
+            Some(&id) if !missing.contains(&id) => continue,
+            Some(&id) => id,
+            None => {
+                let id = graph.add_node(target_title.clone(), Namespace::Category.as_str());
+                node_by_title.insert(target_title, id);
+                missing.insert(id);
+                id
+            }
+        };
+        graph.add_edge(from, to, EdgeKind::CategoryMembership);
+    }
+    Ok(missing)
+}
+
+fn parent_chain(
+    graph: &DirectedGraph,
+    predecessors: &BTreeMap<NodeId, NodeId>,
+    category: NodeId,
+    reachable: bool,
+) -> Vec<String> {
+    if !reachable {
+        return Vec::new();
+    }
+    let mut chain = vec![graph.nodes[category.0 as usize].title.clone()];
+    let mut current = category;
+    while let Some(&parent) = predecessors.get(&current) {
+        chain.push(graph.nodes[parent.0 as usize].title.clone());
+        current = parent;
+    }
+    chain.reverse();
+    chain
+}
+
+fn normalize_category_title(value: &str) -> String {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return String::new();
+    }
+    let prefixed = if trimmed
+        .get(..9)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("Category:"))
+    {
+        format!("Category:{}", &trimmed[9..])
+    } else {
+        format!("Category:{trimmed}")
+    };
+    normalize_query_title(&prefixed)
+}
+
+fn load_content_page_titles(connection: &Connection) -> Result<Vec<String>> {
+    let mut statement = connection
+        .prepare(
+            "SELECT title
+             FROM indexed_pages
+             WHERE namespace = ?1 AND is_redirect = 0 AND is_translation_variant = 0
+             ORDER BY title ASC",
+        )
+        .context("failed to prepare category tree content page query")?;
+    let rows = statement
+        .query_map([Namespace::Main.as_str()], |row| row.get::<_, String>(0))
+        .context("failed to query category tree content pages")?;
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row.context("failed to decode category tree content page row")?);
+    }
+    Ok(out)
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use super::prelude::*;
+use crate::graph::{GraphFilter, GraphKind, build_graph, compute_centrality};
+
+pub use super::model::{
+    PageCentralityOptions, PageCentralityReport, PageCentralityScore, PageCentralitySort,
+};
+
+/// Recompute link-graph centrality for every indexed page. Runs inside the
+/// index rebuild transaction after links are inserted.
+pub(crate) fn store_page_centrality(connection: &Connection) -> Result<usize> {
+    let filter = GraphFilter {
+        exclude_self_loops: true,
+        ..GraphFilter::default()
+    };
+    let graph = build_graph(connection, GraphKind::ArticleLinksFiltered, &filter)?;
+    let scores = compute_centrality(&graph);
+    let node_by_title = graph
+        .nodes
+        .iter()
+        .map(|node| (node.title.as_str(), node.id))
+        .collect::<HashMap<_, _>>();
+
+    connection
+        .execute("DELETE FROM indexed_page_centrality", [])
+        .context("failed to clear indexed_page_centrality table")?;
+    let mut page_statement = connection
+        .prepare("SELECT relative_path, title, namespace FROM indexed_pages")
+        .context("failed to prepare centrality page query")?;
+    let rows = page_statement
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })
+        .context("failed to query pages for centrality")?;
+    let mut insert_statement = connection
+        .prepare(
+            "INSERT OR REPLACE INTO indexed_page_centrality (
+                source_relative_path,
+                source_title,
+                source_namespace,
+                pagerank,
+                hub_score,
+                authority_score
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )
+        .context("failed to prepare indexed_page_centrality insert")?;
+
+    let mut inserted = 0usize;
+    for row in rows {
+        let (relative_path, title, namespace) =
+            row.context("failed to decode centrality page row")?;
+        let Some(&node) = node_by_title.get(title.as_str()) else {
+            continue;
+        };
+        let index = node.0 as usize;
+        insert_statement
+            .execute(params![
+                relative_path,
+                title,
+                namespace,
+                scores.pagerank[index],
+                scores.hub[index],
+                scores.authority[index],
+            ])
+            .with_context(|| format!("failed to insert centrality for {relative_path}"))?;
+        inserted += 1;
+    }
+    Ok(inserted)
+}
+
+pub fn query_page_centrality(
+    paths: &ResolvedPaths,
+    options: &PageCentralityOptions,
+) -> Result<Option<PageCentralityReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    query_page_centrality_for_connection(&connection, options).map(Some)
+}
+
+pub(crate) fn query_page_centrality_for_connection(
+    connection: &Connection,
+    options: &PageCentralityOptions,
+) -> Result<PageCentralityReport> {
+    let title = match &options.title {
+        Some(title) => Some(
+            load_page_record(connection, title)?
+                .map(|record| record.title)
+                .with_context(|| format!("page is not indexed: {title}"))?,
+        ),
+        None => None,
+    };
+    let namespace = match &title {
+        Some(_) => None,
+        None => options.namespace.clone(),
+    };
+
+    let mut pages = Vec::new();
+    if table_exists(connection, "indexed_page_centrality")? {
+        let order_column = match options.sort {
+            PageCentralitySort::Pagerank => "pagerank",
+            PageCentralitySort::Hub => "hub_score",
+            PageCentralitySort::Authority => "authority_score",
+        };
+        let mut statement = connection
+            .prepare(&format!(
+                "SELECT source_title, source_namespace, pagerank, hub_score, authority_score
+                 FROM indexed_page_centrality
+                 WHERE ?1 IS NULL OR source_namespace = ?1
+                 ORDER BY {order_column} DESC, source_title ASC"
+            ))
+            .context("failed to prepare page centrality query")?;
+        let rows = statement
+            .query_map([namespace.as_deref()], |row| {
+                Ok(PageCentralityScore {
+                    title: row.get(0)?,
+                    namespace: row.get(1)?,
+                    rank: 0,
+                    pagerank: row.get(2)?,
+                    hub_score: row.get(3)?,
+                    authority_score: row.get(4)?,
+                })
+            })
+            .context("failed to run page centrality query")?;
+        for row in rows {
+            let mut score = row.context("failed to decode page centrality row")?;
+            score.rank = pages.len() + 1;
+            pages.push(score);
+        }
+    }
+
+    let page_count = pages.len();
+    match &title {
+        Some(title) => pages.retain(|score| &score.title == title),
+        None => pages.truncate(options.limit),
+    }
+    Ok(PageCentralityReport {
+        sort: options.sort,
+        namespace,
+        page_count,
+        pages,
+    })
+}
+
+/// PageRank keyed by lowercased title, for tie-breaking ranked page lists.
+pub(crate) fn load_pagerank_scores<'a>(
+    connection: &Connection,
+    titles: impl IntoIterator<Item = &'a str>,
+) -> Result<BTreeMap<String, f64>> {
+    let mut out = BTreeMap::new();
+    if !table_exists(connection, "indexed_page_centrality")? {
+        return Ok(out);
+    }
+    let mut statement = connection
+        .prepare(
+            "SELECT pagerank
+             FROM indexed_page_centrality
+             WHERE source_title = ?1
+             LIMIT 1",
+        )
+        .context("failed to prepare pagerank lookup")?;
+    for title in titles {
+        let key = title.to_ascii_lowercase();
+        if out.contains_key(&key) {
+            continue;
+        }
+        if let Some(pagerank) = statement
+            .query_row([title], |row| row.get::<_, f64>(0))
+            .optional()
+            .context("failed to load pagerank")?
+        {
+            out.insert(key, pagerank);
+        }
+    }
+    Ok(out)
+}
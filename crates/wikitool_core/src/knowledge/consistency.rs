@@ -0,0 +1,501 @@
+use super::prelude::*;
+use crate::wikitext::dates::{find_date, parse_year};
+use crate::wikitext::facts::{format_number, leading_number};
+use crate::wikitext::sentences::{ProseSentence, prose_sentences};
+use crate::wikitext::spans::floor_char_boundary;
+
+pub use super::model::{FactConflict, FactConsistencyOptions, FactConsistencyReport, FactLocation};
+
+/// Identity and presentation parameters that never carry comparable facts.
+const SKIPPED_KEYS: &[&str] = &[
+    "name",
+    "title",
+    "image",
+    "image caption",
+    "image size",
+    "caption",
+    "logo",
+    "logo caption",
+    "alt",
+    "signature",
+    "embed",
+    "child",
+];
+const FOUNDING_CUES: &[&str] = &[
+    "founded",
+    "established",
+    "formed",
+    "launched",
+    "created",
+    "started",
+    "inception",
+];
+const CUE_STOPWORDS: &[&str] = &[
+    "date", "of", "the", "number", "total", "num", "count", "year", "size", "as",
+];
+/// Byte window searched around a cue word for the stated value.
+const CUE_WINDOW: usize = 60;
+
+#[derive(Debug, Clone)]
+struct FactRow {
+    relative_path: String,
+    page_title: String,
+    template_title: String,
+    invocation_index: i64,
+    parameter_key: String,
+    value_kind: String,
+    normalized_value: String,
+    raw_value: String,
+}
+
+impl FactRow {
+    fn location(&self, line: Option<usize>) -> FactLocation {
+        FactLocation {
+            page_title: self.page_title.clone(),
+            source: "infobox".to_string(),
+            template_title: Some(self.template_title.clone()),
+            parameter_key: Some(self.parameter_key.clone()),
+            line,
+            evidence: format!("{} = {}", self.parameter_key, self.raw_value),
+        }
+    }
+}
+
+pub fn check_fact_consistency(
+    paths: &ResolvedPaths,
+    options: &FactConsistencyOptions,
+) -> Result<Option<FactConsistencyReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    check_fact_consistency_for_connection(paths, &connection, options).map(Some)
+}
+
+/// Compare each page's own infobox with infoboxes about the same subject on
+/// other pages and with values stated in lead sentences that mention it.
+pub(crate) fn check_fact_consistency_for_connection(
+    paths: &ResolvedPaths,
+    connection: &Connection,
+    options: &FactConsistencyOptions,
+) -> Result<FactConsistencyReport> {
+    let rows = load_fact_rows(connection)?;
+    let mut by_page = BTreeMap::<String, Vec<FactRow>>::new();
+    for row in rows {
+        by_page.entry(row.page_title.clone()).or_default().push(row);
+    }
+
+    // The first infobox on a page describes the page's own subject.
+    let mut subjects = BTreeMap::<String, Vec<FactRow>>::new();
+    for (title, rows) in &by_page {
+        let Some(first) = rows.iter().map(|row| row.invocation_index).min() else {
+            continue;
+        };
+        let facts = rows
+            .iter()
+            .filter(|row| row.invocation_index == first && is_comparable_key(&row.parameter_key))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !facts.is_empty() {
+            subjects.insert(title.clone(), facts);
+        }
+    }
+    if let Some(title) = &options.title {
+        let record = load_page_record(connection, title)?
+            .with_context(|| format!("page is not indexed: {title}"))?;
+        subjects.retain(|subject, _| subject == &record.title);
+    }
+
+    let names = load_subject_names(connection, &subjects)?;
+    let mut contents = ContentCache::new(paths);
+    let mut conflicts = Vec::new();
+    let mut compared_fact_count = 0usize;
+
+    for (page_title, rows) in &by_page {
+        let mut invocations = BTreeMap::<i64, Vec<&FactRow>>::new();
+        for row in rows {
+            invocations
+                .entry(row.invocation_index)
+                .or_default()
+                .push(row);
+        }
+        for facts in invocations.values() {
+            let Some(subject) = facts
+                .iter()
+                .filter(|row| row.parameter_key == "name" || row.parameter_key == "title")
+                .find_map(|row| names.get(&row.normalized_value.to_lowercase()))
+            else {
+                continue;
+            };
+            let Some(canonical) = subjects.get(subject) else {
+                continue;
+            };
+            if subject == page_title
+                && facts.first().map(|row| row.invocation_index)
+                    == canonical.first().map(|row| row.invocation_index)
+            {
+                continue;
+            }
+            for other in facts {
+                let Some(expected) = canonical
+                    .iter()
+                    .find(|row| row.parameter_key == other.parameter_key)
+                else {
+                    continue;
+                };
+                compared_fact_count += 1;
+                if !values_conflict(
+                    &expected.value_kind,
+                    &expected.normalized_value,
+                    &other.normalized_value,
+                ) {
+                    continue;
+                }
+                conflicts.push(FactConflict {
+                    subject_title: subject.clone(),
+                    parameter_key: expected.parameter_key.clone(),
+                    value_kind: expected.value_kind.clone(),
+                    canonical_value: expected.normalized_value.clone(),
+                    canonical_location: expected.location(contents.line_of(expected)?),
+                    other_value: other.normalized_value.clone(),
+                    other_location: other.location(contents.line_of(other)?),
+                });
+            }
+        }
+    }
+
+    if options.include_leads && !subjects.is_empty() {
+        for (relative_path, page_title) in load_content_pages(connection)? {
+            let content = contents.get(&relative_path)?.to_string();
+            for sentence in prose_sentences(&content)
+                .into_iter()
+                .filter(|sentence| sentence.in_lead)
+            {
+                for subject in mentioned_subjects(&sentence, &names, &subjects) {
+                    for expected in &subjects[&subject] {
+                        if expected.value_kind != "date" && expected.value_kind != "number" {
+                            continue;
+                        }
+                        let Some(stated) = stated_value(&sentence.text, expected) else {
+                            continue;
+                        };
+                        compared_fact_count += 1;
+                        if !values_conflict(
+                            &expected.value_kind,
+                            &expected.normalized_value,
+                            &stated,
+                        ) {
+                            continue;
+                        }
+                        conflicts.push(FactConflict {
+                            subject_title: subject.clone(),
+                            parameter_key: expected.parameter_key.clone(),
+                            value_kind: expected.value_kind.clone(),
+                            canonical_value: expected.normalized_value.clone(),
+                            canonical_location: expected.location(contents.line_of(expected)?),
+                            other_value: stated,
+                            other_location: FactLocation {
+                                page_title: page_title.clone(),
+                                source: "lead".to_string(),
+                                template_title: None,
+                                parameter_key: None,
+                                line: Some(sentence.line),
+                                evidence: sentence.text.clone(),
+                            },
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    conflicts.sort_by(|left, right| {
+        left.subject_title
+            .cmp(&right.subject_title)
+            .then(left.parameter_key.cmp(&right.parameter_key))
+            .then(
+                left.other_location
+                    .page_title
+                    .cmp(&right.other_location.page_title),
+            )
+            .then(left.other_location.line.cmp(&right.other_location.line))
+    });
+    let conflict_count = conflicts.len();
+    conflicts.truncate(options.limit);
+    Ok(FactConsistencyReport {
+        subject_count: subjects.len(),
+        compared_fact_count,
+        conflict_count,
+        conflicts,
+    })
+}
+
+fn load_fact_rows(connection: &Connection) -> Result<Vec<FactRow>> {
+    if !table_exists(connection, "indexed_infobox_facts")? {
+        return Ok(Vec::new());
+    }
+    let mut statement = connection
+        .prepare(
+            "SELECT source_relative_path, source_title, template_title, invocation_index,
+                    parameter_key, value_kind, normalized_value, raw_value
+             FROM indexed_infobox_facts
+             WHERE value_kind != 'empty'
+             ORDER BY source_title ASC, invocation_index ASC, parameter_key ASC",
+        )
+        .context("failed to prepare consistency fact query")?;
+    let rows = statement
+        .query_map([], |row| {
+            Ok(FactRow {
+                relative_path: row.get(0)?,
+                page_title: row.get(1)?,
+                template_title: row.get(2)?,
+                invocation_index: row.get(3)?,
+                parameter_key: row.get(4)?,
+                value_kind: row.get(5)?,
+                normalized_value: row.get(6)?,
+                raw_value: row.get(7)?,
+            })
+        })
+        .context("failed to run consistency fact query")?;
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row.context("failed to decode consistency fact row")?);
+    }
+    Ok(out)
+}
+
+/// Lowercased subject titles and their redirect aliases, mapped to the subject.
+fn load_subject_names(
+    connection: &Connection,
+    subjects: &BTreeMap<String, Vec<FactRow>>,
+) -> Result<BTreeMap<String, String>> {
+    let mut names = subjects
+        .keys()
+        .map(|title| (title.to_lowercase(), title.clone()))
+        .collect::<BTreeMap<_, _>>();
+    if !table_exists(connection, "indexed_page_aliases")? {
+        return Ok(names);
+    }
+    let mut statement = connection
+        .prepare("SELECT alias_title, canonical_title FROM indexed_page_aliases")
+        .context("failed to prepare consistency alias query")?;
+    let rows = statement
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .context("failed to run consistency alias query")?;
+    for row in rows {
+        let (alias, canonical) = row.context("failed to decode consistency alias row")?;
+        if subjects.contains_key(&canonical) {
+            names.entry(alias.to_lowercase()).or_insert(canonical);
+        }
+    }
+    Ok(names)
+}
+
+fn load_content_pages(connection: &Connection) -> Result<Vec<(String, String)>> {
+    let mut statement = connection
+        .prepare(
+            "SELECT relative_path, title
+             FROM indexed_pages
+             WHERE namespace = ?1 AND is_redirect = 0
+             ORDER BY title ASC",
+        )
+        .context("failed to prepare consistency page query")?;
+    let rows = statement
+        .query_map([Namespace::Main.as_str()], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .context("failed to run consistency page query")?;
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row.context("failed to decode consistency page row")?);
+    }
+    Ok(out)
+}
+
+struct ContentCache<'a> {
+    paths: &'a ResolvedPaths,
+    contents: BTreeMap<String, String>,
+}
+
+impl<'a> ContentCache<'a> {
+    fn new(paths: &'a ResolvedPaths) -> Self {
+        Self {
+            paths,
+            contents: BTreeMap::new(),
+        }
+    }
+
+    fn get(&mut self, relative_path: &str) -> Result<&str> {
+        if !self.contents.contains_key(relative_path) {
+            let absolute = absolute_path_from_relative(self.paths, relative_path);
+            let content = fs::read_to_string(&absolute).with_context(|| {
+                format!("failed to read indexed source file {}", absolute.display())
+            })?;
+            self.contents.insert(relative_path.to_string(), content);
+        }
+        Ok(self.contents[relative_path].as_str())
+    }
+
+    fn line_of(&mut self, row: &FactRow) -> Result<Option<usize>> {
+        let content = self.get(&row.relative_path)?;
+        Ok(content
+            .find(&row.raw_value)
+            .map(|offset| content[..offset].matches('\n').count() + 1))
+    }
+}
+
+fn is_comparable_key(key: &str) -> bool {
+    !key.starts_with('$') && !SKIPPED_KEYS.contains(&key)
+}
+
+fn mentioned_subjects(
+    sentence: &ProseSentence,
+    names: &BTreeMap<String, String>,
+    subjects: &BTreeMap<String, Vec<FactRow>>,
+) -> BTreeSet<String> {
+    let mut out = sentence
+        .link_targets
+        .iter()
+        .filter_map(|target| names.get(&target.to_lowercase()).cloned())
+        .collect::<BTreeSet<_>>();
+    let lowered = sentence.text.to_lowercase();
+    for (name, subject) in names {
+        if name.len() >= 4 && contains_word(&lowered, name) && subjects.contains_key(subject) {
+            out.insert(subject.clone());
+        }
+    }
+    out
+}
+
+fn contains_word(haystack: &str, needle: &str) -> bool {
+    haystack.match_indices(needle).any(|(start, _)| {
+        let before = haystack[..start].chars().next_back();
+        let after = haystack[start + needle.len()..].chars().next();
+        before.is_none_or(|ch| !ch.is_alphanumeric())
+            && after.is_none_or(|ch| !ch.is_alphanumeric())
+    })
+}
+
+/// Words that signal a sentence states the fact behind `key`.
+fn fact_cues(key: &str) -> Vec<String> {
+    if [
+        "found",
+        "establish",
+        "formed",
+        "launch",
+        "inception",
+        "created",
+    ]
+    .iter()
+    .any(|stem| key.contains(stem))
+    {
+        return FOUNDING_CUES.iter().map(|cue| cue.to_string()).collect();
+    }
+    key.split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| word.len() >= 4 && !CUE_STOPWORDS.contains(word))
+        .map(|word| word.trim_end_matches('s').to_string())
+        .collect()
+}
+
+/// The value a sentence states for `expected`, found next to a cue word.
+fn stated_value(text: &str, expected: &FactRow) -> Option<String> {
+    let lowered = text.to_lowercase();
+    let cues = fact_cues(&expected.parameter_key);
+    let (cue_start, cue_end) = word_spans(&lowered).into_iter().find(|&(start, end)| {
+        cues.iter()
+            .any(|cue| lowered[start..end].starts_with(cue.as_str()))
+    })?;
+    let after_end = floor_char_boundary(&lowered, (cue_end + CUE_WINDOW).min(lowered.len()));
+    let after = &lowered[cue_end..after_end];
+    let before_start = floor_char_boundary(&lowered, cue_start.saturating_sub(CUE_WINDOW / 2));
+    let before = &lowered[before_start..cue_start];
+
+    match expected.value_kind.as_str() {
+        "date" => find_date(after)
+            .or_else(|| find_date(before))
+            .map(|date| date.iso()),
+        "number" => {
+            let canonical_is_year = parse_year(&expected.normalized_value).is_some();
+            let accept =
+                |value: &(f64, String)| canonical_is_year || parse_year(&value.1).is_none();
+            last_number(before)
+                .filter(|(_, end)| before[*end..].trim().is_empty())
+                .map(|(number, _)| (number, format_number(number)))
+                .filter(accept)
+                .or_else(|| {
+                    first_number(after)
+                        .map(|number| (number, format_number(number)))
+                        .filter(accept)
+                })
+                .map(|(_, formatted)| formatted)
+        }
+        _ => None,
+    }
+}
+
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (offset, ch) in text.char_indices() {
+        match (ch.is_alphabetic(), start) {
+            (true, None) => start = Some(offset),
+            (false, Some(begin)) => {
+                spans.push((begin, offset));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        spans.push((begin, text.len()));
+    }
+    spans
+}
+
+fn number_starts(text: &str) -> impl Iterator<Item = usize> + '_ {
+    text.char_indices().filter_map(|(offset, ch)| {
+        let previous = text[..offset].chars().next_back();
+        (ch.is_ascii_digit()
+            && previous.is_none_or(|prev| !(prev.is_alphanumeric() || prev == ',' || prev == '.')))
+        .then_some(offset)
+    })
+}
+
+fn first_number(text: &str) -> Option<f64> {
+    number_starts(text)
+        .next()
+        .and_then(|start| leading_number(&text[start..]).map(|(number, _)| number))
+}
+
+/// Last number in `text` and the byte offset just past it.
+fn last_number(text: &str) -> Option<(f64, usize)> {
+    let start = number_starts(text).last()?;
+    leading_number(&text[start..]).map(|(number, end)| (number, start + end))
+}
+
+fn values_conflict(kind: &str, canonical: &str, other: &str) -> bool {
+    match kind {
+        "date" => match (date_parts(canonical), date_parts(other)) {
+            (Some(left), Some(right)) => {
+                left[0] != right[0]
+                    || left[1].zip(right[1]).is_some_and(|(l, r)| l != r)
+                    || left[2].zip(right[2]).is_some_and(|(l, r)| l != r)
+            }
+            _ => false,
+        },
+        "number" => match (canonical.parse::<f64>(), other.parse::<f64>()) {
+            (Ok(left), Ok(right)) => (left - right).abs() > 0.01 * left.abs().max(right.abs()),
+            _ => false,
+        },
+        _ => !canonical.eq_ignore_ascii_case(other.trim()),
+    }
+}
+
+/// `YYYY[-MM[-DD]]` into `[year, month, day]`, missing parts as `None`.
+fn date_parts(value: &str) -> Option<[Option<u32>; 3]> {
+    let mut parts = value.split('-').map(|part| part.parse::<u32>().ok());
+    let year = parts.next().flatten()?;
+    Some([Some(year), parts.next().flatten(), parts.next().flatten()])
+}
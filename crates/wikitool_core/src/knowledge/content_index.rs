@@ -1,5 +1,9 @@
 use super::prelude::*;
 use crate::filesystem::{ScanStats, ScannedFile};
+use crate::knowledge::centrality::store_page_centrality;
+use crate::knowledge::facts::{load_infobox_template_prefixes, store_infobox_facts};
+use crate::knowledge::quality::{page_quality_metrics, store_page_quality};
+use crate::knowledge::snapshots::{latest_index_build_id, record_index_snapshot};
 use crate::knowledge::status::{KNOWLEDGE_GENERATION, load_content_index_artifact};
 use crate::title_variants::translation_variant_info;
 
@@ -76,6 +80,23 @@ pub fn rebuild_index(paths: &ResolvedPaths, options: &ScanOptions) -> Result<Reb
         )
         .context("failed to prepare indexed_template_invocations insert")?;
 
+    let infobox_prefixes = load_infobox_template_prefixes(&transaction)?;
+    let mut infobox_fact_statement = transaction
+        .prepare(
+            "INSERT OR REPLACE INTO indexed_infobox_facts (
+                source_relative_path,
+                invocation_index,
+                parameter_key,
+                source_title,
+                source_namespace,
+                template_title,
+                raw_value,
+                value_kind,
+                normalized_value
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        )
+        .context("failed to prepare indexed_infobox_facts insert")?;
+
     let mut alias_statement = transaction
         .prepare(
             "INSERT OR REPLACE INTO indexed_page_aliases (
@@ -265,6 +286,7 @@ pub fn rebuild_index(paths: &ResolvedPaths, options: &ScanOptions) -> Result<Reb
     let mut inserted_rows = 0usize;
     let mut inserted_links = 0usize;
     let mut template_implementation_seeds = BTreeMap::<String, TemplateImplementationSeed>::new();
+    let mut quality_rows = Vec::new();
     for file in &files {
         let translation_variant = translation_variant_info(&file.title);
         page_statement
@@ -330,6 +352,19 @@ pub fn rebuild_index(paths: &ResolvedPaths, options: &ScanOptions) -> Result<Reb
                 .with_context(|| format!("failed to insert alias for {}", file.relative_path))?;
         }
 
+        store_infobox_facts(
+            &mut infobox_fact_statement,
+            file,
+            &content,
+            &infobox_prefixes,
+        )?;
+        if file.namespace == Namespace::Main.as_str() && !file.is_redirect {
+            quality_rows.push((
+                file.relative_path.clone(),
+                page_quality_metrics(&file.title, &file.namespace, &content, None),
+            ));
+        }
+
         let artifacts = extract_page_artifacts(&content);
         maybe_record_template_implementation_seed(
             &mut template_implementation_seeds,
@@ -613,10 +648,13 @@ pub fn rebuild_index(paths: &ResolvedPaths, options: &ScanOptions) -> Result<Reb
     drop(template_example_statement);
     drop(section_statement);
     drop(alias_statement);
+    drop(infobox_fact_statement);
     drop(template_invocation_statement);
     drop(chunk_statement);
     drop(link_statement);
     drop(page_statement);
+    store_page_centrality(&transaction)?;
+    store_page_quality(&transaction, &quality_rows)?;
 
     transaction
         .commit()
@@ -638,6 +676,7 @@ pub fn rebuild_index(paths: &ResolvedPaths, options: &ScanOptions) -> Result<Reb
         })
         .to_string(),
     )?;
+    let build_id = record_index_snapshot(&connection)?;
 
     Ok(RebuildReport {
         db_path: normalize_path(&paths.db_path),
@@ -645,6 +684,7 @@ pub fn rebuild_index(paths: &ResolvedPaths, options: &ScanOptions) -> Result<Reb
         inserted_links,
         scan,
         unchanged: false,
+        build_id: Some(build_id),
     })
 }
 
@@ -691,12 +731,19 @@ fn unchanged_index_report(
 
     let inserted_links = count_query(connection, "SELECT COUNT(*) FROM indexed_links")
         .context("failed to count indexed links")?;
+    // Indexes built before snapshots existed get their first one here so the
+    // next changed build has something to diff against.
+    let build_id = match latest_index_build_id(connection)? {
+        Some(build_id) => build_id,
+        None => record_index_snapshot(connection)?,
+    };
     Ok(Some(RebuildReport {
         db_path: normalize_path(&paths.db_path),
         inserted_rows: indexed_hashes.len(),
         inserted_links,
         scan: scan.clone(),
         unchanged: true,
+        build_id: Some(build_id),
     }))
 }
 
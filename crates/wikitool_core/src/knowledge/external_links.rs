@@ -0,0 +1,507 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::Read;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use reqwest::Url;
+use reqwest::blocking::{Client, Response};
+
+use super::prelude::*;
+use crate::research::WebArchiveReport;
+use crate::support::format_iso8601_utc;
+
+pub use super::model::{
+    ExternalLinkCheck, ExternalLinkCheckOptions, ExternalLinkReport, ExternalLinkStatus,
+};
+
+/// Response bytes read when looking for soft-404 and paywall markers.
+const BODY_SNIFF_BYTES: u64 = 256 * 1024;
+const SOFT_404_MARKERS: &[&str] = &[
+    "page not found",
+    "404 not found",
+    "error 404",
+    "could not be found",
+    "couldn't be found",
+    "no longer available",
+    "page you requested",
+    "page you were looking for",
+    "page does not exist",
+    "page doesn't exist",
+];
+const PAYWALL_MARKERS: &[&str] = &[
+    "subscribe to continue",
+    "subscribe to read",
+    "subscription required",
+    "subscribers only",
+    "to continue reading",
+    "already a subscriber",
+    "create a free account to continue",
+    "this article is for subscribers",
+    "paywall",
+];
+
+/// What one HEAD/GET probe saw, before classification.
+#[derive(Debug, Clone, Default)]
+struct Probe {
+    http_status: Option<u16>,
+    final_url: Option<String>,
+    body: String,
+    error: Option<String>,
+    timed_out: bool,
+}
+
+#[derive(Debug, Clone)]
+struct CachedCheck {
+    status: ExternalLinkStatus,
+    http_status: Option<u16>,
+    final_url: Option<String>,
+    detail: Option<String>,
+    checked_at_unix: u64,
+}
+
+pub fn check_external_links(
+    paths: &ResolvedPaths,
+    options: &ExternalLinkCheckOptions,
+) -> Result<Option<ExternalLinkReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    let client = Client::builder()
+        .timeout(Duration::from_secs(options.timeout_seconds.max(1)))
+        .user_agent(crate::config::DEFAULT_USER_AGENT)
+        .build()
+        .context("failed to build external link HTTP client")?;
+    check_external_links_for_connection(paths, &connection, &client, options).map(Some)
+}
+
+/// Check every cited external URL, reusing fresh cached results and spacing
+/// requests to the same host by `per_host_delay_ms`.
+pub(crate) fn check_external_links_for_connection(
+    paths: &ResolvedPaths,
+    connection: &Connection,
+    client: &Client,
+    options: &ExternalLinkCheckOptions,
+) -> Result<ExternalLinkReport> {
+    let mut titles = Vec::new();
+    for title in &options.titles {
+        let record = load_page_record(connection, title)?
+            .with_context(|| format!("page is not indexed: {title}"))?;
+        titles.push(record.title);
+    }
+    let cited = load_cited_urls(connection, &titles)?;
+    let url_count = cited.len();
+    let mut queue = interleave_by_host(cited.keys().cloned().collect());
+    if let Some(limit) = options.limit {
+        queue.truncate(limit);
+    }
+    let archived = load_archived_copies(paths)?;
+
+    let now = unix_timestamp()?;
+    let delay = Duration::from_millis(options.per_host_delay_ms);
+    let mut last_request = HashMap::<String, Instant>::new();
+    let mut checks = Vec::new();
+    let mut cached_count = 0usize;
+    for url in queue {
+        let host = url_host(&url);
+        let cached = if options.refresh {
+            None
+        } else {
+            load_cached_check(connection, &url)?.filter(|cached| {
+                now.saturating_sub(cached.checked_at_unix) <= options.max_age_seconds
+            })
+        };
+        let (result, from_cache) = match cached {
+            Some(cached) => {
+                cached_count += 1;
+                (cached, true)
+            }
+            None => {
+                if let Some(previous) = last_request.get(&host) {
+                    let elapsed = previous.elapsed();
+                    if elapsed < delay {
+                        sleep(delay - elapsed);
+                    }
+                }
+                let probe = probe_url(client, &url);
+                last_request.insert(host.clone(), Instant::now());
+                let (status, detail) = classify_probe(&url, &probe);
+                let result = CachedCheck {
+                    status,
+                    http_status: probe.http_status,
+                    final_url: probe.final_url.filter(|final_url| final_url != &url),
+                    detail,
+                    checked_at_unix: unix_timestamp()?,
+                };
+                if status != ExternalLinkStatus::Unverified {
+                    store_cached_check(connection, &url, &host, &result)?;
+                }
+                (result, false)
+            }
+        };
+        checks.push(ExternalLinkCheck {
+            archived_copy: archived.get(&url_match_key(&url)).cloned(),
+            source_pages: cited.get(&url).cloned().unwrap_or_default(),
+            url,
+            status: result.status,
+            http_status: result.http_status,
+            final_url: result.final_url,
+            detail: result.detail,
+            checked_at: format_iso8601_utc(result.checked_at_unix),
+            from_cache,
+        });
+    }
+
+    let mut status_counts = BTreeMap::new();
+    for check in &checks {
+        *status_counts
+            .entry(check.status.as_str().to_string())
+            .or_insert(0usize) += 1;
+    }
+    let checked_count = checks.len();
+    let mut links = checks
+        .into_iter()
+        .filter(|check| check.status != ExternalLinkStatus::Ok)
+        .collect::<Vec<_>>();
+    links.sort_by(|left, right| {
+        right
+            .status
+            .is_issue()
+            .cmp(&left.status.is_issue())
+            .then(left.status.cmp(&right.status))
+            .then(left.url.cmp(&right.url))
+    });
+    let issue_count = links.iter().filter(|link| link.status.is_issue()).count();
+    let archived_issue_count = links
+        .iter()
+        .filter(|link| link.status.is_issue() && link.archived_copy.is_some())
+        .count();
+    Ok(ExternalLinkReport {
+        url_count,
+        checked_count,
+        cached_count,
+        fetched_count: checked_count - cached_count,
+        status_counts,
+        issue_count,
+        archived_issue_count,
+        links,
+    })
+}
+
+/// Distinct http(s) URLs cited in references, each with its citing page titles.
+fn load_cited_urls(
+    connection: &Connection,
+    titles: &[String],
+) -> Result<BTreeMap<String, Vec<String>>> {
+    let mut statement = connection
+        .prepare(
+            "SELECT source_title, canonical_url, source_urls
+             FROM indexed_page_references
+             ORDER BY source_title ASC, reference_index ASC",
+        )
+        .context("failed to prepare external link reference query")?;
+    let rows = statement
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })
+        .context("failed to run external link reference query")?;
+    let mut out = BTreeMap::<String, BTreeSet<String>>::new();
+    for row in rows {
+        let (title, canonical_url, source_urls) =
+            row.context("failed to decode external link reference row")?;
+        if !titles.is_empty() && !titles.contains(&title) {
+            continue;
+        }
+        let mut urls = parse_string_list(&source_urls);
+        urls.push(canonical_url);
+        for url in urls {
+            let url = url.trim();
+            if url.starts_with("http://") || url.starts_with("https://") {
+                out.entry(url.to_string())
+                    .or_default()
+                    .insert(title.clone());
+            }
+        }
+    }
+    Ok(out
+        .into_iter()
+        .map(|(url, titles)| (url, titles.into_iter().collect()))
+        .collect())
+}
+
+/// Round-robin the URLs across hosts so per-host delays overlap instead of
+/// stalling on one slow site.
+fn interleave_by_host(urls: Vec<String>) -> Vec<String> {
+    let mut by_host = BTreeMap::<String, VecDeque<String>>::new();
+    for url in urls {
+        by_host.entry(url_host(&url)).or_default().push_back(url);
+    }
+    let mut out = Vec::new();
+    while !by_host.is_empty() {
+        by_host.retain(|_, queue| {
+            if let Some(url) = queue.pop_front() {
+                out.push(url);
+            }
+            !queue.is_empty()
+        });
+    }
+    out
+}
+
+fn url_host(url: &str) -> String {
+    Url::parse(url)
+        .ok()
+        .and_then(|parsed| parsed.host_str().map(str::to_ascii_lowercase))
+        .unwrap_or_default()
+}
+
+/// HEAD first; fall back to GET when HEAD fails, is rejected, or returns a
+/// page whose body has to be read for soft-404 and paywall markers.
+fn probe_url(client: &Client, url: &str) -> Probe {
+    if let Ok(response) = client.head(url).send() {
+        let status = response.status().as_u16();
+        let is_html = response
+            .headers()
+            .get("content-type")
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.contains("html"));
+        if (200..300).contains(&status) && !is_html {
+            return Probe {
+                http_status: Some(status),
+                final_url: Some(response.url().to_string()),
+                ..Probe::default()
+            };
+        }
+    }
+    match client.get(url).send() {
+        Ok(response) => probe_from_response(response),
+        Err(error) => Probe {
+            timed_out: error.is_timeout(),
+            error: Some(error.to_string()),
+            ..Probe::default()
+        },
+    }
+}
+
+fn probe_from_response(response: Response) -> Probe {
+    let http_status = response.status().as_u16();
+    let final_url = response.url().to_string();
+    let mut bytes = Vec::new();
+    let _ = response.take(BODY_SNIFF_BYTES).read_to_end(&mut bytes);
+    Probe {
+        http_status: Some(http_status),
+        final_url: Some(final_url),
+        body: String::from_utf8_lossy(&bytes).to_lowercase(),
+        ..Probe::default()
+    }
+}
+
+fn classify_probe(url: &str, probe: &Probe) -> (ExternalLinkStatus, Option<String>) {
+    // Timeouts, DNS and TLS failures and refused connections say as much
+    // about the checker's network as about the link, so only an HTTP answer
+    // can mark a link dead.
+    let Some(status) = probe.http_status else {
+        let error = probe.error.clone().unwrap_or_default();
+        let reason = if probe.timed_out {
+            "timed out"
+        } else {
+            "unreachable"
+        };
+        return (
+            ExternalLinkStatus::Unverified,
+            Some(format!("{reason}: {error}")),
+        );
+    };
+    let paywall_marker = PAYWALL_MARKERS
+        .iter()
+        .find(|marker| probe.body.contains(**marker));
+    match status {
+        401 | 402 => {
+            return (
+                ExternalLinkStatus::Paywalled,
+                Some(format!("HTTP {status}")),
+            );
+        }
+        403 if paywall_marker.is_some() => {
+            return (
+                ExternalLinkStatus::Paywalled,
+                paywall_marker.map(|marker| format!("HTTP 403, page says \"{marker}\"")),
+            );
+        }
+        403 | 429 | 500..=599 => {
+            return (
+                ExternalLinkStatus::Unverified,
+                Some(format!("HTTP {status}")),
+            );
+        }
+        400..=499 => return (ExternalLinkStatus::Dead, Some(format!("HTTP {status}"))),
+        _ => {}
+    }
+
+    let final_url = probe.final_url.as_deref().unwrap_or(url);
+    if redirected_to_site_root(url, final_url) {
+        return (
+            ExternalLinkStatus::Soft404,
+            Some(format!("redirected to site root {final_url}")),
+        );
+    }
+    // Navigation and search boxes mention "not found" on healthy pages, so only
+    // the title and main heading count.
+    let title = html_element_text(&probe.body, "title");
+    let heading = html_element_text(&probe.body, "h1");
+    if let Some(marker) = SOFT_404_MARKERS
+        .iter()
+        .find(|marker| title.contains(**marker) || heading.contains(**marker))
+        .or_else(|| (title.contains("404") || heading.contains("404")).then_some(&"404"))
+    {
+        return (
+            ExternalLinkStatus::Soft404,
+            Some(format!("HTTP {status} but page says \"{marker}\"")),
+        );
+    }
+    if let Some(marker) = paywall_marker {
+        return (
+            ExternalLinkStatus::Paywalled,
+            Some(format!("page says \"{marker}\"")),
+        );
+    }
+    if url_match_key(url) != url_match_key(final_url) {
+        return (
+            ExternalLinkStatus::Redirected,
+            Some(format!("redirects to {final_url}")),
+        );
+    }
+    (ExternalLinkStatus::Ok, None)
+}
+
+/// Text of the first `<tag>` element, markup left as-is.
+fn html_element_text(body: &str, tag: &str) -> String {
+    let open = format!("<{tag}");
+    let Some(start) = body.find(&open) else {
+        return String::new();
+    };
+    let Some(open_end) = body[start..].find('>') else {
+        return String::new();
+    };
+    let content_start = start + open_end + 1;
+    let content_end = body[content_start..]
+        .find(&format!("</{tag}"))
+        .map(|offset| content_start + offset)
+        .unwrap_or(content_start);
+    body[content_start..content_end].trim().to_string()
+}
+
+fn redirected_to_site_root(url: &str, final_url: &str) -> bool {
+    let (Ok(original), Ok(resolved)) = (Url::parse(url), Url::parse(final_url)) else {
+        return false;
+    };
+    !original.path().trim_matches('/').is_empty()
+        && resolved.path().trim_matches('/').is_empty()
+        && resolved.query().is_none()
+}
+
+/// Comparison key that ignores scheme, `www.`, fragments, and a trailing slash.
+fn url_match_key(url: &str) -> String {
+    let without_fragment = url.split('#').next().unwrap_or(url);
+    let without_scheme = without_fragment
+        .split_once("://")
+        .map(|(_, rest)| rest)
+        .unwrap_or(without_fragment);
+    without_scheme
+        .trim_start_matches("www.")
+        .trim_end_matches('/')
+        .to_ascii_lowercase()
+}
+
+/// Captured copies from `research archive` manifests, keyed by URL match key.
+fn load_archived_copies(paths: &ResolvedPaths) -> Result<BTreeMap<String, String>> {
+    let root = paths.state_dir.join("backups").join("web");
+    let mut out = BTreeMap::new();
+    let Ok(entries) = fs::read_dir(&root) else {
+        return Ok(out);
+    };
+    let mut manifests = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path().join("manifest.json"))
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    manifests.sort();
+    for manifest_path in manifests {
+        let content = fs::read_to_string(&manifest_path)
+            .with_context(|| format!("failed to read {}", manifest_path.display()))?;
+        let Ok(manifest) = serde_json::from_str::<WebArchiveReport>(&content) else {
+            continue;
+        };
+        for entry in manifest.entries.iter().filter(|entry| entry.ok) {
+            let Some(relative) = &entry.path else {
+                continue;
+            };
+            let local = normalize_path(std::path::Path::new(&manifest.output_dir).join(relative));
+            for url in std::iter::once(&entry.url).chain(entry.final_url.as_ref()) {
+                out.insert(url_match_key(url), local.clone());
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn load_cached_check(connection: &Connection, url: &str) -> Result<Option<CachedCheck>> {
+    connection
+        .query_row(
+            "SELECT status, http_status, final_url, detail, checked_at_unix
+             FROM external_link_checks
+             WHERE url = ?1",
+            [url],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<i64>>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, i64>(4)?,
+                ))
+            },
+        )
+        .optional()
+        .with_context(|| format!("failed to load cached external link check for {url}"))
+        .map(|row| {
+            row.and_then(|(status, http_status, final_url, detail, checked_at)| {
+                Some(CachedCheck {
+                    status: ExternalLinkStatus::parse(&status)?,
+                    http_status: http_status.and_then(|value| u16::try_from(value).ok()),
+                    final_url,
+                    detail,
+                    checked_at_unix: u64::try_from(checked_at).unwrap_or(0),
+                })
+            })
+        })
+}
+
+fn store_cached_check(
+    connection: &Connection,
+    url: &str,
+    host: &str,
+    check: &CachedCheck,
+) -> Result<()> {
+    connection
+        .execute(
+            "INSERT OR REPLACE INTO external_link_checks (
+                url, host, status, http_status, final_url, detail, checked_at_unix
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                url,
+                host,
+                check.status.as_str(),
+                check.http_status.map(i64::from),
+                check.final_url,
+                check.detail,
+                i64::try_from(check.checked_at_unix).unwrap_or(i64::MAX),
+            ],
+        )
+        .with_context(|| format!("failed to cache external link check for {url}"))?;
+    Ok(())
+}
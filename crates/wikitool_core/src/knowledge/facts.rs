@@ -0,0 +1,241 @@
+use anyhow::bail;
+use rusqlite::Statement;
+
+use super::prelude::*;
+use crate::filesystem::ScannedFile;
+use crate::knowledge::templates::{normalize_module_lookup_title, normalize_template_lookup_title};
+use crate::wikitext::facts::extract_infobox_facts;
+
+pub use super::model::{
+    InfoboxFactOptions, InfoboxFactRecord, InfoboxFactReport, InfoboxFactTemplateSummary,
+};
+
+/// Template and module title prefixes mapped to the `infobox` category.
+pub(crate) fn load_infobox_template_prefixes(connection: &Connection) -> Result<Vec<String>> {
+    if !table_exists(connection, "template_category_mappings")? {
+        return Ok(Vec::new());
+    }
+    let mut statement = connection
+        .prepare(
+            "SELECT prefix
+             FROM template_category_mappings
+             WHERE category = 'infobox'
+             ORDER BY prefix ASC",
+        )
+        .context("failed to prepare infobox template prefix query")?;
+    let rows = statement
+        .query_map([], |row| row.get::<_, String>(0))
+        .context("failed to query infobox template prefixes")?;
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row.context("failed to decode infobox template prefix")?);
+    }
+    Ok(out)
+}
+
+/// Insert one page's infobox parameters during the index rebuild.
+pub(crate) fn store_infobox_facts(
+    statement: &mut Statement<'_>,
+    file: &ScannedFile,
+    content: &str,
+    prefixes: &[String],
+) -> Result<usize> {
+    if prefixes.is_empty() {
+        return Ok(0);
+    }
+    let facts = extract_infobox_facts(content, |title| {
+        prefixes.iter().any(|prefix| title.starts_with(prefix))
+    });
+    for fact in &facts {
+        statement
+            .execute(params![
+                file.relative_path,
+                i64::try_from(fact.invocation_index)
+                    .context("infobox invocation index does not fit into i64")?,
+                fact.parameter_key,
+                file.title,
+                file.namespace,
+                fact.template_title,
+                fact.raw_value,
+                fact.value_kind.as_str(),
+                fact.normalized_value,
+            ])
+            .with_context(|| {
+                format!("failed to insert infobox facts for {}", file.relative_path)
+            })?;
+    }
+    Ok(facts.len())
+}
+
+pub fn query_infobox_facts(
+    paths: &ResolvedPaths,
+    options: &InfoboxFactOptions,
+) -> Result<Option<InfoboxFactReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    query_infobox_facts_for_connection(&connection, options).map(Some)
+}
+
+pub(crate) fn query_infobox_facts_for_connection(
+    connection: &Connection,
+    options: &InfoboxFactOptions,
+) -> Result<InfoboxFactReport> {
+    let template = options.template.as_deref().map(normalize_fact_template);
+    let param = options
+        .param
+        .as_deref()
+        .map(normalize_template_parameter_key);
+    let value = options
+        .value
+        .as_deref()
+        .map(|value| value.trim().to_ascii_lowercase());
+    let value_kind = options.value_kind.clone();
+    let title = match &options.title {
+        Some(title) => Some(
+            load_page_record(connection, title)?
+                .map(|record| record.title)
+                .with_context(|| format!("page is not indexed: {title}"))?,
+        ),
+        None => None,
+    };
+
+    let templates = load_template_summaries(connection, template.as_deref())?;
+    let mut facts = Vec::new();
+    let mut missing_pages = Vec::new();
+    if options.missing {
+        let (Some(template), Some(param)) = (template.as_deref(), param.as_deref()) else {
+            bail!("--missing requires both a template and a parameter");
+        };
+        missing_pages = load_pages_missing_param(connection, template, param)?;
+    } else {
+        let mut statement = connection
+            .prepare(
+                "SELECT source_title, source_namespace, template_title, invocation_index,
+                        parameter_key, value_kind, normalized_value, raw_value
+                 FROM indexed_infobox_facts
+                 WHERE (?1 IS NULL OR template_title = ?1)
+                   AND (?2 IS NULL OR parameter_key = ?2)
+                   AND (?3 IS NULL OR source_title = ?3)
+                   AND (?4 IS NULL OR value_kind = ?4)
+                   AND (?5 IS NULL OR instr(lower(normalized_value), ?5) > 0)
+                   AND value_kind != 'empty'
+                 ORDER BY source_title ASC, invocation_index ASC, parameter_key ASC",
+            )
+            .context("failed to prepare infobox fact query")?;
+        let rows = statement
+            .query_map(params![template, param, title, value_kind, value], |row| {
+                Ok(InfoboxFactRecord {
+                    page_title: row.get(0)?,
+                    page_namespace: row.get(1)?,
+                    template_title: row.get(2)?,
+                    invocation_index: row.get::<_, i64>(3)? as usize,
+                    parameter_key: row.get(4)?,
+                    value_kind: row.get(5)?,
+                    normalized_value: row.get(6)?,
+                    raw_value: row.get(7)?,
+                })
+            })
+            .context("failed to run infobox fact query")?;
+        for row in rows {
+            facts.push(row.context("failed to decode infobox fact row")?);
+        }
+    }
+
+    let fact_count = facts.len();
+    let missing_count = missing_pages.len();
+    facts.truncate(options.limit);
+    missing_pages.truncate(options.limit);
+    Ok(InfoboxFactReport {
+        template,
+        param,
+        missing: options.missing,
+        fact_count,
+        missing_count,
+        templates,
+        facts,
+        missing_pages,
+    })
+}
+
+fn normalize_fact_template(value: &str) -> String {
+    let trimmed = value.trim();
+    if trimmed
+        .get(..7)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("Module:"))
+    {
+        return normalize_module_lookup_title(trimmed);
+    }
+    normalize_template_lookup_title(trimmed)
+}
+
+fn load_template_summaries(
+    connection: &Connection,
+    template: Option<&str>,
+) -> Result<Vec<InfoboxFactTemplateSummary>> {
+    let mut statement = connection
+        .prepare(
+            "SELECT template_title,
+                    COUNT(DISTINCT source_relative_path),
+                    SUM(CASE WHEN value_kind != 'empty' THEN 1 ELSE 0 END)
+             FROM indexed_infobox_facts
+             WHERE ?1 IS NULL OR template_title = ?1
+             GROUP BY template_title
+             ORDER BY COUNT(DISTINCT source_relative_path) DESC, template_title ASC",
+        )
+        .context("failed to prepare infobox template summary query")?;
+    let rows = statement
+        .query_map([template], |row| {
+            Ok(InfoboxFactTemplateSummary {
+                template_title: row.get(0)?,
+                page_count: row.get::<_, i64>(1)? as usize,
+                fact_count: row.get::<_, i64>(2)? as usize,
+            })
+        })
+        .context("failed to run infobox template summary query")?;
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row.context("failed to decode infobox template summary row")?);
+    }
+    Ok(out)
+}
+
+/// Pages that invoke `template` without a non-empty `param`.
+fn load_pages_missing_param(
+    connection: &Connection,
+    template: &str,
+    param: &str,
+) -> Result<Vec<String>> {
+    let mut statement = connection
+        .prepare(
+            "SELECT DISTINCT source_title
+             FROM (
+                 SELECT source_relative_path, source_title
+                 FROM indexed_infobox_facts
+                 WHERE template_title = ?1
+                 UNION
+                 SELECT source_relative_path, source_title
+                 FROM indexed_template_invocations
+                 WHERE template_title = ?1
+             ) AS users
+             WHERE NOT EXISTS (
+                 SELECT 1
+                 FROM indexed_infobox_facts AS facts
+                 WHERE facts.source_relative_path = users.source_relative_path
+                   AND facts.template_title = ?1
+                   AND facts.parameter_key = ?2
+                   AND facts.value_kind != 'empty'
+             )
+             ORDER BY source_title ASC",
+        )
+        .context("failed to prepare missing infobox parameter query")?;
+    let rows = statement
+        .query_map(params![template, param], |row| row.get::<_, String>(0))
+        .context("failed to run missing infobox parameter query")?;
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row.context("failed to decode missing infobox parameter row")?);
+    }
+    Ok(out)
+}
@@ -0,0 +1,64 @@
+use super::prelude::*;
+use crate::graph::{
+    DirectedGraph, GraphFilter, GraphKind, build_graph, connected_node_ids, ego_node_ids,
+    induced_subgraph,
+};
+
+#[derive(Debug, Clone)]
+pub struct GraphExportOptions {
+    pub kind: GraphKind,
+    pub filter: GraphFilter,
+    /// Restrict the export to the neighborhood of this title.
+    pub ego_title: Option<String>,
+    pub ego_depth: usize,
+    /// Keep nodes with no edges; off by default so exports stay readable.
+    pub include_isolated: bool,
+}
+
+impl GraphExportOptions {
+    pub fn new(kind: GraphKind) -> Self {
+        Self {
+            kind,
+            filter: GraphFilter::default(),
+            ego_title: None,
+            ego_depth: 1,
+            include_isolated: false,
+        }
+    }
+}
+
+pub fn build_graph_export(
+    paths: &ResolvedPaths,
+    options: &GraphExportOptions,
+) -> Result<Option<DirectedGraph>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    build_graph_export_for_connection(&connection, options).map(Some)
+}
+
+pub(crate) fn build_graph_export_for_connection(
+    connection: &Connection,
+    options: &GraphExportOptions,
+) -> Result<DirectedGraph> {
+    let graph = build_graph(connection, options.kind, &options.filter)?;
+
+    let mut keep = match &options.ego_title {
+        Some(title) => {
+            let center = load_page_record(connection, title)?
+                .and_then(|record| graph.node_by_title(&record.title))
+                .with_context(|| format!("graph export title is not indexed: {title}"))?;
+            ego_node_ids(&graph, center, options.ego_depth)
+        }
+        None => graph.nodes.iter().map(|node| node.id).collect(),
+    };
+    if let Some(namespaces) = &options.filter.include_namespaces {
+        keep.retain(|id| namespaces.contains(&graph.nodes[id.0 as usize].namespace));
+    }
+    if !options.include_isolated && options.ego_title.is_none() {
+        let connected = connected_node_ids(&graph);
+        keep.retain(|id| connected.contains(id));
+    }
+    Ok(induced_subgraph(&graph, &keep))
+}
@@ -3,11 +3,14 @@ use super::prelude::*;
 use serde::Deserialize;
 
 use crate::mw::{MediaWikiClient, WikiReadApi};
+use crate::wikitext::facts::plain_text;
 
 pub use super::model::{
-    BrokenLinkIssue, DoubleRedirectIssue, LiveBrokenLinkVerification,
-    LiveDoubleRedirectVerification, LivePageVerificationStatus, LiveRedirectVerificationStatus,
-    LiveValidationReport, ValidationReport,
+    BrokenLinkIssue, BrokenRedirectIssue, DoubleRedirectIssue, LiveAnchorVerification,
+    LiveBrokenLinkVerification, LiveDoubleRedirectVerification, LivePageVerificationStatus,
+    LiveRedirectVerificationStatus, LiveTitleVerification, LiveValidationReport,
+    MissingAnchorIssue, MissingCategoryPageIssue, MissingFileIssue, OversizedPageIssue,
+    RedirectBypassIssue, ValidationCategory, ValidationOptions, ValidationReport,
 };
 
 #[derive(Debug, Deserialize, Default)]
@@ -29,6 +32,27 @@ struct LiveQueryPage {
     pageid: Option<i64>,
     title: String,
     missing: Option<bool>,
+    length: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct LiveParseResponse {
+    #[serde(default)]
+    parse: LiveParsePayload,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct LiveParsePayload {
+    #[serde(default)]
+    sections: Vec<LiveParseSection>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LiveParseSection {
+    #[serde(default)]
+    line: String,
+    #[serde(default)]
+    anchor: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -38,17 +62,57 @@ struct LiveQueryRedirect {
 }
 
 pub fn run_validation_checks(paths: &ResolvedPaths) -> Result<Option<ValidationReport>> {
+    run_validation_checks_with_options(paths, &ValidationOptions::default())
+}
+
+pub fn run_validation_checks_with_options(
+    paths: &ResolvedPaths,
+    options: &ValidationOptions,
+) -> Result<Option<ValidationReport>> {
     let connection = match open_indexed_connection(paths)? {
         Some(connection) => connection,
         None => return Ok(None),
     };
 
-    Ok(Some(ValidationReport {
-        broken_links: query_broken_links_for_connection(&connection)?,
-        double_redirects: query_double_redirects_for_connection(&connection)?,
-        uncategorized_pages: query_uncategorized_pages_for_connection(&connection)?,
-        orphan_pages: query_orphans_for_connection(&connection)?,
-    }))
+    // Each category reads the index separately (missing anchors re-parse every
+    // linking page), so only the selected ones run.
+    let run = |category| options.includes(category);
+    let mut report = ValidationReport::default();
+    if run(ValidationCategory::BrokenLinks) {
+        report.broken_links = query_broken_links_for_connection(&connection)?;
+    }
+    if run(ValidationCategory::DoubleRedirects) {
+        report.double_redirects = query_double_redirects_for_connection(&connection)?;
+    }
+    if run(ValidationCategory::UncategorizedPages) {
+        report.uncategorized_pages = query_uncategorized_pages_for_connection(&connection)?;
+    }
+    if run(ValidationCategory::OrphanPages) {
+        report.orphan_pages = query_orphans_for_connection(&connection)?;
+    }
+    if run(ValidationCategory::SelfLinks) {
+        report.self_links = query_self_links_for_connection(&connection)?;
+    }
+    if run(ValidationCategory::RedirectBypasses) {
+        report.redirect_bypasses = query_redirect_bypasses_for_connection(&connection)?;
+    }
+    if run(ValidationCategory::MissingAnchors) {
+        report.missing_anchors = query_missing_anchors_for_connection(paths, &connection)?;
+    }
+    if run(ValidationCategory::MissingFiles) {
+        report.missing_files = query_missing_files_for_connection(&connection)?;
+    }
+    if run(ValidationCategory::MissingCategoryPages) {
+        report.missing_category_pages = query_missing_category_pages_for_connection(&connection)?;
+    }
+    if run(ValidationCategory::OversizedPages) {
+        report.oversized_pages =
+            query_oversized_pages_for_connection(&connection, options.oversized_page_bytes)?;
+    }
+    if run(ValidationCategory::BrokenRedirects) {
+        report.broken_redirects = query_broken_redirects_for_connection(&connection)?;
+    }
+    Ok(Some(report))
 }
 
 pub fn verify_validation_report_live(
@@ -62,6 +126,43 @@ pub fn verify_validation_report_live(
     for issue in &report.double_redirects {
         titles.insert(issue.title.clone());
     }
+    titles.extend(report.self_links.iter().cloned());
+    titles.extend(
+        report
+            .redirect_bypasses
+            .iter()
+            .map(|issue| issue.link_title.clone()),
+    );
+    titles.extend(
+        report
+            .missing_anchors
+            .iter()
+            .map(|issue| issue.target_title.clone()),
+    );
+    titles.extend(
+        report
+            .missing_files
+            .iter()
+            .map(|issue| issue.file_title.clone()),
+    );
+    titles.extend(
+        report
+            .missing_category_pages
+            .iter()
+            .map(|issue| issue.category_title.clone()),
+    );
+    titles.extend(
+        report
+            .oversized_pages
+            .iter()
+            .map(|issue| issue.title.clone()),
+    );
+    titles.extend(
+        report
+            .broken_redirects
+            .iter()
+            .map(|issue| issue.target_title.clone()),
+    );
 
     let mut client = MediaWikiClient::from_config(config)?;
     let live_titles = fetch_live_title_statuses(&mut client, titles.into_iter().collect())?;
@@ -121,13 +222,440 @@ pub fn verify_validation_report_live(
         })
         .collect();
 
+    let title_check = |issue_title: &str, checked_title: &str| {
+        live_title_verification(&live_titles, issue_title, checked_title)
+    };
+    let self_links = report
+        .self_links
+        .iter()
+        .map(|title| title_check(title, title))
+        .collect();
+    let redirect_bypasses = report
+        .redirect_bypasses
+        .iter()
+        .map(|issue| title_check(&issue.source_title, &issue.link_title))
+        .collect();
+    let missing_files = report
+        .missing_files
+        .iter()
+        .map(|issue| title_check(&issue.source_title, &issue.file_title))
+        .collect();
+    let missing_category_pages = report
+        .missing_category_pages
+        .iter()
+        .map(|issue| title_check(&issue.category_title, &issue.category_title))
+        .collect();
+    let oversized_pages = report
+        .oversized_pages
+        .iter()
+        .map(|issue| title_check(&issue.title, &issue.title))
+        .collect();
+    let broken_redirects = report
+        .broken_redirects
+        .iter()
+        .map(|issue| title_check(&issue.title, &issue.target_title))
+        .collect();
+
+    let mut live_anchors = BTreeMap::<String, Option<Vec<String>>>::new();
+    let mut missing_anchors = Vec::new();
+    for issue in &report.missing_anchors {
+        let live_title = lookup_live_title(&live_titles, &issue.target_title);
+        let live_status = live_title
+            .map(LiveTitleStatus::page_status)
+            .unwrap_or(LivePageVerificationStatus::Missing);
+        let live_heading_exists = if live_status == LivePageVerificationStatus::Missing {
+            None
+        } else {
+            let key = live_title_key(&issue.target_title);
+            if !live_anchors.contains_key(&key) {
+                let anchors = fetch_live_section_anchors(&mut client, &issue.target_title);
+                live_anchors.insert(key.clone(), anchors);
+            }
+            live_anchors[&key]
+                .as_ref()
+                .map(|anchors| anchors.contains(&anchor_key(&issue.anchor)))
+        };
+        missing_anchors.push(LiveAnchorVerification {
+            source_title: issue.source_title.clone(),
+            target_title: issue.target_title.clone(),
+            anchor: issue.anchor.clone(),
+            live_status,
+            live_heading_exists,
+        });
+    }
+
     Ok(LiveValidationReport {
         request_count: client.request_count(),
         broken_links,
         double_redirects,
+        self_links,
+        redirect_bypasses,
+        missing_anchors,
+        missing_files,
+        missing_category_pages,
+        oversized_pages,
+        broken_redirects,
     })
 }
 
+fn live_title_verification(
+    live_titles: &BTreeMap<String, LiveTitleStatus>,
+    issue_title: &str,
+    checked_title: &str,
+) -> LiveTitleVerification {
+    let live_title = lookup_live_title(live_titles, checked_title);
+    LiveTitleVerification {
+        issue_title: issue_title.to_string(),
+        checked_title: checked_title.to_string(),
+        live_status: live_title
+            .map(LiveTitleStatus::page_status)
+            .unwrap_or(LivePageVerificationStatus::Missing),
+        resolved_title: live_title.and_then(LiveTitleStatus::resolved_title),
+        page_id: live_title.and_then(LiveTitleStatus::page_id),
+        live_bytes: live_title.and_then(|status| status.page.length),
+    }
+}
+
+/// Anchor keys of the live page's section headings, or `None` when the page
+/// cannot be parsed.
+fn fetch_live_section_anchors(client: &mut MediaWikiClient, title: &str) -> Option<Vec<String>> {
+    let response = client
+        .request_json_get(&[
+            ("action", "parse".to_string()),
+            ("page", title.to_string()),
+            ("prop", "sections".to_string()),
+            ("redirects", "1".to_string()),
+        ])
+        .ok()?;
+    let parsed: LiveParseResponse = serde_json::from_value(response).ok()?;
+    Some(
+        parsed
+            .parse
+            .sections
+            .iter()
+            .flat_map(|section| [anchor_key(&section.anchor), anchor_key(&section.line)])
+            .collect(),
+    )
+}
+
+fn query_self_links_for_connection(connection: &Connection) -> Result<Vec<String>> {
+    query_validation_rows(
+        connection,
+        "self-link",
+        "SELECT DISTINCT l.source_title
+         FROM indexed_links l
+         JOIN indexed_pages src ON src.relative_path = l.source_relative_path
+         WHERE l.target_title = l.source_title
+           AND l.is_category_membership = 0
+           AND src.namespace = 'Main'
+           AND src.is_redirect = 0
+         ORDER BY l.source_title ASC",
+        [],
+        |row| row.get(0),
+    )
+}
+
+fn query_redirect_bypasses_for_connection(
+    connection: &Connection,
+) -> Result<Vec<RedirectBypassIssue>> {
+    query_validation_rows(
+        connection,
+        "redirect-bypass",
+        "SELECT DISTINCT l.source_title, l.target_title, p.redirect_target
+         FROM indexed_links l
+         JOIN indexed_pages src ON src.relative_path = l.source_relative_path
+         JOIN indexed_pages p ON p.title = l.target_title
+         WHERE l.is_category_membership = 0
+           AND src.namespace = 'Main'
+           AND src.is_redirect = 0
+           AND p.is_redirect = 1
+           AND p.redirect_target IS NOT NULL
+         ORDER BY l.source_title ASC, l.target_title ASC",
+        [],
+        |row| {
+            Ok(RedirectBypassIssue {
+                source_title: row.get(0)?,
+                link_title: row.get(1)?,
+                redirect_target: row.get(2)?,
+            })
+        },
+    )
+}
+
+fn query_missing_files_for_connection(connection: &Connection) -> Result<Vec<MissingFileIssue>> {
+    query_validation_rows(
+        connection,
+        "missing-file",
+        "SELECT source_title, file_title
+         FROM (
+             SELECT m.source_title, m.file_title
+             FROM indexed_page_media m
+             UNION
+             SELECT l.source_title, l.target_title
+             FROM indexed_links l
+             WHERE l.target_namespace = 'File'
+         ) used
+         WHERE NOT EXISTS (SELECT 1 FROM indexed_pages p WHERE p.title = used.file_title)
+         ORDER BY source_title ASC, file_title ASC",
+        [],
+        |row| {
+            Ok(MissingFileIssue {
+                source_title: row.get(0)?,
+                file_title: row.get(1)?,
+            })
+        },
+    )
+}
+
+fn query_missing_category_pages_for_connection(
+    connection: &Connection,
+) -> Result<Vec<MissingCategoryPageIssue>> {
+    query_validation_rows(
+        connection,
+        "missing-category-page",
+        "SELECT l.target_title, COUNT(DISTINCT l.source_title)
+         FROM indexed_links l
+         LEFT JOIN indexed_pages p ON p.title = l.target_title
+         WHERE l.is_category_membership = 1
+           AND p.title IS NULL
+         GROUP BY l.target_title
+         ORDER BY l.target_title ASC",
+        [],
+        |row| {
+            Ok(MissingCategoryPageIssue {
+                category_title: row.get(0)?,
+                member_count: usize::try_from(row.get::<_, i64>(1)?).unwrap_or_default(),
+            })
+        },
+    )
+}
+
+fn query_oversized_pages_for_connection(
+    connection: &Connection,
+    min_bytes: u64,
+) -> Result<Vec<OversizedPageIssue>> {
+    query_validation_rows(
+        connection,
+        "oversized-page",
+        "SELECT title, bytes
+         FROM indexed_pages
+         WHERE is_redirect = 0
+           AND bytes > ?1
+         ORDER BY bytes DESC, title ASC",
+        [i64::try_from(min_bytes).unwrap_or(i64::MAX)],
+        |row| {
+            Ok(OversizedPageIssue {
+                title: row.get(0)?,
+                bytes: u64::try_from(row.get::<_, i64>(1)?).unwrap_or_default(),
+            })
+        },
+    )
+}
+
+fn query_broken_redirects_for_connection(
+    connection: &Connection,
+) -> Result<Vec<BrokenRedirectIssue>> {
+    let redirects = query_validation_rows(
+        connection,
+        "broken-redirect",
+        "SELECT title, redirect_target
+         FROM indexed_pages
+         WHERE is_redirect = 1
+           AND redirect_target IS NOT NULL
+         ORDER BY title ASC",
+        [],
+        |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+    )?;
+    let mut out = Vec::new();
+    for (title, redirect_target) in redirects {
+        let target = redirect_target
+            .split_once('#')
+            .map(|(page, _)| page)
+            .unwrap_or(&redirect_target);
+        let target_title = normalize_query_title(target);
+        if target_title.is_empty() {
+            continue;
+        }
+        if load_page_record_exact(connection, &target_title)?.is_none() {
+            out.push(BrokenRedirectIssue {
+                title,
+                target_title,
+            });
+        }
+    }
+    Ok(out)
+}
+
+/// Section links whose anchor matches neither a heading in
+/// `indexed_page_sections` nor an explicit `{{anchor}}` or `id=` on the
+/// target page. Redirect targets are followed one hop.
+fn query_missing_anchors_for_connection(
+    paths: &ResolvedPaths,
+    connection: &Connection,
+) -> Result<Vec<MissingAnchorIssue>> {
+    let sources = query_validation_rows(
+        connection,
+        "section-link source",
+        "SELECT title, namespace, relative_path
+         FROM indexed_pages
+         WHERE is_redirect = 0
+         ORDER BY title ASC",
+        [],
+        |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        },
+    )?;
+
+    let mut anchors_by_target = BTreeMap::<String, BTreeSet<String>>::new();
+    let mut seen = BTreeSet::new();
+    let mut out = Vec::new();
+    for (source_title, namespace, relative_path) in sources {
+        let content = read_indexed_content(paths, &relative_path)?;
+        for link in extract_section_links_for_namespace(&content, &namespace) {
+            let target_title = match &link.target {
+                Some(target) => target.target_title.clone(),
+                None => source_title.clone(),
+            };
+            let Some(mut record) = load_page_record_exact(connection, &target_title)? else {
+                continue;
+            };
+            if record.is_redirect
+                && let Some(redirect_target) = &record.redirect_target
+            {
+                let redirect_page = redirect_target
+                    .split_once('#')
+                    .map_or(redirect_target.as_str(), |(page, _)| page);
+                match load_page_record_exact(connection, &normalize_query_title(redirect_page))? {
+                    Some(resolved) => record = resolved,
+                    None => continue,
+                }
+            }
+            if !anchors_by_target.contains_key(&record.title) {
+                let anchors = load_page_anchor_keys(paths, connection, &record)?;
+                anchors_by_target.insert(record.title.clone(), anchors);
+            }
+            if anchors_by_target[&record.title].contains(&anchor_key(&link.anchor)) {
+                continue;
+            }
+            if seen.insert((
+                source_title.clone(),
+                target_title.clone(),
+                link.anchor.clone(),
+            )) {
+                out.push(MissingAnchorIssue {
+                    source_title: source_title.clone(),
+                    target_title,
+                    anchor: link.anchor,
+                });
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn load_page_anchor_keys(
+    paths: &ResolvedPaths,
+    connection: &Connection,
+    record: &IndexedPageRecord,
+) -> Result<BTreeSet<String>> {
+    let mut anchors = query_validation_rows(
+        connection,
+        "section anchor",
+        "SELECT section_heading
+         FROM indexed_page_sections
+         WHERE source_relative_path = ?1
+           AND section_heading IS NOT NULL",
+        [record.relative_path.as_str()],
+        |row| row.get::<_, String>(0),
+    )?
+    .into_iter()
+    .map(|heading| anchor_key(&plain_text(&heading)))
+    .collect::<BTreeSet<_>>();
+    let content = read_indexed_content(paths, &record.relative_path)?;
+    anchors.extend(
+        explicit_anchor_ids(&content)
+            .iter()
+            .map(|anchor| anchor_key(anchor)),
+    );
+    Ok(anchors)
+}
+
+fn read_indexed_content(paths: &ResolvedPaths, relative_path: &str) -> Result<String> {
+    let path = absolute_path_from_relative(paths, relative_path);
+    fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+/// Anchors declared outside headings: `{{anchor|...}}` arguments and HTML
+/// `id` attributes.
+fn explicit_anchor_ids(content: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut rest = content;
+    while let Some(open) = rest.find("{{") {
+        rest = &rest[open + 2..];
+        let Some(close) = rest.find("}}") else {
+            break;
+        };
+        let mut parts = rest[..close].split('|');
+        let name = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+        if matches!(name.as_str(), "anchor" | "anchors" | "visible anchor") {
+            out.extend(
+                parts
+                    .filter(|part| !part.contains('='))
+                    .map(|part| part.trim().to_string())
+                    .filter(|part| !part.is_empty()),
+            );
+        }
+    }
+    let lowered = content.to_ascii_lowercase();
+    for (index, _) in lowered.match_indices(" id=") {
+        let value = &content[index + 4..];
+        let (quote, value) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => (Some(quote), &value[1..]),
+            _ => (None, value),
+        };
+        let end = value
+            .find(|ch: char| match quote {
+                Some(quote) => ch == quote,
+                None => ch.is_whitespace() || ch == '>' || ch == '/',
+            })
+            .unwrap_or(value.len());
+        if end > 0 {
+            out.push(value[..end].to_string());
+        }
+    }
+    out
+}
+
+/// Compare anchors the way MediaWiki resolves them: underscores and runs of
+/// spaces are equivalent, everything else is case-sensitive.
+fn anchor_key(value: &str) -> String {
+    normalize_spaces(&value.replace('_', " "))
+}
+
+fn query_validation_rows<T, P: rusqlite::Params>(
+    connection: &Connection,
+    label: &str,
+    sql: &str,
+    params: P,
+    map: impl FnMut(&rusqlite::Row<'_>) -> rusqlite::Result<T>,
+) -> Result<Vec<T>> {
+    let mut statement = connection
+        .prepare(sql)
+        .with_context(|| format!("failed to prepare {label} query"))?;
+    let rows = statement
+        .query_map(params, map)
+        .with_context(|| format!("failed to run {label} query"))?;
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row.with_context(|| format!("failed to decode {label} row"))?);
+    }
+    Ok(out)
+}
+
 pub fn query_backlinks(paths: &ResolvedPaths, title: &str) -> Result<Option<Vec<String>>> {
     let connection = match open_indexed_connection(paths)? {
         Some(connection) => connection,
@@ -194,6 +722,7 @@ struct LivePageStatus {
     title: Option<String>,
     page_id: Option<i64>,
     missing: bool,
+    length: Option<u64>,
 }
 
 impl LiveTitleStatus {
@@ -250,6 +779,7 @@ fn fetch_live_title_statuses(
                     title: Some(page.title),
                     page_id: page.pageid,
                     missing: page.missing.unwrap_or(false),
+                    length: page.length,
                 },
             );
         }
@@ -272,6 +802,7 @@ fn fetch_live_title_statuses(
                     title: None,
                     page_id: None,
                     missing: true,
+                    length: None,
                 });
             out.insert(
                 live_title_key(requested_title),
@@ -1,11 +1,25 @@
 pub mod authoring;
+pub mod categories;
+pub mod centrality;
+pub mod consistency;
 pub mod content_index;
+pub mod external_links;
+pub mod facts;
+pub mod graph_export;
 pub mod inspect;
 pub(crate) mod model;
 pub(crate) mod prelude;
+pub mod quality;
 pub mod references;
 pub mod retrieval;
+pub mod snapshots;
+pub mod staleness;
 pub mod status;
+pub mod template_impact;
 pub mod templates;
 #[cfg(test)]
 mod tests;
+pub mod translations;
+pub mod unreliable;
+pub mod unused;
+pub mod wanted;
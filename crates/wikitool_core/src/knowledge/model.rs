@@ -1,8 +1,8 @@
 use std::collections::BTreeMap;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use crate::filesystem::ScanStats;
+use crate::filesystem::{Namespace, ScanStats};
 use crate::knowledge::status::DEFAULT_DOCS_PROFILE;
 
 #[derive(Debug, Clone, Serialize)]
@@ -15,6 +15,10 @@ pub struct RebuildReport {
     /// byte-for-byte and the rebuild was skipped; the row/link counts then report
     /// the existing index contents.
     pub unchanged: bool,
+    /// Structural snapshot recorded for this build; the latest snapshot when
+    /// the rebuild was skipped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_id: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -702,12 +706,113 @@ pub struct DoubleRedirectIssue {
     pub final_target: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RedirectBypassIssue {
+    pub source_title: String,
+    pub link_title: String,
+    pub redirect_target: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct MissingAnchorIssue {
+    pub source_title: String,
+    pub target_title: String,
+    pub anchor: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct MissingFileIssue {
+    pub source_title: String,
+    pub file_title: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct MissingCategoryPageIssue {
+    pub category_title: String,
+    pub member_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct OversizedPageIssue {
+    pub title: String,
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct BrokenRedirectIssue {
+    pub title: String,
+    pub target_title: String,
+}
+
+/// A `validate` issue category. The first four make up the default gate; the
+/// rest are opt-in because they cost more or flag advisory problems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationCategory {
+    BrokenLinks,
+    DoubleRedirects,
+    UncategorizedPages,
+    OrphanPages,
+    SelfLinks,
+    RedirectBypasses,
+    MissingAnchors,
+    MissingFiles,
+    MissingCategoryPages,
+    OversizedPages,
+    BrokenRedirects,
+}
+
+impl ValidationCategory {
+    pub fn is_default(self) -> bool {
+        matches!(
+            self,
+            Self::BrokenLinks
+                | Self::DoubleRedirects
+                | Self::UncategorizedPages
+                | Self::OrphanPages
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationOptions {
+    /// Categories to check; empty checks the default categories only.
+    pub categories: Vec<ValidationCategory>,
+    /// Pages larger than this many bytes are reported as oversized.
+    pub oversized_page_bytes: u64,
+}
+
+impl ValidationOptions {
+    pub fn includes(&self, category: ValidationCategory) -> bool {
+        if self.categories.is_empty() {
+            category.is_default()
+        } else {
+            self.categories.contains(&category)
+        }
+    }
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            categories: Vec::new(),
+            oversized_page_bytes: 100_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ValidationReport {
     pub broken_links: Vec<BrokenLinkIssue>,
     pub double_redirects: Vec<DoubleRedirectIssue>,
     pub uncategorized_pages: Vec<String>,
     pub orphan_pages: Vec<String>,
+    pub self_links: Vec<String>,
+    pub redirect_bypasses: Vec<RedirectBypassIssue>,
+    pub missing_anchors: Vec<MissingAnchorIssue>,
+    pub missing_files: Vec<MissingFileIssue>,
+    pub missing_category_pages: Vec<MissingCategoryPageIssue>,
+    pub oversized_pages: Vec<OversizedPageIssue>,
+    pub broken_redirects: Vec<BrokenRedirectIssue>,
 }
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
@@ -746,9 +851,862 @@ pub struct LiveDoubleRedirectVerification {
     pub page_id: Option<i64>,
 }
 
+/// Live state of the page an issue hinges on: the self-linking or oversized
+/// page, the bypassable redirect, the missing file or category page, or the
+/// missing redirect target.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct LiveTitleVerification {
+    pub issue_title: String,
+    pub checked_title: String,
+    pub live_status: LivePageVerificationStatus,
+    pub resolved_title: Option<String>,
+    pub page_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct LiveAnchorVerification {
+    pub source_title: String,
+    pub target_title: String,
+    pub anchor: String,
+    pub live_status: LivePageVerificationStatus,
+    /// Whether a live section heading produces the anchor; `None` when the
+    /// target page could not be parsed live.
+    pub live_heading_exists: Option<bool>,
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 pub struct LiveValidationReport {
     pub request_count: usize,
     pub broken_links: Vec<LiveBrokenLinkVerification>,
     pub double_redirects: Vec<LiveDoubleRedirectVerification>,
+    pub self_links: Vec<LiveTitleVerification>,
+    pub redirect_bypasses: Vec<LiveTitleVerification>,
+    pub missing_anchors: Vec<LiveAnchorVerification>,
+    pub missing_files: Vec<LiveTitleVerification>,
+    pub missing_category_pages: Vec<LiveTitleVerification>,
+    pub oversized_pages: Vec<LiveTitleVerification>,
+    pub broken_redirects: Vec<LiveTitleVerification>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryTreeOptions {
+    pub root_categories: Vec<String>,
+    pub oversized_threshold: usize,
+    pub undersized_threshold: usize,
+    pub limit: usize,
+}
+
+impl Default for CategoryTreeOptions {
+    fn default() -> Self {
+        Self {
+            root_categories: Vec::new(),
+            oversized_threshold: 200,
+            undersized_threshold: 2,
+            limit: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct CategoryTreeNode {
+    pub title: String,
+    /// Distance from the nearest root category; `None` when no root reaches it.
+    pub depth: Option<usize>,
+    /// Shortest subcategory chain from a root down to this category, inclusive.
+    pub parent_chain: Vec<String>,
+    pub parent_categories: Vec<String>,
+    pub page_member_count: usize,
+    pub subcategory_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct CategorySizeIssue {
+    pub title: String,
+    pub page_member_count: usize,
+    pub subcategory_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RedundantCategoryIssue {
+    pub title: String,
+    pub page_member_count: usize,
+    /// Descendant subcategories that already contain the category's direct members.
+    pub covering_subcategories: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct PageOutsideCategoryTree {
+    pub title: String,
+    pub categories: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct CategoryTreeReport {
+    pub root_categories: Vec<String>,
+    /// True when roots came from top-level categories because none were configured.
+    pub inferred_roots: bool,
+    pub missing_root_categories: Vec<String>,
+    pub category_count: usize,
+    pub max_depth: usize,
+    pub unreachable_category_count: usize,
+    pub categories: Vec<CategoryTreeNode>,
+    pub oversized_threshold: usize,
+    pub oversized_count: usize,
+    pub oversized_categories: Vec<CategorySizeIssue>,
+    pub undersized_threshold: usize,
+    pub undersized_count: usize,
+    pub undersized_categories: Vec<CategorySizeIssue>,
+    pub redundant_count: usize,
+    pub redundant_categories: Vec<RedundantCategoryIssue>,
+    pub pages_outside_root_tree_count: usize,
+    pub pages_outside_root_tree: Vec<PageOutsideCategoryTree>,
+    /// Pages outside the root tree that sit in at least one category without
+    /// a local page, whose place in the tree is therefore unknown.
+    pub pages_under_missing_categories_count: usize,
+    pub pages_under_missing_categories: Vec<PageOutsideCategoryTree>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PageCentralitySort {
+    Pagerank,
+    Hub,
+    Authority,
+}
+
+impl PageCentralitySort {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pagerank => "pagerank",
+            Self::Hub => "hub",
+            Self::Authority => "authority",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageCentralityOptions {
+    pub title: Option<String>,
+    pub namespace: Option<String>,
+    pub sort: PageCentralitySort,
+    pub limit: usize,
+}
+
+impl Default for PageCentralityOptions {
+    fn default() -> Self {
+        Self {
+            title: None,
+            namespace: Some(Namespace::Main.as_str().to_string()),
+            sort: PageCentralitySort::Pagerank,
+            limit: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PageCentralityScore {
+    pub title: String,
+    pub namespace: String,
+    /// 1-based position under the requested sort within the namespace filter.
+    pub rank: usize,
+    pub pagerank: f64,
+    pub hub_score: f64,
+    pub authority_score: f64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PageCentralityReport {
+    pub sort: PageCentralitySort,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    pub page_count: usize,
+    pub pages: Vec<PageCentralityScore>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct TemplateImpactPage {
+    pub title: String,
+    pub namespace: String,
+    /// Hops from this page down to the changed template or module.
+    pub depth: usize,
+    /// Shortest dependency chain, starting at this page and ending at the target.
+    pub dependency_path: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct TemplateImpactReport {
+    pub target_title: String,
+    pub target_namespace: String,
+    pub affected_count: usize,
+    pub content_page_count: usize,
+    pub direct_count: usize,
+    pub max_depth: usize,
+    pub namespace_counts: BTreeMap<String, usize>,
+    pub pages: Vec<TemplateImpactPage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleClaimOptions {
+    pub title: Option<String>,
+    pub namespace: Option<String>,
+    /// Dated claims at least this many days old count as stale.
+    pub stale_after_days: i64,
+    pub limit: usize,
+}
+
+impl Default for StaleClaimOptions {
+    fn default() -> Self {
+        Self {
+            title: None,
+            namespace: Some(Namespace::Main.as_str().to_string()),
+            stale_after_days: 365,
+            limit: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct StaleClaim {
+    /// `as_of_template`, `update_template`, `as_of_phrase`, `relative_phrase`, or `infobox_field`.
+    pub kind: String,
+    pub line: usize,
+    pub text: String,
+    /// Last date the claim can refer to (`YYYY-MM-DD`); absent for undated wording.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age_days: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct StaleClaimPage {
+    pub title: String,
+    pub namespace: String,
+    pub stale_claim_count: usize,
+    pub dated_claim_count: usize,
+    pub undated_claim_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oldest_age_days: Option<i64>,
+    pub claims: Vec<StaleClaim>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct StaleClaimReport {
+    pub today: String,
+    pub stale_after_days: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    pub scanned_page_count: usize,
+    pub stale_page_count: usize,
+    pub stale_claim_count: usize,
+    pub pages: Vec<StaleClaimPage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InfoboxFactOptions {
+    pub template: Option<String>,
+    pub param: Option<String>,
+    pub title: Option<String>,
+    /// Substring match against the normalized value, case-insensitive.
+    pub value: Option<String>,
+    /// `date`, `number`, `link`, `url`, or `text`.
+    pub value_kind: Option<String>,
+    /// List pages using `template` without a non-empty `param` instead of facts.
+    pub missing: bool,
+    pub limit: usize,
+}
+
+impl Default for InfoboxFactOptions {
+    fn default() -> Self {
+        Self {
+            template: None,
+            param: None,
+            title: None,
+            value: None,
+            value_kind: None,
+            missing: false,
+            limit: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct InfoboxFactRecord {
+    pub page_title: String,
+    pub page_namespace: String,
+    pub template_title: String,
+    pub invocation_index: usize,
+    pub parameter_key: String,
+    pub value_kind: String,
+    pub normalized_value: String,
+    pub raw_value: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct InfoboxFactTemplateSummary {
+    pub template_title: String,
+    pub page_count: usize,
+    pub fact_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct InfoboxFactReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<String>,
+    pub missing: bool,
+    pub fact_count: usize,
+    pub missing_count: usize,
+    pub templates: Vec<InfoboxFactTemplateSummary>,
+    pub facts: Vec<InfoboxFactRecord>,
+    pub missing_pages: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FactConsistencyOptions {
+    /// Check only this page's infobox; all infobox pages when unset.
+    pub title: Option<String>,
+    /// Also compare against lead-sentence mentions on other pages.
+    pub include_leads: bool,
+    pub limit: usize,
+}
+
+impl Default for FactConsistencyOptions {
+    fn default() -> Self {
+        Self {
+            title: None,
+            include_leads: true,
+            limit: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct FactLocation {
+    pub page_title: String,
+    /// `infobox` or `lead`.
+    pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub evidence: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct FactConflict {
+    pub subject_title: String,
+    pub parameter_key: String,
+    pub value_kind: String,
+    pub canonical_value: String,
+    pub canonical_location: FactLocation,
+    pub other_value: String,
+    pub other_location: FactLocation,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct FactConsistencyReport {
+    pub subject_count: usize,
+    pub compared_fact_count: usize,
+    pub conflict_count: usize,
+    pub conflicts: Vec<FactConflict>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalLinkCheckOptions {
+    /// Only check URLs cited on these pages; all indexed pages when empty.
+    pub titles: Vec<String>,
+    /// Maximum distinct URLs to check in one run.
+    pub limit: Option<usize>,
+    /// Cached results younger than this are reused without a request.
+    pub max_age_seconds: u64,
+    /// Ignore cached results and re-check every URL.
+    pub refresh: bool,
+    /// Minimum gap between two requests to the same host.
+    pub per_host_delay_ms: u64,
+    pub timeout_seconds: u64,
+}
+
+impl Default for ExternalLinkCheckOptions {
+    fn default() -> Self {
+        Self {
+            titles: Vec::new(),
+            limit: None,
+            max_age_seconds: 7 * 24 * 60 * 60,
+            refresh: false,
+            per_host_delay_ms: 1_000,
+            timeout_seconds: 15,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalLinkStatus {
+    Ok,
+    Dead,
+    Redirected,
+    Paywalled,
+    Soft404,
+    /// Transient or inconclusive failure (timeouts, 429, 5xx, bot walls); never cached.
+    Unverified,
+}
+
+impl ExternalLinkStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Dead => "dead",
+            Self::Redirected => "redirected",
+            Self::Paywalled => "paywalled",
+            Self::Soft404 => "soft_404",
+            Self::Unverified => "unverified",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "ok" => Some(Self::Ok),
+            "dead" => Some(Self::Dead),
+            "redirected" => Some(Self::Redirected),
+            "paywalled" => Some(Self::Paywalled),
+            "soft_404" => Some(Self::Soft404),
+            "unverified" => Some(Self::Unverified),
+            _ => None,
+        }
+    }
+
+    pub fn is_issue(self) -> bool {
+        !matches!(self, Self::Ok | Self::Unverified)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ExternalLinkCheck {
+    pub url: String,
+    pub status: ExternalLinkStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_status: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub final_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub checked_at: String,
+    pub from_cache: bool,
+    /// Local path of a captured copy from a `research archive` manifest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived_copy: Option<String>,
+    pub source_pages: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ExternalLinkReport {
+    pub url_count: usize,
+    pub checked_count: usize,
+    pub cached_count: usize,
+    pub fetched_count: usize,
+    pub status_counts: BTreeMap<String, usize>,
+    pub issue_count: usize,
+    pub archived_issue_count: usize,
+    /// Every non-OK result, issues first, then unverified.
+    pub links: Vec<ExternalLinkCheck>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct IndexBuildSummary {
+    pub build_id: i64,
+    pub built_at: String,
+    pub page_count: usize,
+    pub link_count: usize,
+    pub reference_count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexDiffOptions {
+    /// Older build; the build before `until` when unset.
+    pub since: Option<i64>,
+    /// Newer build; the latest build when unset.
+    pub until: Option<i64>,
+    /// Maximum items listed per change kind; totals are always exact.
+    pub limit: usize,
+}
+
+impl Default for IndexDiffOptions {
+    fn default() -> Self {
+        Self {
+            since: None,
+            until: None,
+            limit: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IndexLinkChange {
+    pub source_title: String,
+    pub target_title: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct IndexCountChange {
+    pub name: String,
+    pub before: usize,
+    pub after: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct IndexDiffSection<T> {
+    pub total: usize,
+    pub items: Vec<T>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct IndexDiffReport {
+    pub from_build: IndexBuildSummary,
+    pub to_build: IndexBuildSummary,
+    pub added_pages: IndexDiffSection<String>,
+    pub removed_pages: IndexDiffSection<String>,
+    pub added_links: IndexDiffSection<IndexLinkChange>,
+    pub removed_links: IndexDiffSection<IndexLinkChange>,
+    pub new_red_links: IndexDiffSection<IndexLinkChange>,
+    pub resolved_red_links: IndexDiffSection<IndexLinkChange>,
+    /// `target_title` is the category.
+    pub added_categories: IndexDiffSection<IndexLinkChange>,
+    pub removed_categories: IndexDiffSection<IndexLinkChange>,
+    pub new_orphans: IndexDiffSection<String>,
+    pub resolved_orphans: IndexDiffSection<String>,
+    pub new_uncategorized: IndexDiffSection<String>,
+    /// Per-page reference count changes; `name` is the page title.
+    pub reference_changes: IndexDiffSection<IndexCountChange>,
+    pub validation_counts: Vec<IndexCountChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslationStatusOptions {
+    /// Restrict to one translation page or to every translation of one base page.
+    pub title: Option<String>,
+    /// Also list current and unmarked translations, not only outdated ones.
+    pub include_all: bool,
+    pub include_diff: bool,
+    pub limit: usize,
+}
+
+impl Default for TranslationStatusOptions {
+    fn default() -> Self {
+        Self {
+            title: None,
+            include_all: false,
+            include_diff: true,
+            limit: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum TranslationSyncStatus {
+    /// The base page changed after the translation was last synced.
+    Outdated,
+    /// No sync marker yet; run `knowledge translations mark` once the translation is current.
+    Unmarked,
+    MissingBase,
+    Current,
+}
+
+impl TranslationSyncStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Outdated => "outdated",
+            Self::Unmarked => "unmarked",
+            Self::MissingBase => "missing_base",
+            Self::Current => "current",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct TranslationStatusEntry {
+    pub title: String,
+    pub base_title: String,
+    pub language: String,
+    pub status: TranslationSyncStatus,
+    /// Base revision recorded in the translation's sync marker, when it was synced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synced_revision: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synced_hash: Option<String>,
+    /// Base revision currently recorded in the sync ledger.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_revision: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_hash: Option<String>,
+    pub lines_added: usize,
+    pub lines_removed: usize,
+    /// Unified diff of the base page since the synced version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff_unavailable: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct TranslationStatusReport {
+    pub translation_count: usize,
+    pub outdated_count: usize,
+    pub unmarked_count: usize,
+    pub missing_base_count: usize,
+    pub current_count: usize,
+    pub entries: Vec<TranslationStatusEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct TranslationSyncMark {
+    pub title: String,
+    pub base_title: String,
+    pub relative_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_revision: Option<i64>,
+    pub base_hash: String,
+    /// False when the translation already carried an identical marker.
+    pub changed: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PageQualityMetric {
+    ProseWords,
+    ReferenceCount,
+    ReferencesPer100Words,
+    UncitedParagraphs,
+    SectionCount,
+    LargestSectionShare,
+    LeadWords,
+    ImageCount,
+    InboundLinks,
+}
+
+impl PageQualityMetric {
+    pub const ALL: [Self; 9] = [
+        Self::ProseWords,
+        Self::ReferenceCount,
+        Self::ReferencesPer100Words,
+        Self::UncitedParagraphs,
+        Self::SectionCount,
+        Self::LargestSectionShare,
+        Self::LeadWords,
+        Self::ImageCount,
+        Self::InboundLinks,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ProseWords => "prose_words",
+            Self::ReferenceCount => "reference_count",
+            Self::ReferencesPer100Words => "references_per_100_words",
+            Self::UncitedParagraphs => "uncited_paragraphs",
+            Self::SectionCount => "section_count",
+            Self::LargestSectionShare => "largest_section_share",
+            Self::LeadWords => "lead_words",
+            Self::ImageCount => "image_count",
+            Self::InboundLinks => "inbound_links",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|metric| metric.as_str() == value.trim())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PageQualityMetrics {
+    pub title: String,
+    pub namespace: String,
+    pub prose_words: usize,
+    pub reference_count: usize,
+    pub references_per_100_words: f64,
+    /// Body paragraphs with prose but no reference; the lead is exempt.
+    pub uncited_paragraphs: usize,
+    pub paragraph_count: usize,
+    pub section_count: usize,
+    /// Section balance: share of body prose words in the largest section.
+    pub largest_section_share: f64,
+    pub lead_words: usize,
+    pub image_count: usize,
+    /// Distinct other pages linking here; `None` when no index is available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbound_links: Option<usize>,
+}
+
+impl PageQualityMetrics {
+    pub fn value(&self, metric: PageQualityMetric) -> f64 {
+        match metric {
+            PageQualityMetric::ProseWords => self.prose_words as f64,
+            PageQualityMetric::ReferenceCount => self.reference_count as f64,
+            PageQualityMetric::ReferencesPer100Words => self.references_per_100_words,
+            PageQualityMetric::UncitedParagraphs => self.uncited_paragraphs as f64,
+            PageQualityMetric::SectionCount => self.section_count as f64,
+            PageQualityMetric::LargestSectionShare => self.largest_section_share,
+            PageQualityMetric::LeadWords => self.lead_words as f64,
+            PageQualityMetric::ImageCount => self.image_count as f64,
+            PageQualityMetric::InboundLinks => self.inbound_links.unwrap_or_default() as f64,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub struct PageQualityThreshold {
+    pub metric: PageQualityMetric,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageQualityOptions {
+    pub title: Option<String>,
+    pub sort: PageQualityMetric,
+    pub descending: bool,
+    /// Pages must satisfy every threshold to be listed.
+    pub thresholds: Vec<PageQualityThreshold>,
+    pub limit: usize,
+}
+
+impl Default for PageQualityOptions {
+    fn default() -> Self {
+        Self {
+            title: None,
+            sort: PageQualityMetric::ProseWords,
+            descending: false,
+            thresholds: Vec::new(),
+            limit: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PageQualityReport {
+    pub sort: PageQualityMetric,
+    pub descending: bool,
+    pub thresholds: Vec<PageQualityThreshold>,
+    pub page_count: usize,
+    pub matched_count: usize,
+    pub pages: Vec<PageQualityMetrics>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WantedPagesOptions {
+    pub limit: usize,
+    /// Mention sentences kept per missing target.
+    pub mention_limit: usize,
+    /// Only report targets linked from at least this many distinct pages.
+    pub min_sources: usize,
+}
+
+impl Default for WantedPagesOptions {
+    fn default() -> Self {
+        Self {
+            limit: 50,
+            mention_limit: 3,
+            min_sources: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct WantedPageMention {
+    pub source_title: String,
+    pub line: usize,
+    pub sentence: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct WantedPage {
+    pub title: String,
+    /// Link occurrences across all linking pages.
+    pub link_count: usize,
+    pub source_count: usize,
+    pub sources: Vec<String>,
+    pub mentions: Vec<WantedPageMention>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct WantedPagesReport {
+    pub wanted_count: usize,
+    pub pages: Vec<WantedPage>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct WantedPageBriefFile {
+    pub title: String,
+    pub path: String,
+    pub local_state: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnusedPagesOptions {
+    /// Namespaces to report; empty means Template, Module and File.
+    pub namespaces: Vec<Namespace>,
+    pub limit: usize,
+}
+
+impl Default for UnusedPagesOptions {
+    fn default() -> Self {
+        Self {
+            namespaces: Vec::new(),
+            limit: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct UnusedPage {
+    pub title: String,
+    pub namespace: String,
+    pub relative_path: String,
+    pub bytes: u64,
+    pub modified_at_unix: Option<u64>,
+    pub modified_at: Option<String>,
+    /// Pages that use this one but are themselves unused; empty when nothing
+    /// references it at all.
+    pub used_only_by: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct UnusedPagesReport {
+    pub candidate_count: usize,
+    pub template_count: usize,
+    pub module_count: usize,
+    pub file_count: usize,
+    pub pages: Vec<UnusedPage>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct UnreliableSourcePage {
+    pub title: String,
+    pub citation_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct UnreliableSourceUsage {
+    pub label: String,
+    pub matcher: String,
+    pub reason: String,
+    pub page_count: usize,
+    pub citation_count: usize,
+    /// Citing pages, most citations first; capped by the query's page limit.
+    pub pages: Vec<UnreliableSourcePage>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct UnreliableSourcesReport {
+    pub rule_count: usize,
+    pub citing_page_count: usize,
+    pub citation_count: usize,
+    pub sources: Vec<UnreliableSourceUsage>,
 }
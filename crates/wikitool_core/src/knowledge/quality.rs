@@ -0,0 +1,218 @@
+use super::prelude::*;
+use crate::wikitext::quality::measure_quality;
+
+pub use super::model::{
+    PageQualityMetric, PageQualityMetrics, PageQualityOptions, PageQualityReport,
+    PageQualityThreshold,
+};
+
+/// Content metrics for one page; `inbound_links` is filled from the link
+/// graph by the caller when an index is available.
+pub(crate) fn page_quality_metrics(
+    title: &str,
+    namespace: &str,
+    content: &str,
+    inbound_links: Option<usize>,
+) -> PageQualityMetrics {
+    let measure = measure_quality(content);
+    let references_per_100_words = if measure.prose_words == 0 {
+        0.0
+    } else {
+        measure.reference_count as f64 * 100.0 / measure.prose_words as f64
+    };
+    PageQualityMetrics {
+        title: title.to_string(),
+        namespace: namespace.to_string(),
+        prose_words: measure.prose_words,
+        reference_count: measure.reference_count,
+        references_per_100_words,
+        uncited_paragraphs: measure.uncited_paragraphs,
+        paragraph_count: measure.paragraph_count,
+        section_count: measure.section_count,
+        largest_section_share: measure.largest_section_share,
+        lead_words: measure.lead_words,
+        image_count: measure.image_count,
+        inbound_links,
+    }
+}
+
+/// Store metrics gathered during the index rebuild. Runs inside the rebuild
+/// transaction after links are inserted so inbound counts are complete.
+pub(crate) fn store_page_quality(
+    connection: &Connection,
+    pages: &[(String, PageQualityMetrics)],
+) -> Result<usize> {
+    let inbound = load_inbound_link_counts(connection)?;
+    let mut statement = connection
+        .prepare(
+            "INSERT OR REPLACE INTO indexed_page_quality (
+                source_relative_path,
+                source_title,
+                source_namespace,
+                prose_words,
+                reference_count,
+                references_per_100_words,
+                uncited_paragraphs,
+                paragraph_count,
+                section_count,
+                largest_section_share,
+                lead_words,
+                image_count,
+                inbound_links
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+        )
+        .context("failed to prepare indexed_page_quality insert")?;
+    for (relative_path, metrics) in pages {
+        let inbound_links = inbound.get(&metrics.title).copied().unwrap_or_default();
+        statement
+            .execute(params![
+                relative_path,
+                metrics.title,
+                metrics.namespace,
+                to_i64(metrics.prose_words)?,
+                to_i64(metrics.reference_count)?,
+                metrics.references_per_100_words,
+                to_i64(metrics.uncited_paragraphs)?,
+                to_i64(metrics.paragraph_count)?,
+                to_i64(metrics.section_count)?,
+                metrics.largest_section_share,
+                to_i64(metrics.lead_words)?,
+                to_i64(metrics.image_count)?,
+                to_i64(inbound_links)?,
+            ])
+            .with_context(|| format!("failed to insert quality metrics for {relative_path}"))?;
+    }
+    Ok(pages.len())
+}
+
+/// Distinct other non-redirect pages linking to `title`.
+pub(crate) fn load_inbound_link_count(connection: &Connection, title: &str) -> Result<usize> {
+    let count = connection
+        .query_row(
+            "SELECT COUNT(DISTINCT source_title)
+             FROM indexed_links
+             WHERE target_title = ?1 AND is_category_membership = 0 AND source_title <> ?1
+               AND source_title NOT IN (SELECT title FROM indexed_pages WHERE is_redirect = 1)",
+            [title],
+            |row| row.get::<_, i64>(0),
+        )
+        .with_context(|| format!("failed to count inbound links for {title}"))?;
+    Ok(usize::try_from(count).unwrap_or_default())
+}
+
+fn load_inbound_link_counts(connection: &Connection) -> Result<BTreeMap<String, usize>> {
+    let mut statement = connection
+        .prepare(
+            "SELECT target_title, COUNT(DISTINCT source_title)
+             FROM indexed_links
+             WHERE is_category_membership = 0 AND source_title <> target_title
+               AND source_title NOT IN (SELECT title FROM indexed_pages WHERE is_redirect = 1)
+             GROUP BY target_title",
+        )
+        .context("failed to prepare inbound link count query")?;
+    let rows = statement
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })
+        .context("failed to run inbound link count query")?;
+    let mut out = BTreeMap::new();
+    for row in rows {
+        let (title, count) = row.context("failed to decode inbound link count row")?;
+        out.insert(title, usize::try_from(count).unwrap_or_default());
+    }
+    Ok(out)
+}
+
+pub fn query_page_quality(
+    paths: &ResolvedPaths,
+    options: &PageQualityOptions,
+) -> Result<Option<PageQualityReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    query_page_quality_for_connection(&connection, options).map(Some)
+}
+
+pub(crate) fn query_page_quality_for_connection(
+    connection: &Connection,
+    options: &PageQualityOptions,
+) -> Result<PageQualityReport> {
+    let title = match &options.title {
+        Some(title) => Some(
+            load_page_record(connection, title)?
+                .map(|record| record.title)
+                .with_context(|| format!("page is not indexed: {title}"))?,
+        ),
+        None => None,
+    };
+
+    let mut pages = Vec::new();
+    if table_exists(connection, "indexed_page_quality")? {
+        let mut statement = connection
+            .prepare(
+                "SELECT source_title, source_namespace, prose_words, reference_count,
+                        references_per_100_words, uncited_paragraphs, paragraph_count,
+                        section_count, largest_section_share, lead_words, image_count,
+                        inbound_links
+                 FROM indexed_page_quality
+                 WHERE ?1 IS NULL OR source_title = ?1",
+            )
+            .context("failed to prepare page quality query")?;
+        let rows = statement
+            .query_map([title.as_deref()], |row| {
+                Ok(PageQualityMetrics {
+                    title: row.get(0)?,
+                    namespace: row.get(1)?,
+                    prose_words: row.get::<_, i64>(2)? as usize,
+                    reference_count: row.get::<_, i64>(3)? as usize,
+                    references_per_100_words: row.get(4)?,
+                    uncited_paragraphs: row.get::<_, i64>(5)? as usize,
+                    paragraph_count: row.get::<_, i64>(6)? as usize,
+                    section_count: row.get::<_, i64>(7)? as usize,
+                    largest_section_share: row.get(8)?,
+                    lead_words: row.get::<_, i64>(9)? as usize,
+                    image_count: row.get::<_, i64>(10)? as usize,
+                    inbound_links: Some(row.get::<_, i64>(11)? as usize),
+                })
+            })
+            .context("failed to run page quality query")?;
+        for row in rows {
+            pages.push(row.context("failed to decode page quality row")?);
+        }
+    }
+
+    let page_count = pages.len();
+    pages.retain(|page| {
+        options.thresholds.iter().all(|threshold| {
+            let value = page.value(threshold.metric);
+            threshold.min.is_none_or(|min| value >= min)
+                && threshold.max.is_none_or(|max| value <= max)
+        })
+    });
+    let matched_count = pages.len();
+    pages.sort_by(|left, right| {
+        let ordering = left
+            .value(options.sort)
+            .total_cmp(&right.value(options.sort));
+        let ordering = if options.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+        ordering.then_with(|| left.title.cmp(&right.title))
+    });
+    pages.truncate(options.limit);
+    Ok(PageQualityReport {
+        sort: options.sort,
+        descending: options.descending,
+        thresholds: options.thresholds.clone(),
+        page_count,
+        matched_count,
+        pages,
+    })
+}
+
+fn to_i64(value: usize) -> Result<i64> {
+    i64::try_from(value).context("quality metric does not fit into i64")
+}
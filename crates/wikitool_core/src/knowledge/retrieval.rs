@@ -33,7 +33,7 @@ pub(crate) use chunks::{
 pub(crate) use context::{load_context_chunks_for_bundle, load_section_records_for_bundle};
 pub(crate) use rerank::retrieve_reranked_chunks_across_pages;
 #[cfg(test)]
-pub(crate) use rerank::section_authoring_bias;
+pub(crate) use rerank::{rerank_retrieved_chunks, section_authoring_bias};
 pub(crate) use search::{collapse_search_hits, query_search_fts, query_search_like};
 
 use chunks::{
@@ -1,4 +1,5 @@
 use super::*;
+use crate::knowledge::centrality::load_pagerank_scores;
 
 pub(crate) fn section_authoring_bias(section_heading: Option<&str>, chunk_text: &str) -> i64 {
     let heading = section_heading.unwrap_or_default().to_ascii_lowercase();
@@ -182,9 +183,17 @@ pub(crate) fn rerank_retrieved_chunks(
         })
         .collect::<Vec<_>>();
 
+    let centrality = |chunk: &RetrievedChunk| {
+        signals
+            .centrality_page_scores
+            .get(&chunk.source_title.to_ascii_lowercase())
+            .copied()
+            .unwrap_or(0.0)
+    };
     scored.sort_by(|(left_score, left_chunk), (right_score, right_chunk)| {
         right_score
             .cmp(left_score)
+            .then_with(|| centrality(right_chunk).total_cmp(&centrality(left_chunk)))
             .then_with(|| left_chunk.source_title.cmp(&right_chunk.source_title))
             .then_with(|| left_chunk.section_heading.cmp(&right_chunk.section_heading))
             .then_with(|| left_chunk.chunk_text.cmp(&right_chunk.chunk_text))
@@ -199,7 +208,7 @@ pub(crate) fn retrieve_reranked_chunks_across_pages(
     query_terms: &[String],
     plan: ChunkRetrievalPlan,
     related_page_titles: &[String],
-    signals: ChunkRerankSignals,
+    mut signals: ChunkRerankSignals,
 ) -> Result<LocalChunkAcrossPagesResult> {
     let max_chunks = plan.limit.max(1);
     let max_tokens = plan.token_budget.max(1);
@@ -221,6 +230,10 @@ pub(crate) fn retrieve_reranked_chunks_across_pages(
         .into_iter()
         .filter_map(|chunk| sanitize_chunk_for_audience(chunk, audience))
         .collect::<Vec<_>>();
+    signals.centrality_page_scores = load_pagerank_scores(
+        connection,
+        candidates.iter().map(|chunk| chunk.source_title.as_str()),
+    )?;
     let reranked = rerank_retrieved_chunks(candidates, query, query_terms, &signals, audience);
     let chunks = select_retrieved_chunks(
         reranked,
@@ -0,0 +1,404 @@
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+use super::prelude::*;
+use crate::support::format_iso8601_utc;
+
+pub use super::model::{
+    IndexBuildSummary, IndexCountChange, IndexDiffOptions, IndexDiffReport, IndexDiffSection,
+    IndexLinkChange,
+};
+
+/// Snapshots kept after a rebuild; older builds are pruned.
+const SNAPSHOT_RETENTION: i64 = 30;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct IndexSnapshot {
+    pages: BTreeMap<String, SnapshotPage>,
+    red_links: BTreeSet<(String, String)>,
+    double_redirects: BTreeSet<String>,
+    uncategorized_pages: BTreeSet<String>,
+    orphan_pages: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SnapshotPage {
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    links: BTreeSet<String>,
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    categories: BTreeSet<String>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    references: usize,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+/// Record the structural snapshot of the freshly rebuilt index and prune old
+/// builds. Returns the new build id.
+pub(crate) fn record_index_snapshot(connection: &Connection) -> Result<i64> {
+    let snapshot = collect_snapshot(connection)?;
+    let link_count = snapshot
+        .pages
+        .values()
+        .map(|page| page.links.len() + page.categories.len())
+        .sum::<usize>();
+    let reference_count = snapshot
+        .pages
+        .values()
+        .map(|page| page.references)
+        .sum::<usize>();
+    connection
+        .execute(
+            "INSERT INTO index_build_snapshots (
+                built_at_unix, page_count, link_count, reference_count, snapshot_json
+             ) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                i64::try_from(unix_timestamp()?)
+                    .context("snapshot timestamp does not fit into i64")?,
+                i64::try_from(snapshot.pages.len()).context("page count does not fit into i64")?,
+                i64::try_from(link_count).context("link count does not fit into i64")?,
+                i64::try_from(reference_count).context("reference count does not fit into i64")?,
+                serde_json::to_string(&snapshot).context("failed to serialize index snapshot")?,
+            ],
+        )
+        .context("failed to record index build snapshot")?;
+    let build_id = connection.last_insert_rowid();
+    connection
+        .execute(
+            "DELETE FROM index_build_snapshots WHERE build_id <= ?1",
+            [build_id - SNAPSHOT_RETENTION],
+        )
+        .context("failed to prune old index build snapshots")?;
+    Ok(build_id)
+}
+
+pub(crate) fn latest_index_build_id(connection: &Connection) -> Result<Option<i64>> {
+    if !table_exists(connection, "index_build_snapshots")? {
+        return Ok(None);
+    }
+    connection
+        .query_row(
+            "SELECT MAX(build_id) FROM index_build_snapshots",
+            [],
+            |row| row.get::<_, Option<i64>>(0),
+        )
+        .context("failed to load latest index build id")
+}
+
+pub fn list_index_builds(paths: &ResolvedPaths) -> Result<Option<Vec<IndexBuildSummary>>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    let mut statement = connection
+        .prepare(
+            "SELECT build_id, built_at_unix, page_count, link_count, reference_count
+             FROM index_build_snapshots
+             ORDER BY build_id DESC",
+        )
+        .context("failed to prepare index build list query")?;
+    let rows = statement
+        .query_map([], decode_build_summary)
+        .context("failed to run index build list query")?;
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row.context("failed to decode index build row")?);
+    }
+    Ok(Some(out))
+}
+
+pub fn diff_index_builds(
+    paths: &ResolvedPaths,
+    options: &IndexDiffOptions,
+) -> Result<Option<IndexDiffReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    diff_index_builds_for_connection(&connection, options).map(Some)
+}
+
+pub(crate) fn diff_index_builds_for_connection(
+    connection: &Connection,
+    options: &IndexDiffOptions,
+) -> Result<IndexDiffReport> {
+    let until = match options.until {
+        Some(build_id) => build_id,
+        None => latest_index_build_id(connection)?
+            .context("no index build snapshots recorded yet (run `wikitool knowledge build`)")?,
+    };
+    let since = match options.since {
+        Some(build_id) => build_id,
+        None => connection
+            .query_row(
+                "SELECT MAX(build_id) FROM index_build_snapshots WHERE build_id < ?1",
+                [until],
+                |row| row.get::<_, Option<i64>>(0),
+            )
+            .context("failed to load previous index build id")?
+            .with_context(|| format!("no index build recorded before build {until}"))?,
+    };
+    if since >= until {
+        bail!("--since build {since} must be older than build {until}");
+    }
+    let (from_build, before) = load_snapshot(connection, since)?;
+    let (to_build, after) = load_snapshot(connection, until)?;
+    let limit = options.limit;
+
+    let added_pages = key_difference(&after.pages, &before.pages);
+    let removed_pages = key_difference(&before.pages, &after.pages);
+    let empty = SnapshotPage::default();
+    let mut added_links = Vec::new();
+    let mut removed_links = Vec::new();
+    let mut added_categories = Vec::new();
+    let mut removed_categories = Vec::new();
+    let mut reference_changes = Vec::new();
+    let titles = before
+        .pages
+        .keys()
+        .chain(after.pages.keys())
+        .collect::<BTreeSet<_>>();
+    for title in titles {
+        let old = before.pages.get(title).unwrap_or(&empty);
+        let new = after.pages.get(title).unwrap_or(&empty);
+        added_links.extend(link_changes(title, new.links.difference(&old.links)));
+        removed_links.extend(link_changes(title, old.links.difference(&new.links)));
+        added_categories.extend(link_changes(
+            title,
+            new.categories.difference(&old.categories),
+        ));
+        removed_categories.extend(link_changes(
+            title,
+            old.categories.difference(&new.categories),
+        ));
+        if old.references != new.references {
+            reference_changes.push(IndexCountChange {
+                name: title.clone(),
+                before: old.references,
+                after: new.references,
+            });
+        }
+    }
+    reference_changes.sort_by(|left, right| {
+        let left_delta = left.after.abs_diff(left.before);
+        let right_delta = right.after.abs_diff(right.before);
+        right_delta
+            .cmp(&left_delta)
+            .then(left.name.cmp(&right.name))
+    });
+
+    let new_red_links = after
+        .red_links
+        .difference(&before.red_links)
+        .map(|(source, target)| IndexLinkChange {
+            source_title: source.clone(),
+            target_title: target.clone(),
+        })
+        .collect::<Vec<_>>();
+    let resolved_red_links = before
+        .red_links
+        .difference(&after.red_links)
+        .map(|(source, target)| IndexLinkChange {
+            source_title: source.clone(),
+            target_title: target.clone(),
+        })
+        .collect::<Vec<_>>();
+    let validation_counts = vec![
+        count_change("pages", before.pages.len(), after.pages.len()),
+        count_change("red_links", before.red_links.len(), after.red_links.len()),
+        count_change(
+            "double_redirects",
+            before.double_redirects.len(),
+            after.double_redirects.len(),
+        ),
+        count_change(
+            "uncategorized_pages",
+            before.uncategorized_pages.len(),
+            after.uncategorized_pages.len(),
+        ),
+        count_change(
+            "orphan_pages",
+            before.orphan_pages.len(),
+            after.orphan_pages.len(),
+        ),
+        count_change(
+            "references",
+            from_build.reference_count,
+            to_build.reference_count,
+        ),
+    ];
+
+    Ok(IndexDiffReport {
+        added_pages: section(added_pages, limit),
+        removed_pages: section(removed_pages, limit),
+        added_links: section(added_links, limit),
+        removed_links: section(removed_links, limit),
+        new_red_links: section(new_red_links, limit),
+        resolved_red_links: section(resolved_red_links, limit),
+        added_categories: section(added_categories, limit),
+        removed_categories: section(removed_categories, limit),
+        new_orphans: section(
+            set_difference(&after.orphan_pages, &before.orphan_pages),
+            limit,
+        ),
+        resolved_orphans: section(
+            set_difference(&before.orphan_pages, &after.orphan_pages),
+            limit,
+        ),
+        new_uncategorized: section(
+            set_difference(&after.uncategorized_pages, &before.uncategorized_pages),
+            limit,
+        ),
+        reference_changes: section(reference_changes, limit),
+        validation_counts,
+        from_build,
+        to_build,
+    })
+}
+
+fn collect_snapshot(connection: &Connection) -> Result<IndexSnapshot> {
+    let mut snapshot = IndexSnapshot::default();
+    let mut statement = connection
+        .prepare("SELECT title FROM indexed_pages")
+        .context("failed to prepare snapshot page query")?;
+    let rows = statement
+        .query_map([], |row| row.get::<_, String>(0))
+        .context("failed to run snapshot page query")?;
+    for row in rows {
+        snapshot.pages.insert(
+            row.context("failed to decode snapshot page row")?,
+            SnapshotPage::default(),
+        );
+    }
+
+    let mut statement = connection
+        .prepare("SELECT source_title, target_title, is_category_membership FROM indexed_links")
+        .context("failed to prepare snapshot link query")?;
+    let rows = statement
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)? != 0,
+            ))
+        })
+        .context("failed to run snapshot link query")?;
+    for row in rows {
+        let (source, target, is_category) = row.context("failed to decode snapshot link row")?;
+        let page = snapshot.pages.entry(source).or_default();
+        if is_category {
+            page.categories.insert(target);
+        } else {
+            page.links.insert(target);
+        }
+    }
+
+    if table_exists(connection, "indexed_page_references")? {
+        let mut statement = connection
+            .prepare(
+                "SELECT source_title, COUNT(*)
+                 FROM indexed_page_references
+                 GROUP BY source_title",
+            )
+            .context("failed to prepare snapshot reference query")?;
+        let rows = statement
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })
+            .context("failed to run snapshot reference query")?;
+        for row in rows {
+            let (title, count) = row.context("failed to decode snapshot reference row")?;
+            snapshot.pages.entry(title).or_default().references =
+                usize::try_from(count).unwrap_or_default();
+        }
+    }
+
+    snapshot.red_links = query_broken_links_for_connection(connection)?
+        .into_iter()
+        .map(|issue| (issue.source_title, issue.target_title))
+        .collect();
+    snapshot.double_redirects = query_double_redirects_for_connection(connection)?
+        .into_iter()
+        .map(|issue| issue.title)
+        .collect();
+    snapshot.uncategorized_pages = query_uncategorized_pages_for_connection(connection)?
+        .into_iter()
+        .collect();
+    snapshot.orphan_pages = query_orphans_for_connection(connection)?
+        .into_iter()
+        .collect();
+    Ok(snapshot)
+}
+
+fn load_snapshot(
+    connection: &Connection,
+    build_id: i64,
+) -> Result<(IndexBuildSummary, IndexSnapshot)> {
+    let row = connection
+        .query_row(
+            "SELECT build_id, built_at_unix, page_count, link_count, reference_count, snapshot_json
+             FROM index_build_snapshots
+             WHERE build_id = ?1",
+            [build_id],
+            |row| Ok((decode_build_summary(row)?, row.get::<_, String>(5)?)),
+        )
+        .optional()
+        .with_context(|| format!("failed to load index build {build_id}"))?;
+    let Some((summary, snapshot_json)) = row else {
+        bail!("index build {build_id} is not recorded (see `wikitool knowledge diff --list`)");
+    };
+    let snapshot = serde_json::from_str(&snapshot_json)
+        .with_context(|| format!("failed to decode snapshot for index build {build_id}"))?;
+    Ok((summary, snapshot))
+}
+
+fn decode_build_summary(row: &rusqlite::Row<'_>) -> rusqlite::Result<IndexBuildSummary> {
+    Ok(IndexBuildSummary {
+        build_id: row.get(0)?,
+        built_at: format_iso8601_utc(u64::try_from(row.get::<_, i64>(1)?).unwrap_or_default()),
+        page_count: row.get::<_, i64>(2)? as usize,
+        link_count: row.get::<_, i64>(3)? as usize,
+        reference_count: row.get::<_, i64>(4)? as usize,
+    })
+}
+
+fn key_difference(
+    left: &BTreeMap<String, SnapshotPage>,
+    right: &BTreeMap<String, SnapshotPage>,
+) -> Vec<String> {
+    left.keys()
+        .filter(|title| !right.contains_key(*title))
+        .cloned()
+        .collect()
+}
+
+fn set_difference(left: &BTreeSet<String>, right: &BTreeSet<String>) -> Vec<String> {
+    left.difference(right).cloned().collect()
+}
+
+fn link_changes<'a>(
+    source: &str,
+    targets: impl Iterator<Item = &'a String>,
+) -> impl Iterator<Item = IndexLinkChange> {
+    let source = source.to_string();
+    targets.map(move |target| IndexLinkChange {
+        source_title: source.clone(),
+        target_title: target.clone(),
+    })
+}
+
+fn count_change(name: &str, before: usize, after: usize) -> IndexCountChange {
+    IndexCountChange {
+        name: name.to_string(),
+        before,
+        after,
+    }
+}
+
+fn section<T>(mut items: Vec<T>, limit: usize) -> IndexDiffSection<T> {
+    let total = items.len();
+    items.truncate(limit);
+    IndexDiffSection { total, items }
+}
@@ -0,0 +1,127 @@
+use super::prelude::*;
+use crate::support::civil_from_days;
+use crate::wikitext::staleness::find_temporal_claims;
+
+pub use super::model::{StaleClaim, StaleClaimOptions, StaleClaimPage, StaleClaimReport};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+pub fn query_stale_claims(
+    paths: &ResolvedPaths,
+    options: &StaleClaimOptions,
+) -> Result<Option<StaleClaimReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    let today = (unix_timestamp()? / SECONDS_PER_DAY) as i64;
+    query_stale_claims_for_connection(paths, &connection, options, today).map(Some)
+}
+
+/// Scan indexed pages for dated and time-relative statements and rank pages by
+/// stale-claim count. `today` is days since the UNIX epoch.
+pub(crate) fn query_stale_claims_for_connection(
+    paths: &ResolvedPaths,
+    connection: &Connection,
+    options: &StaleClaimOptions,
+    today: i64,
+) -> Result<StaleClaimReport> {
+    let title = match &options.title {
+        Some(title) => Some(
+            load_page_record(connection, title)?
+                .map(|record| record.title)
+                .with_context(|| format!("page is not indexed: {title}"))?,
+        ),
+        None => None,
+    };
+    let namespace = match &title {
+        Some(_) => None,
+        None => options.namespace.clone(),
+    };
+
+    let mut statement = connection
+        .prepare(
+            "SELECT relative_path, title, namespace
+             FROM indexed_pages
+             WHERE is_redirect = 0
+               AND (?1 IS NULL OR title = ?1)
+               AND (?2 IS NULL OR namespace = ?2)
+             ORDER BY title ASC",
+        )
+        .context("failed to prepare stale claim page query")?;
+    let rows = statement
+        .query_map(params![title, namespace], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })
+        .context("failed to query pages for stale claims")?;
+
+    let mut scanned_page_count = 0usize;
+    let mut pages = Vec::new();
+    for row in rows {
+        let (relative_path, page_title, page_namespace) =
+            row.context("failed to decode stale claim page row")?;
+        let absolute = absolute_path_from_relative(paths, &relative_path);
+        let content = fs::read_to_string(&absolute).with_context(|| {
+            format!("failed to read indexed source file {}", absolute.display())
+        })?;
+        scanned_page_count += 1;
+
+        let claims = find_temporal_claims(&content, today)
+            .into_iter()
+            .filter(|claim| claim.is_stale(options.stale_after_days))
+            .map(|claim| StaleClaim {
+                kind: claim.kind.as_str().to_string(),
+                line: content[..claim.start].matches('\n').count() + 1,
+                text: claim.text,
+                claim_date: claim.claim_day.map(format_civil_day),
+                age_days: claim.age_days,
+            })
+            .collect::<Vec<_>>();
+        if claims.is_empty() {
+            continue;
+        }
+        let dated_claim_count = claims
+            .iter()
+            .filter(|claim| claim.age_days.is_some())
+            .count();
+        pages.push(StaleClaimPage {
+            title: page_title,
+            namespace: page_namespace,
+            stale_claim_count: claims.len(),
+            dated_claim_count,
+            undated_claim_count: claims.len() - dated_claim_count,
+            oldest_age_days: claims.iter().filter_map(|claim| claim.age_days).max(),
+            claims,
+        });
+    }
+
+    pages.sort_by(|left, right| {
+        right
+            .stale_claim_count
+            .cmp(&left.stale_claim_count)
+            .then(right.oldest_age_days.cmp(&left.oldest_age_days))
+            .then(left.title.cmp(&right.title))
+    });
+    let stale_page_count = pages.len();
+    let stale_claim_count = pages.iter().map(|page| page.stale_claim_count).sum();
+    pages.truncate(options.limit);
+
+    Ok(StaleClaimReport {
+        today: format_civil_day(today),
+        stale_after_days: options.stale_after_days,
+        namespace,
+        scanned_page_count,
+        stale_page_count,
+        stale_claim_count,
+        pages,
+    })
+}
+
+fn format_civil_day(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{year:04}-{month:02}-{day:02}")
+}
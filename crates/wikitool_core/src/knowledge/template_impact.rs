@@ -0,0 +1,121 @@
+use std::collections::VecDeque;
+
+use anyhow::bail;
+
+use super::prelude::*;
+use crate::graph::{GraphFilter, GraphKind, NodeId, build_graph};
+use crate::knowledge::templates::{normalize_module_lookup_title, normalize_template_lookup_title};
+
+pub use super::model::{TemplateImpactPage, TemplateImpactReport};
+
+/// Walk transclusion, `#invoke`, and redirect edges backwards from a template or
+/// module to every page that depends on it, directly or through other templates.
+/// `include_all_namespaces` keeps intermediate templates and modules in `pages`;
+/// namespace counts always cover every affected page.
+pub fn query_template_impact(
+    paths: &ResolvedPaths,
+    title: &str,
+    include_all_namespaces: bool,
+) -> Result<Option<TemplateImpactReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    query_template_impact_for_connection(&connection, title, include_all_namespaces).map(Some)
+}
+
+pub(crate) fn query_template_impact_for_connection(
+    connection: &Connection,
+    title: &str,
+    include_all_namespaces: bool,
+) -> Result<TemplateImpactReport> {
+    let lookup_title = normalize_impact_target_title(title);
+    let transclusion = build_graph(connection, GraphKind::Transclusion, &GraphFilter::default())?;
+    let redirects = build_graph(connection, GraphKind::Redirects, &GraphFilter::default())?;
+    let Some(target) = transclusion.node_by_title(&lookup_title) else {
+        bail!("template impact target is not indexed: {lookup_title}");
+    };
+
+    // Both graphs load nodes from the same ordered page query, so node ids line up.
+    let mut dependents = vec![Vec::<NodeId>::new(); transclusion.node_count()];
+    for edge in transclusion.edges.iter().chain(redirects.edges.iter()) {
+        if edge.from != edge.to {
+            dependents[edge.to.0 as usize].push(edge.from);
+        }
+    }
+    for list in &mut dependents {
+        list.sort();
+        list.dedup();
+    }
+
+    let mut next_hop = BTreeMap::<NodeId, NodeId>::new();
+    let mut depths = BTreeMap::from([(target, 0usize)]);
+    let mut queue = VecDeque::from([target]);
+    while let Some(current) = queue.pop_front() {
+        let depth = depths[&current];
+        for &dependent in &dependents[current.0 as usize] {
+            if depths.contains_key(&dependent) {
+                continue;
+            }
+            depths.insert(dependent, depth + 1);
+            next_hop.insert(dependent, current);
+            queue.push_back(dependent);
+        }
+    }
+    depths.remove(&target);
+
+    let content_namespace = Namespace::Main.as_str();
+    let mut namespace_counts = BTreeMap::<String, usize>::new();
+    let mut pages = Vec::new();
+    for (&node, &depth) in &depths {
+        let page = &transclusion.nodes[node.0 as usize];
+        *namespace_counts.entry(page.namespace.clone()).or_default() += 1;
+        if !include_all_namespaces && page.namespace != content_namespace {
+            continue;
+        }
+        let mut dependency_path = vec![page.title.clone()];
+        let mut current = node;
+        while let Some(&hop) = next_hop.get(&current) {
+            dependency_path.push(transclusion.nodes[hop.0 as usize].title.clone());
+            current = hop;
+        }
+        pages.push(TemplateImpactPage {
+            title: page.title.clone(),
+            namespace: page.namespace.clone(),
+            depth,
+            dependency_path,
+        });
+    }
+    pages.sort_by(|left, right| {
+        left.depth
+            .cmp(&right.depth)
+            .then_with(|| left.title.cmp(&right.title))
+    });
+
+    let target_node = &transclusion.nodes[target.0 as usize];
+    Ok(TemplateImpactReport {
+        target_title: target_node.title.clone(),
+        target_namespace: target_node.namespace.clone(),
+        affected_count: depths.len(),
+        content_page_count: namespace_counts
+            .get(content_namespace)
+            .copied()
+            .unwrap_or(0),
+        direct_count: depths.values().filter(|depth| **depth == 1).count(),
+        max_depth: depths.values().copied().max().unwrap_or(0),
+        namespace_counts,
+        pages,
+    })
+}
+
+fn normalize_impact_target_title(title: &str) -> String {
+    let trimmed = title.trim();
+    if trimmed
+        .get(..7)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("Module:"))
+    {
+        normalize_module_lookup_title(trimmed)
+    } else {
+        normalize_template_lookup_title(trimmed)
+    }
+}
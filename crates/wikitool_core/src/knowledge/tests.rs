@@ -14,16 +14,31 @@ use crate::authoring::{
 use crate::content_store::parsing::{
     extract_first_url, extract_media_records, extract_module_invocations,
     extract_reference_records, extract_template_invocations, extract_wikilinks,
-    extract_wikilinks_for_namespace,
+    extract_wikilinks_for_namespace, open_indexed_connection,
 };
 use crate::filesystem::{Namespace, ScanOptions};
+use crate::graph::GraphKind;
 use crate::knowledge::authoring::{
     AuthoringContractProfile, AuthoringKnowledgePack, AuthoringKnowledgePackOptions,
     AuthoringPayloadMode, build_authoring_knowledge_pack,
 };
+use crate::knowledge::categories::{CategoryTreeOptions, inspect_category_tree};
+use crate::knowledge::centrality::{
+    PageCentralityOptions, PageCentralitySort, query_page_centrality,
+};
+use crate::knowledge::consistency::{FactConsistencyOptions, check_fact_consistency};
 use crate::knowledge::content_index::{load_stored_index_stats, rebuild_index};
+use crate::knowledge::external_links::{ExternalLinkCheckOptions, check_external_links};
+use crate::knowledge::facts::{InfoboxFactOptions, query_infobox_facts};
+use crate::knowledge::graph_export::{GraphExportOptions, build_graph_export};
 use crate::knowledge::inspect::{
-    BrokenLinkIssue, query_backlinks, query_empty_categories, query_orphans, run_validation_checks,
+    BrokenLinkIssue, BrokenRedirectIssue, MissingAnchorIssue, MissingCategoryPageIssue,
+    MissingFileIssue, OversizedPageIssue, RedirectBypassIssue, ValidationCategory,
+    ValidationOptions, query_backlinks, query_empty_categories, query_orphans,
+    run_validation_checks, run_validation_checks_with_options,
+};
+use crate::knowledge::quality::{
+    PageQualityMetric, PageQualityOptions, PageQualityThreshold, query_page_quality,
 };
 use crate::knowledge::references::{
     ReferenceAuditFilters, ReferenceDuplicateKind, inspect_reference_duplicates,
@@ -33,15 +48,32 @@ use crate::knowledge::retrieval::{
     LocalChunkAcrossRetrieval, LocalChunkRetrieval, build_local_context, query_search_local,
     retrieve_local_context_chunks, retrieve_local_context_chunks_across_pages,
 };
+use crate::knowledge::snapshots::{
+    IndexDiffOptions, IndexLinkChange, diff_index_builds, list_index_builds,
+};
+use crate::knowledge::staleness::{StaleClaimOptions, query_stale_claims_for_connection};
+use crate::knowledge::template_impact::query_template_impact;
 use crate::knowledge::templates::{
     ActiveTemplateCatalogLookup, TemplateReferenceLookup, query_active_template_catalog,
     query_template_reference,
 };
+use crate::knowledge::translations::{
+    TranslationStatusOptions, TranslationSyncStatus, mark_translation_synced,
+    query_translation_status,
+};
+use crate::knowledge::unreliable::query_unreliable_sources;
+use crate::knowledge::unused::{UnusedPagesOptions, query_unused_pages};
+use crate::knowledge::wanted::{
+    WantedPagesOptions, brief_file_stem, query_wanted_pages, write_wanted_page_briefs,
+};
 use crate::profile::{
     AuthoringRules, CategoryRules, CitationRules, CitationTemplateRule, GoldenSetRules,
-    InfoboxPreference, LintRules, ProfileOverlay, RemiliaRules, sync_template_catalog_with_overlay,
+    InfoboxPreference, LintRules, ProfileOverlay, RemiliaRules, UnreliableSourceRule,
+    sync_template_catalog_with_overlay,
 };
 use crate::runtime::{ResolvedPaths, ValueSource};
+use crate::schema::open_initialized_database_connection;
+use crate::support::{compute_wiki_sync_hash, days_from_civil, normalize_path};
 
 fn write_file(path: &Path, content: &str) {
     let parent = path.parent().expect("parent");
@@ -125,6 +157,7 @@ fn test_profile_overlay() -> ProfileOverlay {
             forbid_curly_quotes: true,
             forbid_placeholder_fragments: Vec::new(),
             proper_nouns: Vec::new(),
+            phrase_rewrites: Vec::new(),
         },
         golden_set: GoldenSetRules {
             article_corpus_available: false,
@@ -160,142 +193,1401 @@ fn extract_wikilinks_skips_parser_placeholder_targets() {
     assert_eq!(links[0].target_title, "Alpha");
 }
 
-#[test]
-fn namespace_aware_wikilinks_skip_non_rendered_template_and_module_regions() {
-    let template_content = "[[Visible]] <!-- [[Commented]] --> <noinclude>[[Doc only]]</noinclude> <templatedata>{\"params\":{\"[[Pseudo]]\":{}}}</templatedata>";
-    let links = extract_wikilinks_for_namespace(template_content, Namespace::Template.as_str());
+#[test]
+fn namespace_aware_wikilinks_skip_non_rendered_template_and_module_regions() {
+    let template_content = "[[Visible]] <!-- [[Commented]] --> <noinclude>[[Doc only]]</noinclude> <templatedata>{\"params\":{\"[[Pseudo]]\":{}}}</templatedata>";
+    let links = extract_wikilinks_for_namespace(template_content, Namespace::Template.as_str());
+
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].target_title, "Visible");
+
+    let module_content = "local docs = --[[ [[Not a page]] ]] return {}";
+    assert!(extract_wikilinks_for_namespace(module_content, Namespace::Module.as_str()).is_empty());
+}
+
+#[test]
+fn rebuild_index_persists_scan_rows() {
+    let temp = tempdir().expect("tempdir");
+    let project_root: PathBuf = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Alpha.wiki"),
+        "Alpha article",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Category").join("Foo.wiki"),
+        "#REDIRECT [[Category:Bar]]",
+    );
+    write_file(
+        &paths
+            .templates_dir
+            .join("navbox")
+            .join("Module_Navbar")
+            .join("configuration.lua"),
+        "return {}",
+    );
+
+    let report = rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+    assert!(paths.db_path.exists());
+    assert!(!report.unchanged);
+    assert_eq!(report.inserted_rows, 3);
+    assert_eq!(report.scan.total_files, 3);
+    assert_eq!(report.scan.redirects, 1);
+
+    let stored = load_stored_index_stats(&paths)
+        .expect("load stats")
+        .expect("stats must exist");
+    assert_eq!(stored.indexed_rows, 3);
+    assert_eq!(stored.redirects, 1);
+    assert_eq!(
+        stored.by_namespace,
+        BTreeMap::from([
+            (Namespace::Category.as_str().to_string(), 1usize),
+            (Namespace::Main.as_str().to_string(), 1usize),
+            (Namespace::Module.as_str().to_string(), 1usize),
+        ])
+    );
+}
+
+#[test]
+fn rebuild_index_skips_when_corpus_unchanged_and_rebuilds_on_change() {
+    let temp = tempdir().expect("tempdir");
+    let project_root: PathBuf = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+
+    let alpha_path = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
+    write_file(&alpha_path, "Alpha article [[Beta]]");
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Beta.wiki"),
+        "Beta article",
+    );
+
+    let first = rebuild_index(&paths, &ScanOptions::default()).expect("first rebuild");
+    assert!(!first.unchanged);
+    assert_eq!(first.inserted_rows, 2);
+
+    let second = rebuild_index(&paths, &ScanOptions::default()).expect("second rebuild");
+    assert!(second.unchanged);
+    assert_eq!(second.inserted_rows, first.inserted_rows);
+    assert_eq!(second.inserted_links, first.inserted_links);
+
+    write_file(&alpha_path, "Alpha article revised [[Beta]] [[Gamma]]");
+    let third = rebuild_index(&paths, &ScanOptions::default()).expect("third rebuild");
+    assert!(!third.unchanged);
+    assert_eq!(third.inserted_rows, 2);
+
+    fs::remove_file(&alpha_path).expect("remove alpha");
+    let fourth = rebuild_index(&paths, &ScanOptions::default()).expect("fourth rebuild");
+    assert!(!fourth.unchanged);
+    assert_eq!(fourth.inserted_rows, 1);
+}
+
+#[test]
+fn query_backlinks_orphans_and_empty_categories() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Alpha.wiki"),
+        "[[Beta]] [[Category:People]]",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Beta.wiki"),
+        "No links here",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Gamma.wiki"),
+        "[[Beta]]",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Category").join("People.wiki"),
+        "People category",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Category").join("Empty.wiki"),
+        "Empty category",
+    );
+
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+
+    let backlinks = query_backlinks(&paths, "Beta")
+        .expect("backlinks query")
+        .expect("backlinks should exist");
+    assert_eq!(backlinks, vec!["Alpha".to_string(), "Gamma".to_string()]);
+
+    let orphans = query_orphans(&paths)
+        .expect("orphans query")
+        .expect("orphans should exist");
+    assert_eq!(orphans, vec!["Alpha".to_string(), "Gamma".to_string()]);
+
+    let empty_categories = query_empty_categories(&paths)
+        .expect("empty category query")
+        .expect("empty categories should exist");
+    assert_eq!(empty_categories, vec!["Category:Empty".to_string()]);
+}
+
+#[test]
+fn inspect_category_tree_reports_depth_sizing_and_redundancy() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+
+    write_file(
+        &paths.wiki_content_dir.join("Category").join("Remilia.wiki"),
+        "Root category",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Category").join("People.wiki"),
+        "[[Category:Remilia]]",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Category").join("Artists.wiki"),
+        "[[Category:People]]",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Category").join("Loose.wiki"),
+        "Detached category",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Alpha.wiki"),
+        "Alpha [[Category:People]] [[Category:Artists]]",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Beta.wiki"),
+        "Beta [[Category:Artists]]",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Gamma.wiki"),
+        "Gamma [[Category:Loose]]",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Delta.wiki"),
+        "Delta [[Category:Unwritten]]",
+    );
+
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+
+    let report = inspect_category_tree(
+        &paths,
+        &CategoryTreeOptions {
+            root_categories: vec!["Remilia".to_string(), "Category:Missing".to_string()],
+            oversized_threshold: 1,
+            undersized_threshold: 2,
+            limit: 20,
+        },
+    )
+    .expect("category tree")
+    .expect("index should exist");
+
+    assert_eq!(report.root_categories, vec!["Category:Remilia".to_string()]);
+    assert_eq!(
+        report.missing_root_categories,
+        vec!["Category:Missing".to_string()]
+    );
+    assert_eq!(report.category_count, 4);
+    assert_eq!(report.max_depth, 2);
+    assert_eq!(report.unreachable_category_count, 1);
+
+    let artists = report
+        .categories
+        .iter()
+        .find(|node| node.title == "Category:Artists")
+        .expect("artists node");
+    assert_eq!(artists.depth, Some(2));
+    assert_eq!(
+        artists.parent_chain,
+        vec![
+            "Category:Remilia".to_string(),
+            "Category:People".to_string(),
+            "Category:Artists".to_string(),
+        ]
+    );
+    assert_eq!(artists.page_member_count, 2);
+
+    assert_eq!(
+        report
+            .oversized_categories
+            .iter()
+            .map(|issue| issue.title.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Category:Artists"]
+    );
+    assert!(
+        report
+            .undersized_categories
+            .iter()
+            .any(|issue| issue.title == "Category:Loose")
+    );
+    assert_eq!(report.redundant_count, 1);
+    assert_eq!(report.redundant_categories[0].title, "Category:People");
+    assert_eq!(
+        report.redundant_categories[0].covering_subcategories,
+        vec!["Category:Artists".to_string()]
+    );
+    assert_eq!(report.pages_outside_root_tree_count, 1);
+    assert_eq!(report.pages_outside_root_tree[0].title, "Gamma");
+    assert_eq!(
+        report.pages_outside_root_tree[0].categories,
+        vec!["Category:Loose".to_string()]
+    );
+    assert_eq!(report.pages_under_missing_categories_count, 1);
+    assert_eq!(report.pages_under_missing_categories[0].title, "Delta");
+    assert_eq!(
+        report.pages_under_missing_categories[0].categories,
+        vec!["Category:Unwritten".to_string()]
+    );
+}
+
+#[test]
+fn build_graph_export_extracts_ego_graph_and_drops_isolated_nodes() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Alpha.wiki"),
+        "[[Beta]] [[Category:People]]",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Beta.wiki"),
+        "[[Gamma]]",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Gamma.wiki"),
+        "[[Delta]]",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Delta.wiki"),
+        "No links",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Lonely.wiki"),
+        "No links",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Category").join("People.wiki"),
+        "People category",
+    );
+
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+
+    let whole = build_graph_export(
+        &paths,
+        &GraphExportOptions::new(GraphKind::ArticleLinksFiltered),
+    )
+    .expect("graph export")
+    .expect("index should exist");
+    let titles = whole
+        .nodes
+        .iter()
+        .map(|node| node.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["Alpha", "Beta", "Delta", "Gamma"]);
+    assert_eq!(whole.edge_count(), 3);
+
+    let mut ego_options = GraphExportOptions::new(GraphKind::ArticleLinksFiltered);
+    ego_options.ego_title = Some("beta".to_string());
+    ego_options.ego_depth = 1;
+    let ego = build_graph_export(&paths, &ego_options)
+        .expect("ego export")
+        .expect("index should exist");
+    let titles = ego
+        .nodes
+        .iter()
+        .map(|node| node.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["Alpha", "Beta", "Gamma"]);
+    assert_eq!(ego.edge_count(), 2);
+
+    let mut missing = GraphExportOptions::new(GraphKind::ArticleLinksFiltered);
+    missing.ego_title = Some("Nowhere".to_string());
+    assert!(build_graph_export(&paths, &missing).is_err());
+}
+
+#[test]
+fn rebuild_index_stores_link_centrality_scores() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Hub.wiki"),
+        "[[Target]] [[Side]]",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Side.wiki"),
+        "[[Target]]",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Target.wiki"),
+        "No links",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Category").join("People.wiki"),
+        "People category",
+    );
+
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+
+    let report = query_page_centrality(&paths, &PageCentralityOptions::default())
+        .expect("centrality query")
+        .expect("index should exist");
+    assert_eq!(report.page_count, 3);
+    let titles = report
+        .pages
+        .iter()
+        .map(|page| page.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["Target", "Side", "Hub"]);
+    assert_eq!(report.pages[0].rank, 1);
+
+    let hubs = query_page_centrality(
+        &paths,
+        &PageCentralityOptions {
+            sort: PageCentralitySort::Hub,
+            ..PageCentralityOptions::default()
+        },
+    )
+    .expect("hub query")
+    .expect("index should exist");
+    assert_eq!(hubs.pages[0].title, "Hub");
+
+    let single = query_page_centrality(
+        &paths,
+        &PageCentralityOptions {
+            title: Some("side".to_string()),
+            ..PageCentralityOptions::default()
+        },
+    )
+    .expect("single page query")
+    .expect("index should exist");
+    assert_eq!(single.pages.len(), 1);
+    assert_eq!(single.pages[0].title, "Side");
+    assert_eq!(single.pages[0].rank, 2);
+}
+
+#[test]
+fn query_template_impact_walks_indirect_transclusion_and_invoke_chains() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Alpha.wiki"),
+        "{{Infobox person|name=Alpha}}\n'''Alpha''' page.",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Beta.wiki"),
+        "{{Navbox|title=Beta}}\n'''Beta''' page.",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Gamma.wiki"),
+        "'''Gamma''' page without templates.",
+    );
+    write_file(
+        &paths
+            .templates_dir
+            .join("infobox")
+            .join("Template_Infobox_person.wiki"),
+        "{{#invoke:Infobox person|render}}",
+    );
+    write_file(
+        &paths
+            .templates_dir
+            .join("navbox")
+            .join("Template_Navbox.wiki"),
+        "{{Infobox person|name=nested}}",
+    );
+    write_file(
+        &paths
+            .templates_dir
+            .join("infobox")
+            .join("Module_Infobox_person.wiki"),
+        "return { render = function() end }",
+    );
+
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+
+    let report = query_template_impact(&paths, "Module:Infobox person", false)
+        .expect("impact query")
+        .expect("index should exist");
+    assert_eq!(report.target_title, "Module:Infobox person");
+    assert_eq!(report.content_page_count, 2);
+    assert_eq!(report.direct_count, 1);
+    assert_eq!(report.max_depth, 3);
+    assert_eq!(report.namespace_counts.get("Template"), Some(&2));
+    let beta = report
+        .pages
+        .iter()
+        .find(|page| page.title == "Beta")
+        .expect("beta affected");
+    assert_eq!(beta.depth, 3);
+    assert_eq!(
+        beta.dependency_path,
+        vec![
+            "Beta".to_string(),
+            "Template:Navbox".to_string(),
+            "Template:Infobox person".to_string(),
+            "Module:Infobox person".to_string(),
+        ]
+    );
+    assert!(report.pages.iter().all(|page| page.namespace == "Main"));
+
+    let all = query_template_impact(&paths, "Infobox person", true)
+        .expect("impact query")
+        .expect("index should exist");
+    let titles = all
+        .pages
+        .iter()
+        .map(|page| page.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["Alpha", "Template:Navbox", "Beta"]);
+
+    assert!(query_template_impact(&paths, "Missing template", false).is_err());
+}
+
+#[test]
+fn query_stale_claims_ranks_pages_by_stale_statement_count() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Alpha.wiki"),
+        "{{Infobox organization\n| members_as_of = 2019\n}}\n'''Alpha''' is currently active. {{As of|2020|5}}, it had ten members.",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Beta.wiki"),
+        "'''Beta''' launched recently. As of 2026, it is fresh.",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Gamma.wiki"),
+        "'''Gamma''' launched in 2015.",
+    );
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+
+    let connection = open_indexed_connection(&paths)
+        .expect("open index")
+        .expect("index should exist");
+    let today = days_from_civil(2026, 10, 1);
+    let report = query_stale_claims_for_connection(
+        &paths,
+        &connection,
+        &StaleClaimOptions::default(),
+        today,
+    )
+    .expect("stale claim query");
+
+    assert_eq!(report.today, "2026-10-01");
+    assert_eq!(report.scanned_page_count, 3);
+    assert_eq!(report.stale_page_count, 2);
+    assert_eq!(report.stale_claim_count, 4);
+    assert_eq!(report.pages[0].title, "Alpha");
+    assert_eq!(report.pages[0].stale_claim_count, 3);
+    assert_eq!(report.pages[0].dated_claim_count, 2);
+    let kinds = report.pages[0]
+        .claims
+        .iter()
+        .map(|claim| claim.kind.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec!["infobox_field", "relative_phrase", "as_of_template"]
+    );
+    assert_eq!(
+        report.pages[0].claims[2].claim_date.as_deref(),
+        Some("2020-05-31")
+    );
+    assert_eq!(report.pages[0].claims[2].line, 4);
+    assert_eq!(report.pages[1].title, "Beta");
+    assert_eq!(report.pages[1].claims[0].kind, "relative_phrase");
+}
+
+#[test]
+fn rebuild_index_stores_typed_infobox_facts() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+
+    write_file(
+        &paths
+            .wiki_content_dir
+            .join("Main")
+            .join("Alpha Artist.wiki"),
+        "{{Infobox artist\n| name = Alpha\n| founded = {{Start date|2020|6|1}}\n| label = [[Remilia Corporation|Remilia]]\n| website = https://alpha.example\n}}\n'''Alpha''' is an artist.",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Beta Artist.wiki"),
+        "{{Infobox artist\n| name = Beta\n| founded = 2021\n| website = \n}}\n{{Cite web|title=Ignored}}",
+    );
+    write_file(
+        &paths
+            .wiki_content_dir
+            .join("Main")
+            .join("Gamma Artist.wiki"),
+        "{{Infobox artist}}\n'''Gamma''' has no fields.",
+    );
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+
+    let founding = query_infobox_facts(
+        &paths,
+        &InfoboxFactOptions {
+            template: Some("Infobox artist".to_string()),
+            param: Some("Founded".to_string()),
+            ..InfoboxFactOptions::default()
+        },
+    )
+    .expect("fact query")
+    .expect("index should exist");
+    assert_eq!(
+        founding.template.as_deref(),
+        Some("Template:Infobox artist")
+    );
+    let values = founding
+        .facts
+        .iter()
+        .map(|fact| {
+            (
+                fact.page_title.as_str(),
+                fact.value_kind.as_str(),
+                fact.normalized_value.as_str(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![
+            ("Alpha Artist", "date", "2020-06-01"),
+            ("Beta Artist", "date", "2021"),
+        ]
+    );
+    assert_eq!(founding.templates.len(), 1);
+    assert_eq!(founding.templates[0].page_count, 2);
+
+    let links = query_infobox_facts(
+        &paths,
+        &InfoboxFactOptions {
+            value_kind: Some("link".to_string()),
+            ..InfoboxFactOptions::default()
+        },
+    )
+    .expect("link query")
+    .expect("index should exist");
+    assert_eq!(links.fact_count, 1);
+    assert_eq!(links.facts[0].normalized_value, "Remilia Corporation");
+
+    let missing = query_infobox_facts(
+        &paths,
+        &InfoboxFactOptions {
+            template: Some("Template:Infobox artist".to_string()),
+            param: Some("website".to_string()),
+            missing: true,
+            ..InfoboxFactOptions::default()
+        },
+    )
+    .expect("missing query")
+    .expect("index should exist");
+    assert_eq!(missing.missing_pages, vec!["Beta Artist", "Gamma Artist"]);
+}
+
+#[test]
+fn check_fact_consistency_reports_infobox_and_lead_conflicts() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+    let main = paths.wiki_content_dir.join("Main");
+
+    write_file(
+        &main.join("Milady Maker.wiki"),
+        "{{Infobox collection\n| name = Milady Maker\n| founded = {{Start date|2021|8|1}}\n| holders = 10,000\n}}\n'''Milady Maker''' is a collection founded in August 2021.",
+    );
+    write_file(
+        &main.join("Remilia Corporation.wiki"),
+        "{{Infobox organization\n| name = Remilia Corporation\n| founded = 2020\n}}\n'''Remilia Corporation''' published [[Milady Maker]], which launched in 2019 and has 5,000 holders.\n\n== Works ==\n{{Infobox collection\n| name = Milady Maker\n| founded = 2022\n| holders = 10000\n}}\nLater text about [[Milady Maker]] launched in 2018.",
+    );
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+
+    let report = check_fact_consistency(
+        &paths,
+        &FactConsistencyOptions {
+            title: Some("Milady Maker".to_string()),
+            ..FactConsistencyOptions::default()
+        },
+    )
+    .expect("consistency check")
+    .expect("index should exist");
+    assert_eq!(report.subject_count, 1);
+    let conflicts = report
+        .conflicts
+        .iter()
+        .map(|conflict| {
+            (
+                conflict.parameter_key.as_str(),
+                conflict.canonical_value.as_str(),
+                conflict.other_value.as_str(),
+                conflict.other_location.page_title.as_str(),
+                conflict.other_location.source.as_str(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        conflicts,
+        vec![
+            (
+                "founded",
+                "2021-08-01",
+                "2019",
+                "Remilia Corporation",
+                "lead"
+            ),
+            (
+                "founded",
+                "2021-08-01",
+                "2022",
+                "Remilia Corporation",
+                "infobox"
+            ),
+            ("holders", "10000", "5000", "Remilia Corporation", "lead"),
+        ]
+    );
+    assert_eq!(report.conflict_count, 3);
+    let infobox = &report.conflicts[1];
+    assert_eq!(infobox.canonical_location.page_title, "Milady Maker");
+    assert_eq!(infobox.canonical_location.line, Some(3));
+    assert_eq!(infobox.other_location.line, Some(10));
+    assert_eq!(report.conflicts[0].other_location.line, Some(5));
+
+    let without_leads = check_fact_consistency(
+        &paths,
+        &FactConsistencyOptions {
+            include_leads: false,
+            ..FactConsistencyOptions::default()
+        },
+    )
+    .expect("consistency check")
+    .expect("index should exist");
+    assert_eq!(without_leads.conflict_count, 1);
+    assert_eq!(without_leads.conflicts[0].other_value, "2022");
+}
+
+/// Minimal HTTP/1.1 stand-in: answers each request from `routes` by path and
+/// counts requests so cache hits can be asserted.
+fn serve_http_stand_in(
+    routes: Vec<(&'static str, &'static str)>,
+) -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+    use std::io::{BufRead, BufReader, Write};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind stand-in");
+    let base = format!("http://{}", listener.local_addr().expect("local addr"));
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&requests);
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else {
+                continue;
+            };
+            let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).is_err() {
+                continue;
+            }
+            loop {
+                let mut header = String::new();
+                if reader.read_line(&mut header).is_err() || header.trim().is_empty() {
+                    break;
+                }
+            }
+            counter.fetch_add(1, Ordering::SeqCst);
+            let mut parts = request_line.split_whitespace();
+            let method = parts.next().unwrap_or_default().to_string();
+            let path = parts.next().unwrap_or_default().to_string();
+            let raw = routes
+                .iter()
+                .find(|(route, _)| *route == path)
+                .map(|(_, response)| *response)
+                .unwrap_or("HTTP/1.1 404 Not Found\r\n\r\n");
+            let (head, body) = raw.split_once("\r\n\r\n").unwrap_or((raw, ""));
+            let mut response = format!(
+                "{head}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            );
+            if method != "HEAD" {
+                response.push_str(body);
+            }
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+    (base, requests)
+}
+
+#[test]
+fn check_external_links_classifies_and_caches_results() {
+    use std::sync::atomic::Ordering;
+
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+    let (base, requests) = serve_http_stand_in(vec![
+        (
+            "/ok",
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n<title>Alpha</title>",
+        ),
+        (
+            "/report.pdf",
+            "HTTP/1.1 200 OK\r\nContent-Type: application/pdf\r\n\r\n%PDF",
+        ),
+        ("/gone", "HTTP/1.1 410 Gone\r\n\r\n"),
+        (
+            "/moved",
+            "HTTP/1.1 301 Moved Permanently\r\nLocation: /new-home\r\n\r\n",
+        ),
+        (
+            "/new-home",
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n<title>New</title>",
+        ),
+        ("/old-story", "HTTP/1.1 302 Found\r\nLocation: /\r\n\r\n"),
+        (
+            "/",
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n<title>Home</title>",
+        ),
+        (
+            "/soft",
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n<title>Page Not Found | Site</title>",
+        ),
+        (
+            "/paid",
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n<title>Story</title><p>Subscribe to continue reading.</p>",
+        ),
+        ("/busy", "HTTP/1.1 503 Service Unavailable\r\n\r\n"),
+    ]);
+    let cite =
+        |path: &str| format!("<ref>{{{{Cite web|url={base}{path}|title=Source {path}}}}}</ref>");
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Alpha.wiki"),
+        &format!(
+            "'''Alpha''' is cited.{}{}{}{}\n",
+            cite("/ok"),
+            cite("/report.pdf"),
+            cite("/gone"),
+            cite("/moved")
+        ),
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Beta.wiki"),
+        &format!(
+            "'''Beta''' is cited.{}{}{}{}{}\n",
+            cite("/gone"),
+            cite("/old-story"),
+            cite("/soft"),
+            cite("/paid"),
+            cite("/busy")
+        ),
+    );
+    let archive_dir = paths
+        .state_dir
+        .join("backups")
+        .join("web")
+        .join("stand-in-1");
+    write_file(
+        &archive_dir.join("manifest.json"),
+        &serde_json::json!({
+            "schema_version": "web_archive_manifest_v1",
+            "source_url": format!("{base}/gone"),
+            "origin_host": "127.0.0.1",
+            "crawled_at": "2026-01-01T00:00:00Z",
+            "output_dir": normalize_path(&archive_dir),
+            "attempted": 1,
+            "succeeded": 1,
+            "failed": 0,
+            "entries": [{
+                "url": format!("{base}/gone"),
+                "fetched_at": "2026-01-01T00:00:00Z",
+                "status": 200,
+                "ok": true,
+                "path": "127.0.0.1/gone.html"
+            }]
+        })
+        .to_string(),
+    );
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+
+    let options = ExternalLinkCheckOptions {
+        per_host_delay_ms: 0,
+        timeout_seconds: 5,
+        ..ExternalLinkCheckOptions::default()
+    };
+    let report = check_external_links(&paths, &options)
+        .expect("check external links")
+        .expect("index should exist");
+    assert_eq!(report.url_count, 8);
+    assert_eq!(report.fetched_count, 8);
+    let statuses = report
+        .links
+        .iter()
+        .map(|link| (link.url.trim_start_matches(&base), link.status.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        statuses,
+        vec![
+            ("/gone", "dead"),
+            ("/moved", "redirected"),
+            ("/paid", "paywalled"),
+            ("/old-story", "soft_404"),
+            ("/soft", "soft_404"),
+            ("/busy", "unverified"),
+        ]
+    );
+    assert_eq!(report.issue_count, 5);
+    assert_eq!(report.status_counts.get("ok"), Some(&2));
+    let gone = &report.links[0];
+    assert_eq!(gone.http_status, Some(410));
+    assert_eq!(gone.source_pages, vec!["Alpha", "Beta"]);
+    assert!(
+        gone.archived_copy
+            .as_deref()
+            .is_some_and(|path| path.ends_with("stand-in-1/127.0.0.1/gone.html"))
+    );
+    assert_eq!(report.archived_issue_count, 1);
+    assert_eq!(
+        report.links[1].final_url.as_deref(),
+        Some(format!("{base}/new-home").as_str())
+    );
+
+    let served = requests.load(Ordering::SeqCst);
+    let cached = check_external_links(
+        &paths,
+        &ExternalLinkCheckOptions {
+            titles: vec!["Beta".to_string()],
+            ..options.clone()
+        },
+    )
+    .expect("cached check")
+    .expect("index should exist");
+    assert_eq!(cached.url_count, 5);
+    assert_eq!(cached.cached_count, 4);
+    assert_eq!(cached.fetched_count, 1);
+    assert!(
+        cached
+            .links
+            .iter()
+            .all(|link| link.from_cache || link.status.as_str() == "unverified")
+    );
+    assert_eq!(requests.load(Ordering::SeqCst) - served, 2);
+}
+
+#[test]
+fn check_external_links_leaves_unreachable_hosts_unverified_and_uncached() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+    let refused_port = std::net::TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .expect("reserve port")
+        .port();
+    let url = format!("http://127.0.0.1:{refused_port}/story");
+    write_file(
+        &paths.wiki_content_dir.join("Main").join("Alpha.wiki"),
+        &format!("'''Alpha''' is cited.<ref>{{{{Cite web|url={url}|title=Story}}}}</ref>\n"),
+    );
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+
+    let options = ExternalLinkCheckOptions {
+        per_host_delay_ms: 0,
+        timeout_seconds: 5,
+        ..ExternalLinkCheckOptions::default()
+    };
+    for _ in 0..2 {
+        let report = check_external_links(&paths, &options)
+            .expect("check external links")
+            .expect("index should exist");
+        assert_eq!(report.fetched_count, 1);
+        assert_eq!(report.cached_count, 0);
+        assert_eq!(report.issue_count, 0);
+        let link = &report.links[0];
+        assert_eq!(link.url, url);
+        assert_eq!(link.status.as_str(), "unverified");
+        assert!(
+            link.detail
+                .as_deref()
+                .is_some_and(|detail| detail.starts_with("unreachable: "))
+        );
+    }
+}
+
+#[test]
+fn diff_index_builds_reports_structural_changes_between_builds() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+    let main = paths.wiki_content_dir.join("Main");
+    let category = paths.wiki_content_dir.join("Category");
+
+    write_file(
+        &main.join("Alpha.wiki"),
+        "[[Beta]] cited.<ref>{{Cite web|url=https://example.org/a|title=A}}</ref><ref>{{Cite web|url=https://example.org/b|title=B}}</ref>\n[[Category:Topics]]",
+    );
+    write_file(&main.join("Beta.wiki"), "[[Alpha]]\n[[Category:Topics]]");
+    write_file(&category.join("Topics.wiki"), "Topic pages.");
+    let first = rebuild_index(&paths, &ScanOptions::default()).expect("first rebuild");
+    let first_build = first.build_id.expect("first build id");
+
+    let unchanged = rebuild_index(&paths, &ScanOptions::default()).expect("unchanged rebuild");
+    assert!(unchanged.unchanged);
+    assert_eq!(unchanged.build_id, Some(first_build));
 
-    assert_eq!(links.len(), 1);
-    assert_eq!(links[0].target_title, "Visible");
+    write_file(
+        &main.join("Alpha.wiki"),
+        "[[Beta]] and [[Gamma]] cited.<ref>{{Cite web|url=https://example.org/a|title=A}}</ref>",
+    );
+    write_file(&main.join("Delta.wiki"), "[[Beta]]\n[[Category:Topics]]");
+    let second = rebuild_index(&paths, &ScanOptions::default()).expect("second rebuild");
+    let second_build = second.build_id.expect("second build id");
+    assert!(second_build > first_build);
+
+    let report = diff_index_builds(&paths, &IndexDiffOptions::default())
+        .expect("diff builds")
+        .expect("index should exist");
+    assert_eq!(report.from_build.build_id, first_build);
+    assert_eq!(report.to_build.build_id, second_build);
+    assert_eq!(report.added_pages.items, vec!["Delta".to_string()]);
+    assert!(report.removed_pages.items.is_empty());
+    let link = |source: &str, target: &str| IndexLinkChange {
+        source_title: source.to_string(),
+        target_title: target.to_string(),
+    };
+    assert_eq!(
+        report.added_links.items,
+        vec![link("Alpha", "Gamma"), link("Delta", "Beta")]
+    );
+    assert_eq!(report.new_red_links.items, vec![link("Alpha", "Gamma")]);
+    assert_eq!(
+        report.added_categories.items,
+        vec![link("Delta", "Category:Topics")]
+    );
+    assert_eq!(
+        report.removed_categories.items,
+        vec![link("Alpha", "Category:Topics")]
+    );
+    assert_eq!(report.new_uncategorized.items, vec!["Alpha".to_string()]);
+    assert_eq!(report.new_orphans.items, vec!["Delta".to_string()]);
+    let reference_change = &report.reference_changes.items[0];
+    assert_eq!(reference_change.name, "Alpha");
+    assert_eq!((reference_change.before, reference_change.after), (2, 1));
 
-    let module_content = "local docs = --[[ [[Not a page]] ]] return {}";
-    assert!(extract_wikilinks_for_namespace(module_content, Namespace::Module.as_str()).is_empty());
+    let error = diff_index_builds(
+        &paths,
+        &IndexDiffOptions {
+            since: Some(second_build),
+            until: Some(first_build),
+            ..IndexDiffOptions::default()
+        },
+    )
+    .expect_err("reversed range should fail");
+    assert!(error.to_string().contains("must be older"));
+    let builds = list_index_builds(&paths)
+        .expect("list builds")
+        .expect("index should exist");
+    assert_eq!(
+        builds
+            .iter()
+            .map(|build| build.build_id)
+            .collect::<Vec<_>>(),
+        vec![second_build, first_build]
+    );
 }
 
 #[test]
-fn rebuild_index_persists_scan_rows() {
+fn translation_status_reports_base_changes_since_marked_sync() {
     let temp = tempdir().expect("tempdir");
-    let project_root: PathBuf = temp.path().join("project");
+    let project_root = temp.path().join("project");
     fs::create_dir_all(&project_root).expect("create project root");
     let paths = paths(&project_root);
-
+    let main = paths.wiki_content_dir.join("Main");
+    let base_path = main.join("Network_spirituality.wiki");
+    let base_text = "'''Network spirituality''' is a concept.\n== History ==\nEarly history.\n";
+    write_file(&base_path, base_text);
     write_file(
-        &paths.wiki_content_dir.join("Main").join("Alpha.wiki"),
-        "Alpha article",
+        &main.join("Network_spirituality___ko.wiki"),
+        "'''Network spirituality''' 개념.",
     );
     write_file(
-        &paths.wiki_content_dir.join("Category").join("Foo.wiki"),
-        "#REDIRECT [[Category:Bar]]",
+        &main.join("Network_spirituality___fr.wiki"),
+        "<!-- wikitool:translation-base revision=812 hash=../../outside -->\n'''Network spirituality''' est un concept.",
     );
     write_file(
-        &paths
-            .templates_dir
-            .join("navbox")
-            .join("Module_Navbar")
-            .join("configuration.lua"),
-        "return {}",
+        &paths.project_root.join("outside.wiki"),
+        "Outside the archive.\n",
     );
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+    let connection = open_initialized_database_connection(&paths.db_path).expect("open db");
+    connection
+        .execute(
+            "INSERT INTO sync_ledger_pages (
+                title, namespace, relative_path, content_hash, revision_id, is_redirect,
+                last_synced_at_unix
+            ) VALUES ('Network spirituality', 0, 'wiki_content/Main/Network_spirituality.wiki', ?1, 812, 0, 0)",
+            [compute_wiki_sync_hash(base_text)],
+        )
+        .expect("seed ledger");
 
-    let report = rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
-    assert!(paths.db_path.exists());
-    assert!(!report.unchanged);
-    assert_eq!(report.inserted_rows, 3);
-    assert_eq!(report.scan.total_files, 3);
-    assert_eq!(report.scan.redirects, 1);
-
-    let stored = load_stored_index_stats(&paths)
-        .expect("load stats")
-        .expect("stats must exist");
-    assert_eq!(stored.indexed_rows, 3);
-    assert_eq!(stored.redirects, 1);
-    assert_eq!(
-        stored.by_namespace,
-        BTreeMap::from([
-            (Namespace::Category.as_str().to_string(), 1usize),
-            (Namespace::Main.as_str().to_string(), 1usize),
-            (Namespace::Module.as_str().to_string(), 1usize),
-        ])
+    let unmarked = query_translation_status(
+        &paths,
+        &TranslationStatusOptions {
+            include_all: true,
+            ..TranslationStatusOptions::default()
+        },
+    )
+    .expect("status")
+    .expect("index should exist");
+    assert_eq!(unmarked.translation_count, 2);
+    assert_eq!(unmarked.unmarked_count, 2);
+    assert!(
+        unmarked
+            .entries
+            .iter()
+            .all(|entry| entry.status == TranslationSyncStatus::Unmarked)
+    );
+
+    let mark = mark_translation_synced(&paths, "Network spirituality/ko").expect("mark");
+    assert_eq!(mark.base_revision, Some(812));
+    assert!(mark.changed);
+    let marked_text =
+        fs::read_to_string(main.join("Network_spirituality___ko.wiki")).expect("read marked");
+    assert!(marked_text.starts_with("<!-- wikitool:translation-base revision=812 hash="));
+    let again = mark_translation_synced(&paths, "Network spirituality/ko").expect("mark again");
+    assert!(!again.changed);
+
+    write_file(
+        &base_path,
+        "'''Network spirituality''' is a concept.\n== History ==\nEarly history.\nLater history.\n",
+    );
+    let report = query_translation_status(&paths, &TranslationStatusOptions::default())
+        .expect("status")
+        .expect("index should exist");
+    assert_eq!(report.outdated_count, 1);
+    assert_eq!(report.unmarked_count, 1);
+    assert_eq!(report.entries.len(), 1);
+    let entry = &report.entries[0];
+    assert_eq!(entry.title, "Network spirituality/ko");
+    assert_eq!(entry.status, TranslationSyncStatus::Outdated);
+    assert_eq!(entry.synced_revision, Some(812));
+    assert_eq!((entry.lines_added, entry.lines_removed), (1, 0));
+    let diff = entry.diff.as_deref().expect("base diff");
+    assert!(diff.contains("@r812"));
+    assert!(diff.contains("+Later history."));
+
+    mark_translation_synced(&paths, "Network spirituality/ko").expect("re-mark");
+    let current = query_translation_status(
+        &paths,
+        &TranslationStatusOptions {
+            title: Some("Network spirituality/ko".to_string()),
+            include_all: true,
+            ..TranslationStatusOptions::default()
+        },
+    )
+    .expect("status")
+    .expect("index should exist");
+    assert_eq!(current.entries[0].status, TranslationSyncStatus::Current);
+    assert_eq!(current.entries[0].synced_revision, None);
+    assert!(
+        fs::read_to_string(main.join("Network_spirituality___ko.wiki"))
+            .expect("read re-marked")
+            .matches("wikitool:translation-base revision=local")
+            .count()
+            == 1
     );
 }
 
 #[test]
-fn rebuild_index_skips_when_corpus_unchanged_and_rebuilds_on_change() {
+fn rebuild_index_stores_page_quality_metrics_for_sorting_and_thresholds() {
     let temp = tempdir().expect("tempdir");
-    let project_root: PathBuf = temp.path().join("project");
+    let project_root = temp.path().join("project");
     fs::create_dir_all(&project_root).expect("create project root");
     let paths = paths(&project_root);
+    let main = paths.wiki_content_dir.join("Main");
 
-    let alpha_path = paths.wiki_content_dir.join("Main").join("Alpha.wiki");
-    write_file(&alpha_path, "Alpha article [[Beta]]");
     write_file(
-        &paths.wiki_content_dir.join("Main").join("Beta.wiki"),
-        "Beta article",
+        &main.join("Alpha.wiki"),
+        "'''Alpha''' is a well sourced page.<ref>Source.</ref>\n\n== History ==\nAlpha began in 2020.<ref>Source.</ref>\n\nAlpha then grew quickly without a source.\n[[File:Alpha.png|thumb]]",
     );
+    write_file(&main.join("Beta.wiki"), "'''Beta''' links to [[Alpha]].");
+    write_file(
+        &main.join("Gamma.wiki"),
+        "'''Gamma''' also links to [[Alpha]] and [[Beta]].",
+    );
+    write_file(&main.join("Alpha redirect.wiki"), "#REDIRECT [[Alpha]]");
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
 
-    let first = rebuild_index(&paths, &ScanOptions::default()).expect("first rebuild");
-    assert!(!first.unchanged);
-    assert_eq!(first.inserted_rows, 2);
+    let report = query_page_quality(&paths, &PageQualityOptions::default())
+        .expect("quality query")
+        .expect("index should exist");
+    assert_eq!(report.page_count, 3);
+    let titles = report
+        .pages
+        .iter()
+        .map(|page| page.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["Beta", "Gamma", "Alpha"]);
+    let alpha = &report.pages[2];
+    assert_eq!(alpha.prose_words, 17);
+    assert_eq!(alpha.reference_count, 2);
+    assert_eq!(alpha.uncited_paragraphs, 1);
+    assert_eq!(alpha.section_count, 1);
+    assert_eq!(alpha.image_count, 1);
+    assert_eq!(alpha.inbound_links, Some(2));
+
+    let filtered = query_page_quality(
+        &paths,
+        &PageQualityOptions {
+            sort: PageQualityMetric::InboundLinks,
+            descending: true,
+            thresholds: vec![PageQualityThreshold {
+                metric: PageQualityMetric::ReferencesPer100Words,
+                min: None,
+                max: Some(0.0),
+            }],
+            ..PageQualityOptions::default()
+        },
+    )
+    .expect("quality query")
+    .expect("index should exist");
+    assert_eq!(filtered.matched_count, 2);
+    assert_eq!(filtered.pages[0].title, "Beta");
+    assert_eq!(filtered.pages[0].inbound_links, Some(1));
+    assert_eq!(filtered.pages[1].title, "Gamma");
+}
 
-    let second = rebuild_index(&paths, &ScanOptions::default()).expect("second rebuild");
-    assert!(second.unchanged);
-    assert_eq!(second.inserted_rows, first.inserted_rows);
-    assert_eq!(second.inserted_links, first.inserted_links);
+#[test]
+fn wanted_pages_rank_missing_targets_and_write_briefs() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+    let main = paths.wiki_content_dir.join("Main");
+    write_file(
+        &main.join("Milady.wiki"),
+        "'''Milady''' is a collection by [[Remilia Corporation]]. It draws on [[Network spirituality]].\n\n== History ==\n[[Remilia Corporation]] released it in 2021.\n",
+    );
+    write_file(
+        &main.join("Bonkler.wiki"),
+        "'''Bonkler''' is a project by [[Remilia Corporation]] and [[Milady]].\n",
+    );
+    write_file(
+        &main.join("Redacted.wiki"),
+        "'''Redacted''' mentions [[Obscure Page]].\n",
+    );
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
 
-    write_file(&alpha_path, "Alpha article revised [[Beta]] [[Gamma]]");
-    let third = rebuild_index(&paths, &ScanOptions::default()).expect("third rebuild");
-    assert!(!third.unchanged);
-    assert_eq!(third.inserted_rows, 2);
+    let report = query_wanted_pages(&paths, &WantedPagesOptions::default())
+        .expect("wanted")
+        .expect("index should exist");
+    assert_eq!(report.wanted_count, 3);
+    let titles = report
+        .pages
+        .iter()
+        .map(|page| page.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        titles,
+        vec![
+            "Remilia Corporation",
+            "Network spirituality",
+            "Obscure Page"
+        ]
+    );
+    let top = &report.pages[0];
+    assert_eq!(top.link_count, 3);
+    assert_eq!(top.source_count, 2);
+    assert_eq!(top.sources, vec!["Bonkler", "Milady"]);
+    assert_eq!(top.mentions.len(), 3);
+    assert!(
+        top.mentions
+            .iter()
+            .any(|mention| mention.source_title == "Milady"
+                && mention.sentence.contains("released it in 2021"))
+    );
 
-    fs::remove_file(&alpha_path).expect("remove alpha");
-    let fourth = rebuild_index(&paths, &ScanOptions::default()).expect("fourth rebuild");
-    assert!(!fourth.unchanged);
-    assert_eq!(fourth.inserted_rows, 1);
+    let filtered = query_wanted_pages(
+        &paths,
+        &WantedPagesOptions {
+            min_sources: 2,
+            mention_limit: 1,
+            ..WantedPagesOptions::default()
+        },
+    )
+    .expect("wanted")
+    .expect("index should exist");
+    assert_eq!(filtered.wanted_count, 1);
+    assert_eq!(filtered.pages[0].mentions.len(), 1);
+
+    let brief_dir = temp.path().join("briefs");
+    let briefs =
+        write_wanted_page_briefs(&paths, &report.pages[..1], &brief_dir).expect("write briefs");
+    assert_eq!(briefs.len(), 1);
+    let brief: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&briefs[0].path).expect("read brief"))
+            .expect("parse brief");
+    assert_eq!(brief["title"], "Remilia Corporation");
+    assert_eq!(brief["wanted"]["link_count"], 3);
+    assert!(brief["article_start"].is_object());
+    assert!(
+        briefs[0]
+            .path
+            .ends_with(&format!("{}.json", brief_file_stem("Remilia Corporation")))
+    );
+    assert!(brief_file_stem("Remilia Corporation").starts_with("Remilia_Corporation-"));
+    assert_ne!(brief_file_stem("Foo/Bar"), brief_file_stem("Foo Bar"));
+    assert_ne!(brief_file_stem("C++"), brief_file_stem("C--"));
 }
 
 #[test]
-fn query_backlinks_orphans_and_empty_categories() {
+fn unused_pages_follow_transitive_usage_and_skip_doc_subpages() {
     let temp = tempdir().expect("tempdir");
     let project_root = temp.path().join("project");
     fs::create_dir_all(&project_root).expect("create project root");
     let paths = paths(&project_root);
-
+    let templates = paths.templates_dir.join("misc");
     write_file(
         &paths.wiki_content_dir.join("Main").join("Alpha.wiki"),
-        "[[Beta]] [[Category:People]]",
+        "{{Used}}\n'''Alpha''' page.\n[[File:Shown.png|thumb]]",
     );
     write_file(
-        &paths.wiki_content_dir.join("Main").join("Beta.wiki"),
-        "No links here",
+        &templates.join("Template_Used.wiki"),
+        "{{#invoke:Used|main}}",
     );
     write_file(
-        &paths.wiki_content_dir.join("Main").join("Gamma.wiki"),
-        "[[Beta]]",
+        &templates.join("Module_Used.lua"),
+        "local data = mw.loadData('Module:Used/data')\nreturn {}",
     );
+    write_file(&templates.join("Module_Used___data.lua"), "return {}");
     write_file(
-        &paths.wiki_content_dir.join("Category").join("People.wiki"),
-        "People category",
+        &templates.join("Template_Orphan.wiki"),
+        "{{Orphan helper}} {{#invoke:Orphan|main}}",
     );
+    write_file(&templates.join("Template_Orphan_helper.wiki"), "helper");
     write_file(
-        &paths.wiki_content_dir.join("Category").join("Empty.wiki"),
-        "Empty category",
+        &templates.join("Module_Orphan.lua"),
+        "local used = require(\"Module:Used\")\nreturn {}",
+    );
+    write_file(&templates.join("Template_Orphan___doc.wiki"), "{{Orphan}}");
+    write_file(
+        &paths.wiki_content_dir.join("File").join("Shown.png.wiki"),
+        "Shown file.",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("File").join("Unused.png.wiki"),
+        "Unused file.",
     );
-
     rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
 
-    let backlinks = query_backlinks(&paths, "Beta")
-        .expect("backlinks query")
-        .expect("backlinks should exist");
-    assert_eq!(backlinks, vec!["Alpha".to_string(), "Gamma".to_string()]);
+    let report = query_unused_pages(&paths, &UnusedPagesOptions::default())
+        .expect("unused query")
+        .expect("index should exist");
+    let unused = report
+        .pages
+        .iter()
+        .map(|page| (page.title.as_str(), page.used_only_by.clone()))
+        .collect::<BTreeMap<_, _>>();
+    assert_eq!(
+        unused,
+        BTreeMap::from([
+            ("File:Unused.png", Vec::new()),
+            ("Module:Orphan", vec!["Template:Orphan".to_string()]),
+            ("Template:Orphan", Vec::new()),
+            (
+                "Template:Orphan helper",
+                vec!["Template:Orphan".to_string()]
+            ),
+        ])
+    );
+    assert_eq!(report.template_count, 2);
+    assert_eq!(report.module_count, 1);
+    assert_eq!(report.file_count, 1);
+    assert!(report.pages.iter().all(|page| page.modified_at.is_some()));
 
-    let orphans = query_orphans(&paths)
-        .expect("orphans query")
-        .expect("orphans should exist");
-    assert_eq!(orphans, vec!["Alpha".to_string(), "Gamma".to_string()]);
+    let modules = query_unused_pages(
+        &paths,
+        &UnusedPagesOptions {
+            namespaces: vec![Namespace::Module],
+            ..UnusedPagesOptions::default()
+        },
+    )
+    .expect("unused query")
+    .expect("index should exist");
+    assert_eq!(modules.candidate_count, 1);
+    assert_eq!(modules.pages[0].title, "Module:Orphan");
+}
 
-    let empty_categories = query_empty_categories(&paths)
-        .expect("empty category query")
-        .expect("empty categories should exist");
-    assert_eq!(empty_categories, vec!["Category:Empty".to_string()]);
+#[test]
+fn unreliable_sources_count_citing_pages_by_domain_and_publisher() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+    let main = paths.wiki_content_dir.join("Main");
+    write_file(
+        &main.join("Alpha.wiki"),
+        "'''Alpha''' is a meme.<ref>{{Cite web|url=https://knowyourmeme.com/memes/alpha|title=Alpha}}</ref> More.<ref>{{Cite web|url=https://www.knowyourmeme.com/memes/alpha-2|title=Alpha 2}}</ref>",
+    );
+    write_file(
+        &main.join("Beta.wiki"),
+        "'''Beta''' is a meme.<ref>{{Cite web|url=https://example.org/beta|title=Beta|website=Know Your Meme}}</ref>",
+    );
+    write_file(
+        &main.join("Gamma.wiki"),
+        "'''Gamma''' is sourced.<ref>{{Cite web|url=https://example.com/gamma|title=Gamma}}</ref>",
+    );
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+
+    let rules = vec![
+        UnreliableSourceRule {
+            label: "Know Your Meme".to_string(),
+            matcher: "knowyourmeme.com".to_string(),
+            reason: "tertiary source".to_string(),
+        },
+        UnreliableSourceRule {
+            label: "IQ.wiki".to_string(),
+            matcher: "iq.wiki".to_string(),
+            reason: String::new(),
+        },
+    ];
+    let report = query_unreliable_sources(&paths, &rules, 10)
+        .expect("unreliable query")
+        .expect("index should exist");
+    assert_eq!(report.rule_count, 2);
+    assert_eq!(report.citing_page_count, 2);
+    assert_eq!(report.citation_count, 3);
+    assert_eq!(report.sources[0].label, "Know Your Meme");
+    assert_eq!(report.sources[0].page_count, 2);
+    assert_eq!(report.sources[0].pages[0].title, "Alpha");
+    assert_eq!(report.sources[0].pages[0].citation_count, 2);
+    assert_eq!(report.sources[1].page_count, 0);
+
+    let cites = |url: &str| rules[0].matches_citation("", &[url.to_string()], "");
+    assert!(cites(
+        "https://web.archive.org/web/2020/https://www.knowyourmeme.com/memes/x"
+    ));
+    assert!(cites("//knowyourmeme.com/memes/x"));
+    assert!(!cites("https://knowyourmeme.com.mirror.example/memes/x"));
+    assert!(!cites("https://notknowyourmeme.com/memes/x"));
+    assert!(!cites("https://example.com/knowyourmeme.com/x"));
 }
 
 #[test]
@@ -556,6 +1848,87 @@ fn section_authoring_bias_prefers_content_sections_over_reference_tail() {
     assert!(references_score < 0);
 }
 
+#[test]
+fn chunk_rerank_breaks_score_ties_by_link_centrality() {
+    use crate::content_store::model::{ChunkRerankSignals, RetrievalAudience};
+    use crate::knowledge::retrieval::RetrievedChunk;
+
+    let chunk = |title: &str| RetrievedChunk {
+        source_title: title.to_string(),
+        source_namespace: Namespace::Main.as_str().to_string(),
+        source_relative_path: format!("wiki_content/Main/{title}.wiki"),
+        section_heading: Some("History".to_string()),
+        token_estimate: 12,
+        chunk_text: "Shared history text.".to_string(),
+    };
+    let rerank = |signals: &ChunkRerankSignals| {
+        crate::knowledge::retrieval::rerank_retrieved_chunks(
+            vec![chunk("Alpha"), chunk("Beta")],
+            "history",
+            &["history".to_string()],
+            signals,
+            RetrievalAudience::Authoring,
+        )
+        .into_iter()
+        .map(|chunk| chunk.source_title)
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        rerank(&ChunkRerankSignals::default()),
+        vec!["Alpha", "Beta"]
+    );
+    let central_beta = ChunkRerankSignals {
+        centrality_page_scores: BTreeMap::from([
+            ("alpha".to_string(), 0.1),
+            ("beta".to_string(), 0.4),
+        ]),
+        ..ChunkRerankSignals::default()
+    };
+    assert_eq!(rerank(&central_beta), vec!["Beta", "Alpha"]);
+}
+
+#[test]
+fn authoring_comparables_break_score_ties_by_link_centrality() {
+    use crate::authoring::comparables::{
+        AuthoringRelatedPageInputs, collect_related_pages_for_authoring,
+    };
+
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+    let main = paths.wiki_content_dir.join("Main");
+    write_file(&main.join("Alpha.wiki"), "'''Alpha''' is a page.");
+    write_file(&main.join("Beta.wiki"), "'''Beta''' is a page.");
+    write_file(&main.join("Hub.wiki"), "'''Hub''' links to [[Beta]].");
+    write_file(
+        &main.join("Side.wiki"),
+        "'''Side''' links to [[Beta]] and [[Hub]].",
+    );
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+    let connection = open_initialized_database_connection(&paths.db_path).expect("open db");
+
+    let candidates = collect_related_pages_for_authoring(
+        &connection,
+        AuthoringRelatedPageInputs {
+            stub_link_titles: &["Alpha".to_string(), "Beta".to_string()],
+            query_terms: &[],
+            limit: 2,
+            template_page_scores: &BTreeMap::new(),
+            semantic_page_hits: &[],
+            authority_page_hits: &[],
+            identifier_page_hits: &[],
+        },
+    )
+    .expect("comparables");
+    let ranked = candidates
+        .iter()
+        .map(|candidate| (candidate.title.as_str(), candidate.retrieval_weight))
+        .collect::<Vec<_>>();
+    assert_eq!(ranked, vec![("Beta", 400), ("Alpha", 400)]);
+}
+
 #[test]
 fn extract_media_records_parses_inline_and_gallery_entries() {
     let content = "[[Image:Alpha.png|thumb|Alpha portrait]]\n<gallery mode=\"packed\">\nFile:Beta.jpg|Beta gallery caption\n</gallery>";
@@ -2057,6 +3430,132 @@ fn validation_checks_report_expected_issues() {
     assert!(report.orphan_pages.contains(&"Alpha".to_string()));
 }
 
+#[test]
+fn validation_checks_report_link_anchor_file_category_and_size_issues() {
+    let temp = tempdir().expect("tempdir");
+    let project_root = temp.path().join("project");
+    fs::create_dir_all(&project_root).expect("create project root");
+    let paths = paths(&project_root);
+    let main = paths.wiki_content_dir.join("Main");
+
+    write_file(
+        &main.join("Alpha.wiki"),
+        "'''Alpha''' links to [[Alpha]], [[Old Beta]], [[Beta#History]], [[Beta#Reception]], [[Beta#Legacy]], [[Old Beta#Early life]], [[Beta history#Early life]], [[Beta history#Aftermath]] and [[#Missing here]].\n[[File:Present.png|thumb]] [[File:Absent.png|thumb]]\n[[Category:People]] [[Category:Unwritten]]\n",
+    );
+    write_file(
+        &main.join("Beta.wiki"),
+        "'''Beta''' text.\n== History ==\nOld.\n== ''Early'' life ==\n{{Anchor|Legacy}} More.\n[[Category:People]]\n",
+    );
+    write_file(&main.join("Old_Beta.wiki"), "#REDIRECT [[Beta]]");
+    write_file(
+        &main.join("Beta_history.wiki"),
+        "#REDIRECT [[Beta#History]]",
+    );
+    write_file(&main.join("Ghost_Link.wiki"), "#REDIRECT [[Nowhere]]");
+    write_file(&main.join("Large.wiki"), &"word ".repeat(400));
+    write_file(
+        &paths.wiki_content_dir.join("File").join("Present.png.wiki"),
+        "A file.",
+    );
+    write_file(
+        &paths.wiki_content_dir.join("Category").join("People.wiki"),
+        "People category",
+    );
+
+    rebuild_index(&paths, &ScanOptions::default()).expect("rebuild");
+    let report = run_validation_checks_with_options(
+        &paths,
+        &ValidationOptions {
+            categories: vec![
+                ValidationCategory::SelfLinks,
+                ValidationCategory::RedirectBypasses,
+                ValidationCategory::MissingAnchors,
+                ValidationCategory::MissingFiles,
+                ValidationCategory::MissingCategoryPages,
+                ValidationCategory::OversizedPages,
+                ValidationCategory::BrokenRedirects,
+            ],
+            oversized_page_bytes: 1_000,
+        },
+    )
+    .expect("validate query")
+    .expect("validation should be available");
+
+    assert_eq!(report.self_links, vec!["Alpha".to_string()]);
+    assert_eq!(
+        report.redirect_bypasses,
+        vec![
+            RedirectBypassIssue {
+                source_title: "Alpha".to_string(),
+                link_title: "Beta history".to_string(),
+                redirect_target: "Beta#History".to_string(),
+            },
+            RedirectBypassIssue {
+                source_title: "Alpha".to_string(),
+                link_title: "Old Beta".to_string(),
+                redirect_target: "Beta".to_string(),
+            },
+        ]
+    );
+    assert_eq!(
+        report.missing_anchors,
+        vec![
+            MissingAnchorIssue {
+                source_title: "Alpha".to_string(),
+                target_title: "Beta".to_string(),
+                anchor: "Reception".to_string(),
+            },
+            MissingAnchorIssue {
+                source_title: "Alpha".to_string(),
+                target_title: "Beta history".to_string(),
+                anchor: "Aftermath".to_string(),
+            },
+            MissingAnchorIssue {
+                source_title: "Alpha".to_string(),
+                target_title: "Alpha".to_string(),
+                anchor: "Missing here".to_string(),
+            },
+        ]
+    );
+    assert_eq!(
+        report.missing_files,
+        vec![MissingFileIssue {
+            source_title: "Alpha".to_string(),
+            file_title: "File:Absent.png".to_string(),
+        }]
+    );
+    assert_eq!(
+        report.missing_category_pages,
+        vec![MissingCategoryPageIssue {
+            category_title: "Category:Unwritten".to_string(),
+            member_count: 1,
+        }]
+    );
+    assert_eq!(
+        report.oversized_pages,
+        vec![OversizedPageIssue {
+            title: "Large".to_string(),
+            bytes: 2_000,
+        }]
+    );
+    assert_eq!(
+        report.broken_redirects,
+        vec![BrokenRedirectIssue {
+            title: "Ghost Link".to_string(),
+            target_title: "Nowhere".to_string(),
+        }]
+    );
+    assert!(report.orphan_pages.is_empty());
+
+    let default_report = run_validation_checks(&paths)
+        .expect("validate query")
+        .expect("validation should be available");
+    assert!(default_report.orphan_pages.contains(&"Large".to_string()));
+    assert!(default_report.self_links.is_empty());
+    assert!(default_report.missing_anchors.is_empty());
+    assert!(default_report.broken_redirects.is_empty());
+}
+
 #[test]
 fn load_stored_index_stats_returns_none_when_db_is_missing() {
     let temp = tempdir().expect("tempdir");
@@ -0,0 +1,343 @@
+use std::path::PathBuf;
+
+use anyhow::bail;
+use similar::{ChangeTag, TextDiff};
+
+use super::prelude::*;
+use crate::support::{compute_wiki_sync_hash, normalize_wiki_content};
+use crate::title_variants::translation_variant_info;
+
+pub use super::model::{
+    TranslationStatusEntry, TranslationStatusOptions, TranslationStatusReport, TranslationSyncMark,
+    TranslationSyncStatus,
+};
+
+const MARKER_PREFIX: &str = "<!-- wikitool:translation-base";
+const MARKER_SUFFIX: &str = "-->";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SyncMarker {
+    revision: Option<i64>,
+    hash: String,
+}
+
+#[derive(Debug, Clone)]
+struct TranslationPage {
+    title: String,
+    relative_path: String,
+    base_title: String,
+    language: String,
+}
+
+/// Record that a translation is in sync with the current local text of its
+/// base page: writes a hidden marker into the translation and archives the
+/// base text so later diffs can show what changed since.
+pub fn mark_translation_synced(paths: &ResolvedPaths, title: &str) -> Result<TranslationSyncMark> {
+    let Some(connection) = open_indexed_connection(paths)? else {
+        bail!("knowledge index is not built (run `wikitool knowledge build`)");
+    };
+    let Some(info) = translation_variant_info(title) else {
+        bail!("`{title}` is not a translation subpage (expected `Base/xx`)");
+    };
+    let Some(record) = load_page_record_exact(&connection, title)? else {
+        bail!("translation page `{title}` is not in the local index");
+    };
+    if record.is_redirect {
+        bail!("translation page `{title}` is a redirect");
+    }
+    let Some(base) = load_page_record_exact(&connection, &info.base_title)? else {
+        bail!(
+            "base page `{}` for `{title}` is not in the local index",
+            info.base_title
+        );
+    };
+
+    let base_content = read_page(paths, &base.relative_path)?;
+    let base_hash = compute_wiki_sync_hash(&base_content);
+    let base_revision = synced_base_revision(&connection, &base.title, &base_hash)?;
+    archive_base_text(paths, &base_hash, &base_content)?;
+
+    let marker = SyncMarker {
+        revision: base_revision,
+        hash: base_hash.clone(),
+    };
+    let translation_path = absolute_path_from_relative(paths, &record.relative_path);
+    let content = read_page(paths, &record.relative_path)?;
+    let updated = apply_marker(&content, &marker);
+    let changed = updated != content;
+    if changed {
+        fs::write(&translation_path, updated)
+            .with_context(|| format!("failed to write {}", translation_path.display()))?;
+    }
+
+    Ok(TranslationSyncMark {
+        title: record.title,
+        base_title: base.title,
+        relative_path: record.relative_path,
+        base_revision,
+        base_hash,
+        changed,
+    })
+}
+
+pub fn query_translation_status(
+    paths: &ResolvedPaths,
+    options: &TranslationStatusOptions,
+) -> Result<Option<TranslationStatusReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    query_translation_status_for_connection(paths, &connection, options).map(Some)
+}
+
+pub(crate) fn query_translation_status_for_connection(
+    paths: &ResolvedPaths,
+    connection: &Connection,
+    options: &TranslationStatusOptions,
+) -> Result<TranslationStatusReport> {
+    let mut entries = Vec::new();
+    for page in load_translation_pages(connection)? {
+        if let Some(filter) = options.title.as_deref()
+            && !page.title.eq_ignore_ascii_case(filter)
+            && !page.base_title.eq_ignore_ascii_case(filter)
+        {
+            continue;
+        }
+        entries.push(translation_status(paths, connection, page, options)?);
+    }
+
+    let count = |status: TranslationSyncStatus| {
+        entries
+            .iter()
+            .filter(|entry| entry.status == status)
+            .count()
+    };
+    let mut report = TranslationStatusReport {
+        translation_count: entries.len(),
+        outdated_count: count(TranslationSyncStatus::Outdated),
+        unmarked_count: count(TranslationSyncStatus::Unmarked),
+        missing_base_count: count(TranslationSyncStatus::MissingBase),
+        current_count: count(TranslationSyncStatus::Current),
+        entries: Vec::new(),
+    };
+    entries.retain(|entry| options.include_all || entry.status == TranslationSyncStatus::Outdated);
+    entries.sort_by(|left, right| {
+        left.status
+            .cmp(&right.status)
+            .then_with(|| {
+                (right.lines_added + right.lines_removed)
+                    .cmp(&(left.lines_added + left.lines_removed))
+            })
+            .then_with(|| left.title.cmp(&right.title))
+    });
+    entries.truncate(options.limit);
+    report.entries = entries;
+    Ok(report)
+}
+
+fn translation_status(
+    paths: &ResolvedPaths,
+    connection: &Connection,
+    page: TranslationPage,
+    options: &TranslationStatusOptions,
+) -> Result<TranslationStatusEntry> {
+    let marker = parse_marker(&read_page(paths, &page.relative_path)?);
+    let mut entry = TranslationStatusEntry {
+        title: page.title,
+        base_title: page.base_title,
+        language: page.language,
+        status: TranslationSyncStatus::Unmarked,
+        synced_revision: marker.as_ref().and_then(|marker| marker.revision),
+        synced_hash: marker.as_ref().map(|marker| marker.hash.clone()),
+        base_revision: None,
+        base_hash: None,
+        lines_added: 0,
+        lines_removed: 0,
+        diff: None,
+        diff_unavailable: None,
+    };
+    let Some(base) = load_page_record_exact(connection, &entry.base_title)? else {
+        entry.status = TranslationSyncStatus::MissingBase;
+        return Ok(entry);
+    };
+    let base_content = read_page(paths, &base.relative_path)?;
+    let base_hash = compute_wiki_sync_hash(&base_content);
+    entry.base_revision = ledger_revision(connection, &base.title)?.map(|(revision, _)| revision);
+    entry.base_hash = Some(base_hash.clone());
+    let Some(marker) = marker else {
+        return Ok(entry);
+    };
+    if marker.hash == base_hash {
+        entry.status = TranslationSyncStatus::Current;
+        return Ok(entry);
+    }
+
+    entry.status = TranslationSyncStatus::Outdated;
+    let archive_path = base_archive_path(paths, &marker.hash);
+    let Ok(synced_text) = fs::read_to_string(&archive_path) else {
+        entry.diff_unavailable = Some(format!(
+            "base text for hash {} is not archived at {}",
+            marker.hash,
+            normalize_path(&archive_path)
+        ));
+        return Ok(entry);
+    };
+    // Both sides are stored trimmed; terminate the last line so an append does
+    // not also report the previous last line as changed.
+    let synced_text = format!("{synced_text}\n");
+    let current_text = format!("{}\n", normalize_wiki_content(&base_content));
+    let diff = TextDiff::from_lines(synced_text.as_str(), current_text.as_str());
+    for change in diff.iter_all_changes() {
+        match change.tag() {
+            ChangeTag::Insert => entry.lines_added += 1,
+            ChangeTag::Delete => entry.lines_removed += 1,
+            ChangeTag::Equal => {}
+        }
+    }
+    if options.include_diff {
+        let synced_label = match marker.revision {
+            Some(revision) => format!("a/{}@r{revision}", base.relative_path),
+            None => format!("a/{}@{}", base.relative_path, marker.hash),
+        };
+        entry.diff = Some(
+            diff.unified_diff()
+                .context_radius(3)
+                .header(&synced_label, &format!("b/{}", base.relative_path))
+                .to_string(),
+        );
+    }
+    Ok(entry)
+}
+
+fn load_translation_pages(connection: &Connection) -> Result<Vec<TranslationPage>> {
+    let mut statement = connection
+        .prepare(
+            "SELECT title, relative_path, translation_base_title, translation_language
+             FROM indexed_pages
+             WHERE is_translation_variant = 1 AND is_redirect = 0
+             ORDER BY title ASC",
+        )
+        .context("failed to prepare translation page query")?;
+    let rows = statement
+        .query_map([], |row| {
+            Ok(TranslationPage {
+                title: row.get(0)?,
+                relative_path: row.get(1)?,
+                base_title: row.get::<_, Option<String>>(2)?.unwrap_or_default(),
+                language: row.get::<_, Option<String>>(3)?.unwrap_or_default(),
+            })
+        })
+        .context("failed to run translation page query")?;
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row.context("failed to decode translation page row")?);
+    }
+    Ok(out)
+}
+
+/// Ledger revision of the base page, but only when the local text is exactly
+/// what was last synced; local edits have no wiki revision yet.
+fn synced_base_revision(
+    connection: &Connection,
+    base_title: &str,
+    base_hash: &str,
+) -> Result<Option<i64>> {
+    Ok(ledger_revision(connection, base_title)?
+        .filter(|(_, ledger_hash)| ledger_hash == base_hash)
+        .map(|(revision, _)| revision))
+}
+
+fn ledger_revision(connection: &Connection, title: &str) -> Result<Option<(i64, String)>> {
+    if !table_exists(connection, "sync_ledger_pages")? {
+        return Ok(None);
+    }
+    let row = connection
+        .query_row(
+            "SELECT revision_id, content_hash FROM sync_ledger_pages WHERE title = ?1",
+            [title],
+            |row| Ok((row.get::<_, Option<i64>>(0)?, row.get::<_, String>(1)?)),
+        )
+        .optional()
+        .with_context(|| format!("failed to load sync ledger row for {title}"))?;
+    Ok(row.and_then(|(revision, hash)| revision.map(|revision| (revision, hash))))
+}
+
+fn read_page(paths: &ResolvedPaths, relative_path: &str) -> Result<String> {
+    let path = absolute_path_from_relative(paths, relative_path);
+    fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+fn base_archive_path(paths: &ResolvedPaths, hash: &str) -> PathBuf {
+    paths
+        .state_dir
+        .join("translations")
+        .join(format!("{hash}.wiki"))
+}
+
+fn archive_base_text(paths: &ResolvedPaths, hash: &str, content: &str) -> Result<()> {
+    let path = base_archive_path(paths, hash);
+    if path.exists() {
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    fs::write(&path, normalize_wiki_content(content))
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+fn render_marker(marker: &SyncMarker) -> String {
+    let revision = marker
+        .revision
+        .map(|revision| revision.to_string())
+        .unwrap_or_else(|| "local".to_string());
+    format!(
+        "{MARKER_PREFIX} revision={revision} hash={} {MARKER_SUFFIX}",
+        marker.hash
+    )
+}
+
+fn marker_span(content: &str) -> Option<(usize, usize)> {
+    let start = content.find(MARKER_PREFIX)?;
+    let end = start + content[start..].find(MARKER_SUFFIX)? + MARKER_SUFFIX.len();
+    Some((start, end))
+}
+
+fn parse_marker(content: &str) -> Option<SyncMarker> {
+    let (start, end) = marker_span(content)?;
+    let body = &content[start + MARKER_PREFIX.len()..end - MARKER_SUFFIX.len()];
+    let mut revision = None;
+    let mut hash = None;
+    for field in body.split_whitespace() {
+        match field.split_once('=') {
+            Some(("revision", value)) => revision = value.parse::<i64>().ok(),
+            Some(("hash", value)) if is_sync_hash(value) => hash = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    Some(SyncMarker {
+        revision,
+        hash: hash?,
+    })
+}
+
+/// Whether `value` has the shape of [`compute_wiki_sync_hash`] output. The
+/// hash names a file under the archive directory, so anything else in page
+/// content is ignored.
+fn is_sync_hash(value: &str) -> bool {
+    value.len() == 16
+        && value
+            .bytes()
+            .all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+/// Replace an existing marker in place, or put a new one on the first line.
+fn apply_marker(content: &str, marker: &SyncMarker) -> String {
+    let rendered = render_marker(marker);
+    match marker_span(content) {
+        Some((start, end)) => format!("{}{rendered}{}", &content[..start], &content[end..]),
+        None => format!("{rendered}\n{content}"),
+    }
+}
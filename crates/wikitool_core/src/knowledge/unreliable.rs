@@ -0,0 +1,105 @@
+use super::prelude::*;
+use crate::profile::UnreliableSourceRule;
+
+pub use super::model::{UnreliableSourcePage, UnreliableSourceUsage, UnreliableSourcesReport};
+
+/// How many indexed pages cite each source in `rules`, from the parsed
+/// `indexed_page_references` records. Every rule is listed, cited or not.
+pub fn query_unreliable_sources(
+    paths: &ResolvedPaths,
+    rules: &[UnreliableSourceRule],
+    page_limit: usize,
+) -> Result<Option<UnreliableSourcesReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    query_unreliable_sources_for_connection(&connection, rules, page_limit).map(Some)
+}
+
+pub(crate) fn query_unreliable_sources_for_connection(
+    connection: &Connection,
+    rules: &[UnreliableSourceRule],
+    page_limit: usize,
+) -> Result<UnreliableSourcesReport> {
+    let mut statement = connection
+        .prepare(
+            "SELECT source_title, source_domain, source_container, canonical_url, source_urls
+             FROM indexed_page_references
+             ORDER BY source_title ASC, reference_index ASC",
+        )
+        .context("failed to prepare unreliable source query")?;
+    let rows = statement
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+            ))
+        })
+        .context("failed to run unreliable source query")?;
+
+    let mut per_rule = vec![BTreeMap::<String, usize>::new(); rules.len()];
+    let mut citing_pages = BTreeSet::new();
+    let mut citation_count = 0usize;
+    for row in rows {
+        let (title, domain, container, canonical_url, source_urls) =
+            row.context("failed to decode unreliable source row")?;
+        let mut urls = parse_string_list(&source_urls);
+        urls.push(canonical_url);
+        let Some(index) = rules
+            .iter()
+            .position(|rule| rule.matches_citation(&domain, &urls, &container))
+        else {
+            continue;
+        };
+        *per_rule[index].entry(title.clone()).or_default() += 1;
+        citing_pages.insert(title);
+        citation_count += 1;
+    }
+
+    let mut sources = rules
+        .iter()
+        .zip(per_rule)
+        .map(|(rule, pages)| {
+            let mut pages = pages
+                .into_iter()
+                .map(|(title, citation_count)| UnreliableSourcePage {
+                    title,
+                    citation_count,
+                })
+                .collect::<Vec<_>>();
+            pages.sort_by(|left, right| {
+                right
+                    .citation_count
+                    .cmp(&left.citation_count)
+                    .then_with(|| left.title.cmp(&right.title))
+            });
+            let page_count = pages.len();
+            let citation_count = pages.iter().map(|page| page.citation_count).sum();
+            pages.truncate(page_limit);
+            UnreliableSourceUsage {
+                label: rule.label.clone(),
+                matcher: rule.matcher.clone(),
+                reason: rule.reason.clone(),
+                page_count,
+                citation_count,
+                pages,
+            }
+        })
+        .collect::<Vec<_>>();
+    sources.sort_by(|left, right| {
+        right
+            .page_count
+            .cmp(&left.page_count)
+            .then_with(|| left.label.cmp(&right.label))
+    });
+    Ok(UnreliableSourcesReport {
+        rule_count: rules.len(),
+        citing_page_count: citing_pages.len(),
+        citation_count,
+        sources,
+    })
+}
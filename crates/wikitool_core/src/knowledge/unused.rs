@@ -0,0 +1,278 @@
+use std::collections::VecDeque;
+use std::time::UNIX_EPOCH;
+
+use super::prelude::*;
+use crate::support::format_iso8601_utc;
+
+pub use super::model::{UnusedPage, UnusedPagesOptions, UnusedPagesReport};
+
+const CANDIDATE_NAMESPACES: [Namespace; 3] =
+    [Namespace::Template, Namespace::Module, Namespace::File];
+
+struct IndexedNode {
+    title: String,
+    namespace: String,
+    relative_path: String,
+    bytes: u64,
+}
+
+/// Template, Module and File pages not reachable from any content page.
+/// Usage is transitive: a template used only by unused templates is itself
+/// unused. Documentation subpages are neither roots nor candidates.
+pub fn query_unused_pages(
+    paths: &ResolvedPaths,
+    options: &UnusedPagesOptions,
+) -> Result<Option<UnusedPagesReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    query_unused_pages_for_connection(paths, &connection, options).map(Some)
+}
+
+pub(crate) fn query_unused_pages_for_connection(
+    paths: &ResolvedPaths,
+    connection: &Connection,
+    options: &UnusedPagesOptions,
+) -> Result<UnusedPagesReport> {
+    let nodes = load_indexed_nodes(connection)?;
+    let keys = nodes
+        .iter()
+        .map(|node| (node.title.to_ascii_lowercase(), node.title.clone()))
+        .collect::<BTreeMap<_, _>>();
+    let edges = load_usage_edges(paths, connection, &nodes, &keys)?;
+
+    let mut used = BTreeSet::new();
+    let mut queue = nodes
+        .iter()
+        .filter(|node| !is_candidate_namespace(&node.namespace) && !is_doc_subpage(&node.title))
+        .map(|node| node.title.clone())
+        .collect::<VecDeque<_>>();
+    while let Some(title) = queue.pop_front() {
+        if !used.insert(title.clone()) {
+            continue;
+        }
+        for target in edges.get(&title).into_iter().flatten() {
+            if !used.contains(target) {
+                queue.push_back(target.clone());
+            }
+        }
+    }
+
+    let mut referrers = BTreeMap::<&str, BTreeSet<&str>>::new();
+    for (source, targets) in &edges {
+        for target in targets {
+            if source != target {
+                referrers.entry(target).or_default().insert(source);
+            }
+        }
+    }
+
+    let selected = if options.namespaces.is_empty() {
+        CANDIDATE_NAMESPACES.to_vec()
+    } else {
+        options.namespaces.clone()
+    };
+    let mut pages = nodes
+        .iter()
+        .filter(|node| {
+            is_candidate_namespace(&node.namespace)
+                && selected
+                    .iter()
+                    .any(|namespace| namespace.as_str() == node.namespace)
+                && !is_doc_subpage(&node.title)
+                && !used.contains(&node.title)
+        })
+        .map(|node| {
+            let modified_at_unix = file_modified_unix(paths, &node.relative_path);
+            UnusedPage {
+                title: node.title.clone(),
+                namespace: node.namespace.clone(),
+                relative_path: node.relative_path.clone(),
+                bytes: node.bytes,
+                modified_at_unix,
+                modified_at: modified_at_unix.map(format_iso8601_utc),
+                used_only_by: referrers
+                    .get(node.title.as_str())
+                    .into_iter()
+                    .flatten()
+                    .filter(|source| !is_doc_subpage(source))
+                    .map(|source| source.to_string())
+                    .collect(),
+            }
+        })
+        .collect::<Vec<_>>();
+    pages.sort_by(|left, right| {
+        left.modified_at_unix
+            .cmp(&right.modified_at_unix)
+            .then_with(|| left.title.cmp(&right.title))
+    });
+
+    let count = |namespace: Namespace| {
+        pages
+            .iter()
+            .filter(|page| page.namespace == namespace.as_str())
+            .count()
+    };
+    let template_count = count(Namespace::Template);
+    let module_count = count(Namespace::Module);
+    let file_count = count(Namespace::File);
+    let candidate_count = pages.len();
+    pages.truncate(options.limit);
+    Ok(UnusedPagesReport {
+        candidate_count,
+        template_count,
+        module_count,
+        file_count,
+        pages,
+    })
+}
+
+fn load_indexed_nodes(connection: &Connection) -> Result<Vec<IndexedNode>> {
+    let mut statement = connection
+        .prepare(
+            "SELECT title, namespace, relative_path, bytes
+             FROM indexed_pages
+             ORDER BY title ASC",
+        )
+        .context("failed to prepare unused page node query")?;
+    let rows = statement
+        .query_map([], |row| {
+            Ok(IndexedNode {
+                title: row.get(0)?,
+                namespace: row.get(1)?,
+                relative_path: row.get(2)?,
+                bytes: u64::try_from(row.get::<_, i64>(3)?).unwrap_or_default(),
+            })
+        })
+        .context("failed to run unused page node query")?;
+    let mut out = Vec::new();
+    for row in rows {
+        out.push(row.context("failed to decode unused page node row")?);
+    }
+    Ok(out)
+}
+
+/// Usage edges from each page to the indexed pages it pulls in: template
+/// transclusions, `#invoke`s, media, file links, redirect targets, and Lua
+/// `require`/`mw.loadData`/`expandTemplate` references inside modules.
+fn load_usage_edges(
+    paths: &ResolvedPaths,
+    connection: &Connection,
+    nodes: &[IndexedNode],
+    keys: &BTreeMap<String, String>,
+) -> Result<BTreeMap<String, BTreeSet<String>>> {
+    let mut edges = BTreeMap::<String, BTreeSet<String>>::new();
+    let mut add = |source: String, target: &str| {
+        if let Some(target) = keys.get(&target.to_ascii_lowercase()) {
+            edges.entry(source).or_default().insert(target.clone());
+        }
+    };
+
+    for sql in [
+        "SELECT source_title, template_title FROM indexed_template_invocations",
+        "SELECT source_title, module_title FROM indexed_module_invocations",
+        "SELECT source_title, file_title FROM indexed_page_media",
+        "SELECT source_title, target_title FROM indexed_links WHERE target_namespace = 'File'",
+        "SELECT title, redirect_target FROM indexed_pages
+         WHERE is_redirect = 1 AND redirect_target IS NOT NULL",
+    ] {
+        let mut statement = connection
+            .prepare(sql)
+            .context("failed to prepare usage edge query")?;
+        let rows = statement
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .context("failed to run usage edge query")?;
+        for row in rows {
+            let (source, target) = row.context("failed to decode usage edge row")?;
+            let target = target.split('#').next().unwrap_or_default();
+            add(source, &normalize_query_title(target));
+        }
+    }
+
+    for node in nodes
+        .iter()
+        .filter(|node| node.namespace == Namespace::Module.as_str())
+    {
+        let path = absolute_path_from_relative(paths, &node.relative_path);
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        for target in lua_page_references(&content) {
+            add(node.title.clone(), &target);
+        }
+    }
+    Ok(edges)
+}
+
+/// Page titles named by `require`, `mw.loadData`, `mw.loadJsonData` and
+/// `expandTemplate{ title = ... }` in Lua source.
+fn lua_page_references(content: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    for keyword in ["require", "loadData", "loadJsonData"] {
+        for (index, _) in content.match_indices(keyword) {
+            let rest = content[index + keyword.len()..].trim_start();
+            let rest = rest.strip_prefix('(').unwrap_or(rest).trim_start();
+            if let Some(name) = lua_string_literal(rest)
+                && name.to_ascii_lowercase().starts_with("module:")
+            {
+                out.push(normalize_query_title(&name));
+            }
+        }
+    }
+    for (index, _) in content.match_indices("expandTemplate") {
+        let window = &content[index..];
+        let window = &window[..window
+            .char_indices()
+            .nth(120)
+            .map(|(end, _)| end)
+            .unwrap_or(window.len())];
+        let Some(title_at) = window.find("title") else {
+            continue;
+        };
+        let rest = window[title_at + "title".len()..].trim_start();
+        let Some(rest) = rest.strip_prefix('=') else {
+            continue;
+        };
+        if let Some(name) = lua_string_literal(rest.trim_start()) {
+            let title = normalize_query_title(&name);
+            if title.contains(':') {
+                out.push(title);
+            } else {
+                out.push(format!("Template:{title}"));
+            }
+        }
+    }
+    out
+}
+
+fn lua_string_literal(value: &str) -> Option<String> {
+    let quote = value
+        .chars()
+        .next()
+        .filter(|ch| *ch == '"' || *ch == '\'')?;
+    let body = &value[1..];
+    let end = body.find(quote)?;
+    Some(body[..end].to_string())
+}
+
+fn is_candidate_namespace(namespace: &str) -> bool {
+    CANDIDATE_NAMESPACES
+        .iter()
+        .any(|candidate| candidate.as_str() == namespace)
+}
+
+fn is_doc_subpage(title: &str) -> bool {
+    title.to_ascii_lowercase().ends_with("/doc")
+}
+
+fn file_modified_unix(paths: &ResolvedPaths, relative_path: &str) -> Option<u64> {
+    let path = absolute_path_from_relative(paths, relative_path);
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|duration| duration.as_secs())
+}
@@ -0,0 +1,175 @@
+use std::path::Path;
+
+use anyhow::bail;
+use serde_json::Value;
+
+use super::prelude::*;
+use crate::authoring::article_start::build_article_start;
+use crate::authoring::model::ArticleStartIntent;
+use crate::knowledge::authoring::{
+    AuthoringKnowledgePack, AuthoringKnowledgePackOptions, build_authoring_knowledge_pack,
+};
+use crate::profile::load_or_build_remilia_profile_overlay;
+use crate::support::compute_hash;
+use crate::wikitext::sentences::prose_sentences;
+
+pub use super::model::{
+    WantedPage, WantedPageBriefFile, WantedPageMention, WantedPagesOptions, WantedPagesReport,
+};
+
+/// Missing main-namespace link targets ranked by link occurrences, then by
+/// the number of distinct pages linking to them.
+pub fn query_wanted_pages(
+    paths: &ResolvedPaths,
+    options: &WantedPagesOptions,
+) -> Result<Option<WantedPagesReport>> {
+    let connection = match open_indexed_connection(paths)? {
+        Some(connection) => connection,
+        None => return Ok(None),
+    };
+    query_wanted_pages_for_connection(paths, &connection, options).map(Some)
+}
+
+pub(crate) fn query_wanted_pages_for_connection(
+    paths: &ResolvedPaths,
+    connection: &Connection,
+    options: &WantedPagesOptions,
+) -> Result<WantedPagesReport> {
+    let mut sources_by_target = BTreeMap::<String, BTreeSet<String>>::new();
+    for issue in query_broken_links_for_connection(connection)? {
+        sources_by_target
+            .entry(issue.target_title)
+            .or_default()
+            .insert(issue.source_title);
+    }
+    sources_by_target.retain(|_, sources| sources.len() >= options.min_sources.max(1));
+    let source_titles = sources_by_target
+        .values()
+        .flatten()
+        .cloned()
+        .collect::<BTreeSet<_>>();
+
+    let mut link_counts = BTreeMap::<String, usize>::new();
+    let mut mentions = BTreeMap::<String, Vec<WantedPageMention>>::new();
+    for source_title in &source_titles {
+        let Some(record) = load_page_record_exact(connection, source_title)? else {
+            continue;
+        };
+        let path = absolute_path_from_relative(paths, &record.relative_path);
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        for link in extract_wikilinks_for_namespace(&content, &record.namespace) {
+            if !link.is_category_membership && sources_by_target.contains_key(&link.target_title) {
+                *link_counts.entry(link.target_title).or_default() += 1;
+            }
+        }
+        for sentence in prose_sentences(&content) {
+            let mut seen = BTreeSet::new();
+            for target in &sentence.link_targets {
+                if !sources_by_target.contains_key(target) || !seen.insert(target) {
+                    continue;
+                }
+                mentions
+                    .entry(target.clone())
+                    .or_default()
+                    .push(WantedPageMention {
+                        source_title: source_title.clone(),
+                        line: sentence.line,
+                        sentence: sentence.text.clone(),
+                    });
+            }
+        }
+    }
+
+    let mut pages = sources_by_target
+        .into_iter()
+        .map(|(title, sources)| {
+            let mut page_mentions = mentions.remove(&title).unwrap_or_default();
+            page_mentions.truncate(options.mention_limit);
+            WantedPage {
+                link_count: link_counts
+                    .get(&title)
+                    .copied()
+                    .unwrap_or_default()
+                    .max(sources.len()),
+                source_count: sources.len(),
+                sources: sources.into_iter().collect(),
+                mentions: page_mentions,
+                title,
+            }
+        })
+        .collect::<Vec<_>>();
+    pages.sort_by(|left, right| {
+        right
+            .link_count
+            .cmp(&left.link_count)
+            .then(right.source_count.cmp(&left.source_count))
+            .then_with(|| left.title.cmp(&right.title))
+    });
+    let wanted_count = pages.len();
+    pages.truncate(options.limit);
+    Ok(WantedPagesReport {
+        wanted_count,
+        pages,
+    })
+}
+
+/// Run article-start for each wanted page and write one JSON brief per page
+/// into `output_dir`, alongside the mentions that motivated it.
+pub fn write_wanted_page_briefs(
+    paths: &ResolvedPaths,
+    pages: &[WantedPage],
+    output_dir: &Path,
+) -> Result<Vec<WantedPageBriefFile>> {
+    fs::create_dir_all(output_dir)
+        .with_context(|| format!("failed to create {}", output_dir.display()))?;
+    let overlay = load_or_build_remilia_profile_overlay(paths)?;
+    let options = AuthoringKnowledgePackOptions::default();
+    let mut out = Vec::new();
+    for page in pages {
+        let pack = build_authoring_knowledge_pack(paths, Some(&page.title), None, &options)?;
+        let report = match pack {
+            AuthoringKnowledgePack::Found(report) => report,
+            AuthoringKnowledgePack::IndexMissing | AuthoringKnowledgePack::QueryMissing => {
+                bail!("knowledge index is not built (run `wikitool knowledge build`)")
+            }
+        };
+        let article_start = build_article_start(&report, &overlay, ArticleStartIntent::New);
+        let local_state = match serde_json::to_value(&article_start.local_state)? {
+            Value::String(state) => state,
+            other => other.to_string(),
+        };
+        let path = output_dir.join(format!("{}.json", brief_file_stem(&page.title)));
+        let brief = json!({
+            "title": page.title,
+            "wanted": page,
+            "article_start": article_start,
+        });
+        fs::write(&path, serde_json::to_string_pretty(&brief)?)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        out.push(WantedPageBriefFile {
+            title: page.title.clone(),
+            path: normalize_path(&path),
+            local_state,
+        });
+    }
+    Ok(out)
+}
+
+/// File stem for a title's brief: the title with non-alphanumerics folded to
+/// `_`, plus a short title hash so titles that fold alike ("Foo/Bar" and
+/// "Foo Bar") still get their own file.
+pub(super) fn brief_file_stem(title: &str) -> String {
+    let stem = title
+        .chars()
+        .map(|ch| if ch.is_alphanumeric() { ch } else { '_' })
+        .collect::<String>();
+    let trimmed = stem.trim_matches('_');
+    let hash = compute_hash(title);
+    let suffix = &hash[..8];
+    if trimmed.is_empty() {
+        format!("untitled-{suffix}")
+    } else {
+        format!("{trimmed}-{suffix}")
+    }
+}
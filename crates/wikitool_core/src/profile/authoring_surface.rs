@@ -743,6 +743,7 @@ mod tests {
             }],
             examples: Vec::new(),
             recommendation_tags: Vec::new(),
+            added_categories: Vec::new(),
         }
     }
 
@@ -23,8 +23,8 @@ pub use remilia_overlay::{
 };
 pub use rules::{
     AuthoringRules, CategoryRules, CitationRules, CitationTemplateRule, GoldenSetRules,
-    InfoboxPreference, LintRules, ProfileOverlay, ProfileSourceDocument, RemiliaRules,
-    TemplateCatalogSummary, UnreliableSourceRule, WikiProfileSnapshot,
+    InfoboxPreference, LintRules, PhraseRewrite, ProfileOverlay, ProfileSourceDocument,
+    RemiliaRules, TemplateCatalogSummary, UnreliableSourceRule, WikiProfileSnapshot,
 };
 pub use template_catalog::{
     TemplateCatalog, TemplateCatalogEntry, TemplateCatalogEntryLookup, TemplateCatalogExample,
@@ -14,8 +14,8 @@ use crate::support::{compute_hash, normalize_path, unix_timestamp};
 
 use super::rules::{
     AuthoringRules, CategoryRules, CitationRules, CitationTemplateRule, ExtensionContractRule,
-    GoldenSetRules, InfoboxPreference, LintRules, ProfileOverlay, ProfileSourceDocument,
-    RemiliaRules, UnreliableSourceRule, WikiProfileSnapshot,
+    GoldenSetRules, InfoboxPreference, LintRules, PhraseRewrite, ProfileOverlay,
+    ProfileSourceDocument, RemiliaRules, UnreliableSourceRule, WikiProfileSnapshot,
 };
 use super::template_catalog::load_template_catalog;
 use super::wiki_capabilities::{
@@ -47,6 +47,7 @@ pub fn build_remilia_profile_overlay(paths: &ResolvedPaths) -> Result<ProfileOve
     );
     let banned_phrases = extract_banned_phrases(&style_rules);
     let watchlist_terms = extract_watchlist_terms(&style_rules);
+    let phrase_rewrites = extract_phrase_rewrites(&style_rules);
     let placeholder_fragments = extract_placeholder_fragments(&style_rules);
     let preferred_categories = if writing_guide.contains("[[Category:Remilia]]") {
         vec!["Category:Remilia".to_string()]
@@ -161,6 +162,7 @@ pub fn build_remilia_profile_overlay(paths: &ResolvedPaths) -> Result<ProfileOve
             forbid_curly_quotes: style_rules.contains("any curly quotes"),
             forbid_placeholder_fragments: placeholder_fragments,
             proper_nouns: default_proper_nouns(),
+            phrase_rewrites,
         },
         extension_contracts: extract_extension_contracts(&extensions_doc),
         golden_set: GoldenSetRules {
@@ -512,7 +514,11 @@ fn extract_infobox_preferences(content: &str) -> Vec<InfoboxPreference> {
 
 fn extract_unreliable_sources(content: &str) -> Vec<UnreliableSourceRule> {
     let mut out = Vec::new();
-    for heading in ["### Never cite:", "## VI. Unreliable sources"] {
+    for heading in [
+        "### Never cite:",
+        "**Never cite:**",
+        "## VI. Unreliable sources",
+    ] {
         let Some(section) = extract_markdown_section(content, heading) else {
             continue;
         };
@@ -522,25 +528,41 @@ fn extract_unreliable_sources(content: &str) -> Vec<UnreliableSourceRule> {
                 continue;
             }
             let item = trimmed.trim_start_matches("- ").trim();
-            let (label, matcher) = parse_source_rule(item);
+            let (label, matcher, reason) = parse_source_rule(item);
             if !label.is_empty() {
-                out.push(UnreliableSourceRule { label, matcher });
+                out.push(UnreliableSourceRule {
+                    label,
+                    matcher,
+                    reason,
+                });
             }
         }
     }
     out
 }
 
+/// Merge rules naming the same source; a domain matcher (`iq.wiki`) wins over
+/// a bare name, and the first non-empty reason is kept.
 fn merge_unreliable_sources(
     left: &[UnreliableSourceRule],
     right: &[UnreliableSourceRule],
 ) -> Vec<UnreliableSourceRule> {
-    let mut out = Vec::new();
-    let mut seen = BTreeSet::new();
+    let mut out: Vec<UnreliableSourceRule> = Vec::new();
     for rule in left.iter().chain(right.iter()) {
-        let key = rule.matcher.to_ascii_lowercase();
-        if seen.insert(key) {
-            out.push(rule.clone());
+        let existing = out.iter_mut().find(|existing| {
+            existing.label.eq_ignore_ascii_case(&rule.label)
+                || existing.matcher.eq_ignore_ascii_case(&rule.matcher)
+        });
+        match existing {
+            Some(existing) => {
+                if !existing.matcher.contains('.') && rule.matcher.contains('.') {
+                    existing.matcher = rule.matcher.clone();
+                }
+                if existing.reason.is_empty() {
+                    existing.reason = rule.reason.clone();
+                }
+            }
+            None => out.push(rule.clone()),
         }
     }
     out
@@ -595,6 +617,40 @@ fn extract_watchlist_terms(content: &str) -> Vec<String> {
     out
 }
 
+/// Rows of `| Bad ... | Good ... |` tables, e.g. the copula-avoidance table.
+fn extract_phrase_rewrites(content: &str) -> Vec<PhraseRewrite> {
+    let mut out = Vec::new();
+    let mut seen = BTreeSet::new();
+    let mut in_table = false;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if !trimmed.starts_with('|') {
+            in_table = false;
+            continue;
+        }
+        let cells = split_markdown_row(trimmed);
+        if cells.len() < 2 {
+            continue;
+        }
+        if cells[0].starts_with("Bad") && cells[1].starts_with("Good") {
+            in_table = true;
+            continue;
+        }
+        if !in_table || cells[0].starts_with("---") {
+            continue;
+        }
+        let (phrase, replacement) = (cells[0].clone(), cells[1].clone());
+        if !phrase.is_empty() && !replacement.is_empty() && seen.insert(phrase.to_ascii_lowercase())
+        {
+            out.push(PhraseRewrite {
+                phrase,
+                replacement,
+            });
+        }
+    }
+    out
+}
+
 fn extract_placeholder_fragments(content: &str) -> Vec<String> {
     let mut out = Vec::new();
     let mut seen = BTreeSet::new();
@@ -612,22 +668,21 @@ fn extract_placeholder_fragments(content: &str) -> Vec<String> {
     out
 }
 
-fn parse_source_rule(value: &str) -> (String, String) {
-    let label = value
-        .split('—')
-        .next()
-        .map(collapse_whitespace)
-        .unwrap_or_default();
-    let matcher = if let Some(start) = value.find('(') {
-        if let Some(end_rel) = value[start + 1..].find(')') {
-            collapse_whitespace(&value[start + 1..start + 1 + end_rel])
-        } else {
-            label.to_ascii_lowercase()
-        }
-    } else {
-        label.to_ascii_lowercase()
-    };
-    (label, matcher)
+/// Split `Label (matcher) — reason` into its parts; the matcher defaults to
+/// the lowercased label.
+fn parse_source_rule(value: &str) -> (String, String, String) {
+    let (head, reason) = value.split_once('—').unwrap_or((value, ""));
+    let label = collapse_whitespace(head.split('(').next().unwrap_or_default());
+    let matcher = head
+        .find('(')
+        .and_then(|start| {
+            head[start + 1..]
+                .find(')')
+                .map(|end| collapse_whitespace(&head[start + 1..start + 1 + end]))
+        })
+        .filter(|matcher| !matcher.is_empty())
+        .unwrap_or_else(|| label.to_ascii_lowercase());
+    (label, matcher, collapse_whitespace(reason))
 }
 
 fn extract_template_title(value: &str) -> Option<String> {
@@ -740,8 +795,10 @@ mod tests {
     use super::{
         ARTICLE_STRUCTURE_PATH, STYLE_RULES_PATH, WRITING_GUIDE_PATH,
         build_remilia_profile_overlay, extract_banned_phrases, extract_citation_templates,
-        extract_extension_contracts, extract_infobox_preferences, extract_placeholder_fragments,
+        extract_extension_contracts, extract_infobox_preferences, extract_phrase_rewrites,
+        extract_placeholder_fragments,
     };
+    use crate::profile::PhraseRewrite;
 
     #[test]
     fn extension_contracts_parse_from_contract_lines() {
@@ -873,6 +930,15 @@ Contract: kind=parser_function; name=ev; provider=EmbedVideo; syntax=parser_func
             extract_placeholder_fragments(style),
             vec!["[Author Name]".to_string(), "INSERT_SOURCE_URL".to_string()]
         );
+
+        let table = "| Bad (AI pattern) | Good (natural) |\n|---|---|\n| serves as the headquarters | is the headquarters |\n\n| Other | Table |\n| a | b |\n";
+        assert_eq!(
+            extract_phrase_rewrites(table),
+            vec![PhraseRewrite {
+                phrase: "serves as the headquarters".to_string(),
+                replacement: "is the headquarters".to_string(),
+            }]
+        );
     }
 
     #[test]
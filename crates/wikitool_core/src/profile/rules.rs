@@ -1,5 +1,6 @@
 use std::collections::BTreeSet;
 
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
 use super::wiki_capabilities::WikiCapabilityManifest;
@@ -20,6 +21,9 @@ pub struct CitationTemplateRule {
 pub struct UnreliableSourceRule {
     pub label: String,
     pub matcher: String,
+    /// Why the guide rejects the source, e.g. "inaccurate, user-generated".
+    #[serde(default)]
+    pub reason: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -78,6 +82,16 @@ pub struct LintRules {
     /// non-leading word of a multi-word brand). Defaulted for older cached overlays.
     #[serde(default)]
     pub proper_nouns: Vec<String>,
+    /// Bad/good phrase pairs from the style guide; the bad side is linted like a
+    /// banned phrase and the good side is offered as the rewrite.
+    #[serde(default)]
+    pub phrase_rewrites: Vec<PhraseRewrite>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PhraseRewrite {
+    pub phrase: String,
+    pub replacement: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -128,6 +142,59 @@ pub struct ExtensionContractRule {
     pub example: String,
 }
 
+impl UnreliableSourceRule {
+    /// Whether a citation points at this source. Domain matchers cover the
+    /// domain and its subdomains, both for the citation's own URLs and for
+    /// archived copies that embed the original URL; name matchers compare
+    /// against the citation's publisher or work.
+    pub fn matches_citation(&self, domain: &str, urls: &[String], publisher: &str) -> bool {
+        let matcher = self.matcher.trim().to_ascii_lowercase();
+        if matcher.is_empty() {
+            return false;
+        }
+        if is_domain_matcher(&matcher) {
+            if host_matches(&domain.trim().to_ascii_lowercase(), &matcher) {
+                return true;
+            }
+            if urls
+                .iter()
+                .flat_map(|url| url_hosts(url))
+                .any(|host| host_matches(&host, &matcher))
+            {
+                return true;
+            }
+        }
+        let publisher = publisher.split_whitespace().collect::<Vec<_>>().join(" ");
+        !publisher.is_empty()
+            && (publisher.eq_ignore_ascii_case(&matcher)
+                || publisher.eq_ignore_ascii_case(self.label.trim()))
+    }
+}
+
+fn host_matches(host: &str, domain: &str) -> bool {
+    host == domain || host.ends_with(&format!(".{domain}"))
+}
+
+/// Lowercased hosts of `url` and of any http(s) URL embedded in it, as in
+/// `https://web.archive.org/web/2020/https://example.com/page`.
+fn url_hosts(url: &str) -> Vec<String> {
+    let url = url.trim().to_ascii_lowercase();
+    let url = if url.starts_with("//") {
+        format!("https:{url}")
+    } else {
+        url
+    };
+    url.match_indices("http")
+        .filter_map(|(start, _)| Url::parse(&url[start..]).ok())
+        .filter(|parsed| matches!(parsed.scheme(), "http" | "https"))
+        .filter_map(|parsed| parsed.host_str().map(str::to_string))
+        .collect()
+}
+
+fn is_domain_matcher(matcher: &str) -> bool {
+    matcher.contains('.') && !matcher.contains(char::is_whitespace)
+}
+
 impl ProfileOverlay {
     pub fn profile_template_titles(&self) -> Vec<String> {
         let mut titles = BTreeSet::new();
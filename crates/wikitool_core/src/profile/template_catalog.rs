@@ -24,7 +24,7 @@ use super::rules::{ProfileOverlay, TemplateCatalogSummary};
 use super::template_data::{
     LocalTemplateExample, TemplateDataParameter, TemplateDataRecord, extract_module_references,
     extract_source_parameters, extract_summary_text, extract_template_data,
-    extract_template_examples,
+    extract_template_examples, extract_transcluded_categories,
 };
 mod entry;
 mod local;
@@ -92,6 +92,7 @@ pub(super) fn build_catalog_entry(
         parameters,
         examples: merge_examples(template.local_examples, usage),
         recommendation_tags: recommendation_tags(&template.template_title, overlay),
+        added_categories: template.added_categories,
     }
 }
 
@@ -57,6 +57,7 @@ pub(super) fn load_local_templates(
         let templatedata = extract_template_data(&content)?;
         let declared_parameter_keys = extract_source_parameters(&content);
         let module_titles = extract_module_references(&content);
+        let added_categories = extract_transcluded_categories(&content);
         let mut local_examples = extract_template_examples(
             &content,
             &normalized_title,
@@ -80,6 +81,7 @@ pub(super) fn load_local_templates(
                 documentation_pages: Vec::new(),
                 local_examples: std::mem::take(&mut local_examples),
                 module_titles,
+                added_categories,
             },
         );
     }
@@ -71,6 +71,9 @@ pub struct TemplateCatalogEntry {
     pub parameters: Vec<TemplateCatalogParameter>,
     pub examples: Vec<TemplateCatalogExample>,
     pub recommendation_tags: Vec<String>,
+    /// Categories the template adds to pages that transclude it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added_categories: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -119,4 +122,5 @@ pub(super) struct LocalTemplateRecord {
     pub(super) documentation_pages: Vec<LocalDocumentationPage>,
     pub(super) local_examples: Vec<LocalTemplateExample>,
     pub(super) module_titles: Vec<String>,
+    pub(super) added_categories: Vec<String>,
 }
@@ -214,6 +214,7 @@ fn template_catalog_lookup_matches_aliases() {
             parameters: Vec::new(),
             examples: Vec::new(),
             recommendation_tags: Vec::new(),
+            added_categories: Vec::new(),
         }],
     };
 
@@ -4,6 +4,8 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::content_store::parsing::extract_wikilinks_for_namespace;
+use crate::filesystem::Namespace;
 use crate::knowledge::templates::normalize_template_lookup_title;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -88,8 +90,11 @@ pub(crate) fn extract_template_data(content: &str) -> Result<Option<TemplateData
             let deprecated = param
                 .as_object()
                 .and_then(|value| value.get("deprecated"))
-                .and_then(Value::as_bool)
-                .unwrap_or(false);
+                .is_some_and(|value| match value {
+                    // TemplateData allows a deprecation note in place of `true`.
+                    Value::String(note) => !note.trim().is_empty(),
+                    other => other.as_bool().unwrap_or(false),
+                });
             let example = param
                 .as_object()
                 .and_then(|value| string_field(value.get("example")));
@@ -179,6 +184,23 @@ pub(crate) fn extract_module_references(content: &str) -> Vec<String> {
     out
 }
 
+/// Static `[[Category:...]]` memberships a template hands to the pages that
+/// transclude it: `<noinclude>` sections are skipped, and parameter-driven
+/// targets such as `[[Category:{{{1}}}]]` are left out.
+pub(crate) fn extract_transcluded_categories(content: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut seen = BTreeSet::new();
+    for link in extract_wikilinks_for_namespace(content, Namespace::Template.as_str()) {
+        if !link.is_category_membership || link.target_title.contains('{') {
+            continue;
+        }
+        if seen.insert(link.target_title.to_ascii_lowercase()) {
+            out.push(link.target_title);
+        }
+    }
+    out
+}
+
 pub(crate) fn extract_template_examples(
     content: &str,
     template_title: &str,
@@ -167,6 +167,62 @@ const REQUIRED_TEMPLATE_IMPLEMENTATION_COLUMNS: &[&str] = &[
     "role",
 ];
 
+const REQUIRED_CENTRALITY_COLUMNS: &[&str] = &[
+    "source_relative_path",
+    "source_title",
+    "source_namespace",
+    "pagerank",
+    "hub_score",
+    "authority_score",
+];
+
+const REQUIRED_PAGE_QUALITY_COLUMNS: &[&str] = &[
+    "source_relative_path",
+    "source_title",
+    "source_namespace",
+    "prose_words",
+    "reference_count",
+    "references_per_100_words",
+    "uncited_paragraphs",
+    "paragraph_count",
+    "section_count",
+    "largest_section_share",
+    "lead_words",
+    "image_count",
+    "inbound_links",
+];
+
+const REQUIRED_INFOBOX_FACT_COLUMNS: &[&str] = &[
+    "source_relative_path",
+    "invocation_index",
+    "parameter_key",
+    "source_title",
+    "source_namespace",
+    "template_title",
+    "raw_value",
+    "value_kind",
+    "normalized_value",
+];
+
+const REQUIRED_INDEX_BUILD_SNAPSHOT_COLUMNS: &[&str] = &[
+    "build_id",
+    "built_at_unix",
+    "page_count",
+    "link_count",
+    "reference_count",
+    "snapshot_json",
+];
+
+const REQUIRED_EXTERNAL_LINK_CHECK_COLUMNS: &[&str] = &[
+    "url",
+    "host",
+    "status",
+    "http_status",
+    "final_url",
+    "detail",
+    "checked_at_unix",
+];
+
 const REQUIRED_AUTHORING_CONTRACT_COLUMNS: &[&str] = &[
     "profile",
     "contract_key",
@@ -395,6 +451,31 @@ fn validate_disposable_schema(connection: &Connection) -> Result<()> {
         "indexed_template_implementation_pages",
         REQUIRED_TEMPLATE_IMPLEMENTATION_COLUMNS,
     )?;
+    require_columns(
+        connection,
+        "indexed_page_centrality",
+        REQUIRED_CENTRALITY_COLUMNS,
+    )?;
+    require_columns(
+        connection,
+        "indexed_page_quality",
+        REQUIRED_PAGE_QUALITY_COLUMNS,
+    )?;
+    require_columns(
+        connection,
+        "indexed_infobox_facts",
+        REQUIRED_INFOBOX_FACT_COLUMNS,
+    )?;
+    require_columns(
+        connection,
+        "index_build_snapshots",
+        REQUIRED_INDEX_BUILD_SNAPSHOT_COLUMNS,
+    )?;
+    require_columns(
+        connection,
+        "external_link_checks",
+        REQUIRED_EXTERNAL_LINK_CHECK_COLUMNS,
+    )?;
     require_columns(
         connection,
         "authoring_contracts",
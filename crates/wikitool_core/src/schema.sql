@@ -432,6 +432,90 @@ CREATE INDEX IF NOT EXISTS idx_indexed_template_implementation_pages_template
 CREATE INDEX IF NOT EXISTS idx_indexed_template_implementation_pages_role
     ON indexed_template_implementation_pages(role);
 
+-- Link-graph centrality computed once per index build: PageRank plus HITS hub
+-- and authority scores over ordinary wikilinks. Used as a tie-break prior by
+-- retrieval reranking and comparable-page selection.
+CREATE TABLE IF NOT EXISTS indexed_page_centrality (
+    source_relative_path TEXT PRIMARY KEY,
+    source_title TEXT NOT NULL,
+    source_namespace TEXT NOT NULL,
+    pagerank REAL NOT NULL,
+    hub_score REAL NOT NULL,
+    authority_score REAL NOT NULL,
+    FOREIGN KEY (source_relative_path) REFERENCES indexed_pages(relative_path) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS idx_indexed_page_centrality_title
+    ON indexed_page_centrality(source_title);
+CREATE INDEX IF NOT EXISTS idx_indexed_page_centrality_pagerank
+    ON indexed_page_centrality(pagerank DESC);
+
+-- Per-article quality metrics computed once per index build for main-namespace
+-- content pages; `knowledge inspect quality` sorts and filters these.
+CREATE TABLE IF NOT EXISTS indexed_page_quality (
+    source_relative_path TEXT PRIMARY KEY,
+    source_title TEXT NOT NULL,
+    source_namespace TEXT NOT NULL,
+    prose_words INTEGER NOT NULL,
+    reference_count INTEGER NOT NULL,
+    references_per_100_words REAL NOT NULL,
+    uncited_paragraphs INTEGER NOT NULL,
+    paragraph_count INTEGER NOT NULL,
+    section_count INTEGER NOT NULL,
+    largest_section_share REAL NOT NULL,
+    lead_words INTEGER NOT NULL,
+    image_count INTEGER NOT NULL,
+    inbound_links INTEGER NOT NULL,
+    FOREIGN KEY (source_relative_path) REFERENCES indexed_pages(relative_path) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS idx_indexed_page_quality_title
+    ON indexed_page_quality(source_title);
+
+-- Infobox parameter values for templates mapped to the infobox category in
+-- template_category_mappings, with a typed normalized value (date, number,
+-- link, url, text, or empty) for cross-page fact queries.
+CREATE TABLE IF NOT EXISTS indexed_infobox_facts (
+    source_relative_path TEXT NOT NULL,
+    invocation_index INTEGER NOT NULL,
+    parameter_key TEXT NOT NULL,
+    source_title TEXT NOT NULL,
+    source_namespace TEXT NOT NULL,
+    template_title TEXT NOT NULL,
+    raw_value TEXT NOT NULL,
+    value_kind TEXT NOT NULL,
+    normalized_value TEXT NOT NULL,
+    PRIMARY KEY (source_relative_path, invocation_index, parameter_key),
+    FOREIGN KEY (source_relative_path) REFERENCES indexed_pages(relative_path) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS idx_indexed_infobox_facts_template_param
+    ON indexed_infobox_facts(template_title, parameter_key);
+CREATE INDEX IF NOT EXISTS idx_indexed_infobox_facts_title
+    ON indexed_infobox_facts(source_title);
+
+-- Compact structural snapshot (links, categories, reference counts, validation
+-- findings) recorded after each index rebuild for `knowledge diff`.
+CREATE TABLE IF NOT EXISTS index_build_snapshots (
+    build_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    built_at_unix INTEGER NOT NULL,
+    page_count INTEGER NOT NULL,
+    link_count INTEGER NOT NULL,
+    reference_count INTEGER NOT NULL,
+    snapshot_json TEXT NOT NULL
+);
+
+-- Cached results of `validate external-links` HTTP checks keyed by cited URL.
+-- Not tied to indexed_pages, so results survive index rebuilds.
+CREATE TABLE IF NOT EXISTS external_link_checks (
+    url TEXT PRIMARY KEY,
+    host TEXT NOT NULL,
+    status TEXT NOT NULL,
+    http_status INTEGER,
+    final_url TEXT,
+    detail TEXT,
+    checked_at_unix INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_external_link_checks_status
+    ON external_link_checks(status);
+
 CREATE TABLE IF NOT EXISTS authoring_contracts (
     profile TEXT NOT NULL,
     contract_key TEXT PRIMARY KEY,
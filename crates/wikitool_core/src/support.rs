@@ -116,16 +116,7 @@ pub fn format_iso8601_utc(unix_seconds: u64) -> String {
     let minute = (time_of_day % 3_600) / 60;
     let second = time_of_day % 60;
 
-    let z = days + 719_468;
-    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
-    let doe = (z - era * 146_097) as u64;
-    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
-    let year_base = yoe as i64 + era * 400;
-    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
-    let mp = (5 * doy + 2) / 153;
-    let day = doy - (153 * mp + 2) / 5 + 1;
-    let month = if mp < 10 { mp + 3 } else { mp - 9 };
-    let year = year_base + if month <= 2 { 1 } else { 0 };
+    let (year, month, day) = civil_from_days(days);
 
     format!(
         "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z",
@@ -138,6 +129,42 @@ pub fn format_iso8601_utc(unix_seconds: u64) -> String {
     )
 }
 
+/// Convert days since 1970-01-01 into a proleptic Gregorian `(year, month, day)`.
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let year_base = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_base + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Inverse of [`civil_from_days`]: days since 1970-01-01 for a civil date.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yoe = (year - era * 400) as u64;
+    let month = u64::from(month);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + u64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe as i64 - 719_468
+}
+
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
 /// Current wall clock in ISO-8601 UTC, or a deterministic epoch string if the clock
 /// is unreadable (preserves the previous never-panics contract of `now_timestamp_string`).
 pub fn now_iso8601_utc() -> String {
@@ -171,8 +198,9 @@ mod tests {
     use std::path::{Path, PathBuf};
 
     use super::{
-        compute_hash, compute_wiki_sync_hash, format_iso8601_utc, normalize_path,
-        normalize_pathbuf, normalize_wiki_content, parse_redirect,
+        civil_from_days, compute_hash, compute_wiki_sync_hash, days_from_civil, days_in_month,
+        format_iso8601_utc, normalize_path, normalize_pathbuf, normalize_wiki_content,
+        parse_redirect,
     };
 
     #[test]
@@ -235,6 +263,16 @@ mod tests {
         assert_eq!(format_iso8601_utc(1_767_225_599), "2025-12-31T23:59:59Z");
     }
 
+    #[test]
+    fn civil_day_conversions_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2024, 2, 29), 19_782);
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(1900, 2), 28);
+    }
+
     #[test]
     fn redirect_parser_extracts_target() {
         assert_eq!(
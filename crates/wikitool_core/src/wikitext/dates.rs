@@ -0,0 +1,177 @@
+//! Hand-rolled prose and wikitext date recognition shared by staleness and fact extraction.
+
+use crate::support::{days_from_civil, days_in_month};
+
+const MONTH_NAMES: &[&str] = &[
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+const MIN_DATE_YEAR: i64 = 1900;
+const MAX_DATE_YEAR: i64 = 2100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ParsedDate {
+    pub year: i64,
+    pub month: Option<u32>,
+    pub day: Option<u32>,
+    /// Byte offset just past the date within the parsed text.
+    pub end: usize,
+}
+
+impl ParsedDate {
+    /// Last day the date can refer to, in days since the UNIX epoch. A bare
+    /// year resolves to December 31 and a month to its final day.
+    pub(crate) fn last_day(self) -> i64 {
+        let month = self.month.unwrap_or(12);
+        let day = self
+            .day
+            .unwrap_or_else(|| days_in_month(self.year, month))
+            .min(days_in_month(self.year, month));
+        days_from_civil(self.year, month, day)
+    }
+
+    /// `YYYY`, `YYYY-MM`, or `YYYY-MM-DD` depending on precision.
+    pub(crate) fn iso(self) -> String {
+        match (self.month, self.day) {
+            (Some(month), Some(day)) => format!("{:04}-{month:02}-{day:02}", self.year),
+            (Some(month), None) => format!("{:04}-{month:02}", self.year),
+            _ => format!("{:04}", self.year),
+        }
+    }
+}
+
+/// Find the first date anywhere in `text`: ISO `YYYY-MM-DD`, `5 March 2023`,
+/// `March 5, 2023`, `March 2023`, or a bare year.
+pub(crate) fn find_date(text: &str) -> Option<ParsedDate> {
+    let tokens = word_tokens(text);
+    let year_index = tokens
+        .iter()
+        .position(|&(start, end)| parse_year(&text[start..end]).is_some())?;
+    date_at(text, &tokens, year_index)
+}
+
+/// Like [`find_date`], but only month/day words may precede the year.
+pub(crate) fn find_leading_date(text: &str) -> Option<ParsedDate> {
+    let tokens = word_tokens(text);
+    for (index, &(start, end)) in tokens.iter().enumerate().take(4) {
+        let token = &text[start..end];
+        if parse_year(token).is_some() {
+            return date_at(text, &tokens, index);
+        }
+        if parse_month(token).is_none() && parse_day(token).is_none() {
+            return None;
+        }
+    }
+    None
+}
+
+fn date_at(text: &str, tokens: &[(usize, usize)], year_index: usize) -> Option<ParsedDate> {
+    let (year_start, year_end) = tokens[year_index];
+    let year = parse_year(&text[year_start..year_end])?;
+
+    if text[year_end..].starts_with('-')
+        && let (Some(&(month_start, month_end)), Some(&(day_start, day_end))) =
+            (tokens.get(year_index + 1), tokens.get(year_index + 2))
+        && let Some(month) = text[month_start..month_end]
+            .parse::<u32>()
+            .ok()
+            .filter(|month| (1..=12).contains(month))
+        && let Some(day) = parse_day(&text[day_start..day_end])
+    {
+        return Some(ParsedDate {
+            year,
+            month: Some(month),
+            day: Some(day),
+            end: day_end,
+        });
+    }
+
+    let token = |offset: usize| {
+        year_index
+            .checked_sub(offset)
+            .map(|index| &text[tokens[index].0..tokens[index].1])
+    };
+    let mut month = None;
+    let mut day = None;
+    if let Some(value) = token(1).and_then(parse_month_name) {
+        month = Some(value);
+        day = token(2).and_then(parse_day);
+    } else if let Some(value) = token(2).and_then(parse_month_name) {
+        month = Some(value);
+        day = token(1).and_then(parse_day);
+    }
+    Some(ParsedDate {
+        year,
+        month,
+        day,
+        end: year_end,
+    })
+}
+
+pub(crate) fn parse_year(token: &str) -> Option<i64> {
+    if token.len() != 4 || !token.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    token
+        .parse::<i64>()
+        .ok()
+        .filter(|year| (MIN_DATE_YEAR..=MAX_DATE_YEAR).contains(year))
+}
+
+pub(crate) fn parse_day(token: &str) -> Option<u32> {
+    let digits = token.trim_end_matches(|ch: char| ch.is_ascii_alphabetic());
+    if digits.is_empty() || digits.len() > 2 {
+        return None;
+    }
+    digits
+        .parse::<u32>()
+        .ok()
+        .filter(|day| (1..=31).contains(day))
+}
+
+pub(crate) fn parse_month(token: &str) -> Option<u32> {
+    token
+        .parse::<u32>()
+        .ok()
+        .or_else(|| parse_month_name(token))
+}
+
+pub(crate) fn parse_month_name(token: &str) -> Option<u32> {
+    let lowered = token.to_ascii_lowercase();
+    if lowered.len() < 3 {
+        return None;
+    }
+    MONTH_NAMES
+        .iter()
+        .position(|name| *name == lowered || (lowered.len() <= 4 && name.starts_with(&lowered)))
+        .map(|index| index as u32 + 1)
+}
+
+fn word_tokens(text: &str) -> Vec<(usize, usize)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (offset, ch) in text.char_indices() {
+        match (ch.is_alphanumeric(), start) {
+            (true, None) => start = Some(offset),
+            (false, Some(begin)) => {
+                tokens.push((begin, offset));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        tokens.push((begin, text.len()));
+    }
+    tokens
+}
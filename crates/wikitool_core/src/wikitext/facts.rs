@@ -0,0 +1,426 @@
+//! Infobox parameter extraction and typed value normalization for the indexed fact store.
+
+use crate::content_store::parsing::{
+    canonical_template_title, flatten_markup_excerpt, html_comment_ranges, html_element_ranges,
+    normalize_spaces, normalize_template_parameter_key, parse_wikilink,
+    split_once_top_level_equals, split_template_segments,
+};
+use crate::wikitext::dates::{ParsedDate, find_leading_date, parse_day, parse_month, parse_year};
+use crate::wikitext::spans::{remove_ranges, template_ranges, top_level_ranges};
+
+/// Parameter keys whose bare four-digit values read as years rather than counts.
+const DATE_KEY_HINTS: &[&str] = &[
+    "date",
+    "founded",
+    "established",
+    "formed",
+    "born",
+    "birth",
+    "died",
+    "death",
+    "released",
+    "release",
+    "launched",
+    "launch",
+    "year",
+    "since",
+    "start",
+    "end",
+    "created",
+    "dissolved",
+    "inception",
+    "opened",
+];
+const LIST_TEMPLATES: &[&str] = &[
+    "hlist",
+    "flatlist",
+    "plainlist",
+    "plain list",
+    "ubl",
+    "unbulleted list",
+];
+const NUMBER_MULTIPLIERS: &[(&str, f64)] = &[
+    ("thousand", 1e3),
+    ("million", 1e6),
+    ("billion", 1e9),
+    ("trillion", 1e12),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FactValueKind {
+    Text,
+    Date,
+    Number,
+    Link,
+    Url,
+    Empty,
+}
+
+impl FactValueKind {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Date => "date",
+            Self::Number => "number",
+            Self::Link => "link",
+            Self::Url => "url",
+            Self::Empty => "empty",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct InfoboxFact {
+    pub template_title: String,
+    pub invocation_index: usize,
+    pub parameter_key: String,
+    pub raw_value: String,
+    pub value_kind: FactValueKind,
+    /// ISO date, plain decimal, link target title, URL, or markup-free text.
+    pub normalized_value: String,
+}
+
+/// Extract every parameter of the template or `#invoke` calls accepted by
+/// `is_infobox`, which receives the canonical `Template:` or `Module:` title.
+pub(crate) fn extract_infobox_facts(
+    content: &str,
+    is_infobox: impl Fn(&str) -> bool,
+) -> Vec<InfoboxFact> {
+    let mut facts = Vec::new();
+    let mut invocation_index = 0usize;
+    for (start, end) in template_ranges(content) {
+        let segments = split_template_segments(&content[start + 2..end - 2]);
+        let Some((template_title, skip)) = segments.first().and_then(|name| invocation_title(name))
+        else {
+            continue;
+        };
+        if !is_infobox(&template_title) {
+            continue;
+        }
+
+        let mut parameters = Vec::<(String, String)>::new();
+        let mut positional_index = 1usize;
+        for segment in segments.iter().skip(skip) {
+            let (key, value) = match split_once_top_level_equals(segment) {
+                Some((key, value)) => (normalize_template_parameter_key(&key), value),
+                None => {
+                    let key = format!("${positional_index}");
+                    positional_index += 1;
+                    (key, segment.clone())
+                }
+            };
+            if key.is_empty() {
+                continue;
+            }
+            // Repeated keys follow MediaWiki: the last assignment wins.
+            parameters.retain(|(existing, _)| existing != &key);
+            parameters.push((key, value.trim().to_string()));
+        }
+
+        for (parameter_key, raw_value) in parameters {
+            let (value_kind, normalized_value) = normalize_fact_value(&parameter_key, &raw_value);
+            facts.push(InfoboxFact {
+                template_title: template_title.clone(),
+                invocation_index,
+                parameter_key,
+                raw_value,
+                value_kind,
+                normalized_value,
+            });
+        }
+        invocation_index += 1;
+    }
+    facts
+}
+
+/// Returns the canonical title and how many leading segments precede parameters.
+fn invocation_title(name: &str) -> Option<(String, usize)> {
+    let trimmed = name.trim();
+    if let Some(module) = trimmed
+        .get(..8)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("#invoke:"))
+        .map(|_| &trimmed[8..])
+    {
+        let module = normalize_spaces(&module.replace('_', " "));
+        return (!module.is_empty()).then(|| (format!("Module:{module}"), 2));
+    }
+    canonical_template_title(trimmed).map(|title| (title, 1))
+}
+
+pub(crate) fn normalize_fact_value(key: &str, raw: &str) -> (FactValueKind, String) {
+    let mut noise = html_comment_ranges(raw);
+    noise.extend(html_element_ranges(raw, "ref"));
+    noise.sort_unstable();
+    let cleaned = remove_ranges(raw, &noise);
+    let cleaned = cleaned.trim();
+    if cleaned.is_empty() {
+        return (FactValueKind::Empty, String::new());
+    }
+
+    if let Some(fact) = single_template_fact(cleaned) {
+        return fact;
+    }
+    if let Some(inner) = cleaned
+        .strip_prefix("[[")
+        .and_then(|rest| rest.strip_suffix("]]"))
+        .filter(|inner| !inner.contains("[[") && !inner.contains("]]"))
+        && let Some(link) = parse_wikilink(inner)
+    {
+        return (FactValueKind::Link, link.target_title);
+    }
+    if let Some(url) = leading_url(cleaned) {
+        return (FactValueKind::Url, url);
+    }
+
+    let text = plain_text(cleaned);
+    if text.is_empty() {
+        return (FactValueKind::Empty, String::new());
+    }
+    let prefers_date = DATE_KEY_HINTS.iter().any(|hint| key.contains(hint));
+    if prefers_date && let Some(date) = whole_value_date(&text) {
+        return (FactValueKind::Date, date.iso());
+    }
+    if let Some(number) = parse_number(&text) {
+        return (FactValueKind::Number, number);
+    }
+    if let Some(date) = whole_value_date(&text) {
+        return (FactValueKind::Date, date.iso());
+    }
+    (FactValueKind::Text, text)
+}
+
+fn single_template_fact(value: &str) -> Option<(FactValueKind, String)> {
+    if !value.starts_with("{{") || !value.ends_with("}}") {
+        return None;
+    }
+    let ranges = top_level_ranges(&template_ranges(value));
+    if ranges.len() != 1 || ranges[0] != (0, value.len()) {
+        return None;
+    }
+    let segments = split_template_segments(&value[2..value.len() - 2]);
+    let title = canonical_template_title(segments.first()?)?;
+    let name = title["Template:".len()..].to_ascii_lowercase();
+    let positional = positional_arguments(&segments);
+
+    if name.contains("date") {
+        let year = positional.first().and_then(|value| parse_year(value))?;
+        let month = positional
+            .get(1)
+            .and_then(|value| parse_month(value))
+            .filter(|month| (1..=12).contains(month));
+        let day = month
+            .and(positional.get(2))
+            .and_then(|value| parse_day(value));
+        let date = ParsedDate {
+            year,
+            month,
+            day,
+            end: 0,
+        };
+        return Some((FactValueKind::Date, date.iso()));
+    }
+    if name == "url" || name == "official url" {
+        let url = positional.first()?.trim();
+        return (!url.is_empty()).then(|| (FactValueKind::Url, url.to_string()));
+    }
+    None
+}
+
+fn positional_arguments(segments: &[String]) -> Vec<String> {
+    segments
+        .iter()
+        .skip(1)
+        .filter(|segment| split_once_top_level_equals(segment).is_none())
+        .map(|segment| segment.trim().to_string())
+        .collect()
+}
+
+fn leading_url(value: &str) -> Option<String> {
+    let candidate = value.strip_prefix('[').unwrap_or(value);
+    if !candidate.starts_with("http://") && !candidate.starts_with("https://") {
+        return None;
+    }
+    let end = candidate
+        .find(|ch: char| ch.is_whitespace() || ch == ']')
+        .unwrap_or(candidate.len());
+    Some(candidate[..end].to_string())
+}
+
+/// A date that makes up the whole value, optionally followed by a parenthetical.
+fn whole_value_date(text: &str) -> Option<ParsedDate> {
+    let date = find_leading_date(text)?;
+    let rest = text[date.end..].trim_start();
+    (rest.is_empty() || rest.starts_with('(')).then_some(date)
+}
+
+/// Parse `1,200`, `~4.5 million`, or `$10,000 (2023)` into a plain decimal string.
+fn parse_number(text: &str) -> Option<String> {
+    let lowered = text.to_ascii_lowercase();
+    let mut rest = lowered.trim_start_matches(['~', '≈', '$', '€', '£', '¥', ' ']);
+    for prefix in [
+        "approximately ",
+        "approx. ",
+        "about ",
+        "over ",
+        "c. ",
+        "ca. ",
+    ] {
+        rest = rest.strip_prefix(prefix).unwrap_or(rest);
+    }
+    let (number, end) = leading_number(rest)?;
+    let tail = rest[end..]
+        .trim_start()
+        .trim_start_matches('+')
+        .trim_start();
+    if !(tail.is_empty() || tail.starts_with('(')) {
+        return None;
+    }
+    Some(format_number(number))
+}
+
+/// Parse a number such as `1,200`, `4.5`, or `4.5 million` at the start of
+/// lowercased `text`, returning the value and the byte offset just past it.
+pub(crate) fn leading_number(text: &str) -> Option<(f64, usize)> {
+    let end = text
+        .find(|ch: char| !(ch.is_ascii_digit() || ch == ',' || ch == '.'))
+        .unwrap_or(text.len());
+    let digits = text[..end].trim_end_matches(['.', ',']);
+    if !digits.starts_with(|ch: char| ch.is_ascii_digit()) {
+        return None;
+    }
+    let mut number = digits.replace(',', "").parse::<f64>().ok()?;
+    let mut end = digits.len();
+    let tail = &text[end..];
+    let gap = tail.len() - tail.trim_start().len();
+    for (word, multiplier) in NUMBER_MULTIPLIERS {
+        if tail.trim_start().starts_with(word) {
+            number *= multiplier;
+            end += gap + word.len();
+            break;
+        }
+    }
+    Some((number, end))
+}
+
+pub(crate) fn format_number(number: f64) -> String {
+    if number.fract() == 0.0 && number.abs() < 1e15 {
+        format!("{number:.0}")
+    } else {
+        number.to_string()
+    }
+}
+
+/// Reduce a parameter value to display text: link labels, list items joined
+/// with commas, and no emphasis, HTML, or non-list templates.
+pub(crate) fn plain_text(value: &str) -> String {
+    let mut out = String::new();
+    let mut cursor = 0usize;
+    for (start, end) in top_level_ranges(&template_ranges(value)) {
+        out.push_str(&value[cursor..start]);
+        let segments = split_template_segments(&value[start + 2..end - 2]);
+        let is_list = segments
+            .first()
+            .and_then(|name| canonical_template_title(name))
+            .is_some_and(|title| {
+                LIST_TEMPLATES.contains(&title["Template:".len()..].to_ascii_lowercase().as_str())
+            });
+        if is_list {
+            let items = positional_arguments(&segments)
+                .iter()
+                .map(|item| plain_text(item.trim_start_matches(['*', ' '])))
+                .filter(|item| !item.is_empty())
+                .collect::<Vec<_>>();
+            out.push_str(&items.join(", "));
+        }
+        cursor = end;
+    }
+    out.push_str(&value[cursor..]);
+
+    let text = out
+        .replace("<br>", ", ")
+        .replace("<br/>", ", ")
+        .replace("<br />", ", ")
+        .replace("'''", "")
+        .replace("''", "");
+    flatten_markup_excerpt(&text.replace('\n', " "))
+        .replace(" ,", ",")
+        .trim_matches([',', ' '])
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalized(key: &str, raw: &str) -> (&'static str, String) {
+        let (kind, value) = normalize_fact_value(key, raw);
+        (kind.as_str(), value)
+    }
+
+    #[test]
+    fn normalizes_dates_numbers_links_and_text() {
+        assert_eq!(
+            normalized("founded", "{{Start date|2021|4|9}}"),
+            ("date", "2021-04-09".to_string())
+        );
+        assert_eq!(
+            normalized("founded", "March 2021<ref>Source</ref>"),
+            ("date", "2021-03".to_string())
+        );
+        assert_eq!(normalized("founded", "2019"), ("date", "2019".to_string()));
+        assert_eq!(
+            normalized("members", "2019"),
+            ("number", "2019".to_string())
+        );
+        assert_eq!(
+            normalized("supply", "~1.5 million (2023)"),
+            ("number", "1500000".to_string())
+        );
+        assert_eq!(
+            normalized("founder", "[[Charlotte Fang|Charlotte]]"),
+            ("link", "Charlotte Fang".to_string())
+        );
+        assert_eq!(
+            normalized("website", "{{URL|https://remilia.org}}"),
+            ("url", "https://remilia.org".to_string())
+        );
+        assert_eq!(
+            normalized("genre", "{{hlist|[[Net art]]|''Collage''}}<!-- keep -->"),
+            ("text", "Net art, Collage".to_string())
+        );
+        assert_eq!(
+            normalized("website", " <!-- TBD --> "),
+            ("empty", String::new())
+        );
+    }
+
+    #[test]
+    fn extracts_only_infobox_invocations_with_last_key_winning() {
+        let content = "{{Infobox person\n| name = Ada\n| born = 1990\n| born = 1991\n}}\n\
+                       {{Cite web|title=Not a fact}}\n{{#invoke:Infobox|infobox|title=Module box}}\n\
+                       {{#INVOKE:Infobox album|main|title=[[Loud]]<br>[[Quiet|Soft]]}}";
+        let facts = extract_infobox_facts(content, |title| {
+            title.starts_with("Template:Infobox") || title.starts_with("Module:Infobox")
+        });
+        let summary = facts
+            .iter()
+            .map(|fact| {
+                (
+                    fact.template_title.as_str(),
+                    fact.invocation_index,
+                    fact.parameter_key.as_str(),
+                    fact.normalized_value.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("Template:Infobox person", 0, "name", "Ada"),
+                ("Template:Infobox person", 0, "born", "1991"),
+                ("Module:Infobox", 1, "title", "Module box"),
+                ("Module:Infobox album", 2, "title", "Loud, Soft"),
+            ]
+        );
+    }
+}
@@ -1 +1,9 @@
+pub(crate) mod dates;
+pub(crate) mod facts;
 pub(crate) mod lint;
+pub(crate) mod phrases;
+pub(crate) mod quality;
+pub(crate) mod sentences;
+pub(crate) mod spans;
+pub(crate) mod spelling;
+pub(crate) mod staleness;
@@ -0,0 +1,252 @@
+//! Case- and inflection-tolerant phrase matching over article prose.
+
+use super::spans::{is_excluded, template_ranges, top_level_ranges};
+use crate::content_store::parsing::{html_comment_ranges, html_element_ranges};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PhraseMatch {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    /// Index into the phrase list passed to [`find_phrase_matches`].
+    pub(crate) phrase: usize,
+    /// Every word matched verbatim (ignoring case); otherwise at least one
+    /// word only matched through its stem, e.g. "served as" for "serves as".
+    pub(crate) exact: bool,
+}
+
+struct Word {
+    start: usize,
+    end: usize,
+    lowered: String,
+}
+
+/// Find `phrases` in the prose of `content`. Templates (citations, quote
+/// boxes), references, comments, quotation markup and double-quoted text are
+/// skipped. Overlapping matches resolve to the longest, then the earliest
+/// listed phrase, so callers can order phrase lists by priority.
+pub(crate) fn find_phrase_matches(content: &str, phrases: &[&str]) -> Vec<PhraseMatch> {
+    let excluded = prose_exclusions(content);
+    let words = tokenize(content)
+        .into_iter()
+        .filter(|word| !is_excluded(&excluded, word.start))
+        .collect::<Vec<_>>();
+    let patterns = phrases
+        .iter()
+        .map(|phrase| {
+            tokenize(phrase)
+                .into_iter()
+                .map(|word| {
+                    word.lowered
+                        .split('/')
+                        .filter(|alternative| !alternative.is_empty())
+                        .map(str::to_string)
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut candidates = Vec::new();
+    for first in 0..words.len() {
+        for (phrase, pattern) in patterns.iter().enumerate() {
+            if pattern.is_empty() || first + pattern.len() > words.len() {
+                continue;
+            }
+            let mut exact = true;
+            let matched = pattern.iter().enumerate().all(|(offset, alternatives)| {
+                let word = &words[first + offset];
+                if offset > 0 && !is_word_gap(&content[words[first + offset - 1].end..word.start]) {
+                    return false;
+                }
+                if alternatives.contains(&word.lowered) {
+                    return true;
+                }
+                let word_stem = stem(&word.lowered);
+                let inflected = alternatives
+                    .iter()
+                    .any(|alternative| stem(alternative) == word_stem);
+                exact &= !inflected;
+                inflected
+            });
+            if matched {
+                candidates.push(PhraseMatch {
+                    start: words[first].start,
+                    end: words[first + pattern.len() - 1].end,
+                    phrase,
+                    exact,
+                });
+            }
+        }
+    }
+
+    candidates.sort_by(|left, right| {
+        (right.end - right.start)
+            .cmp(&(left.end - left.start))
+            .then_with(|| left.phrase.cmp(&right.phrase))
+            .then_with(|| left.start.cmp(&right.start))
+    });
+    let mut out: Vec<PhraseMatch> = Vec::new();
+    for candidate in candidates {
+        if out
+            .iter()
+            .all(|kept| candidate.end <= kept.start || candidate.start >= kept.end)
+        {
+            out.push(candidate);
+        }
+    }
+    out.sort_by_key(|found| found.start);
+    out
+}
+
+/// Byte ranges that are not article prose: top-level templates, comments,
+/// references, quotation markup, double-quoted text and hidden link targets.
+pub(crate) fn prose_exclusions(content: &str) -> Vec<(usize, usize)> {
+    let mut excluded = top_level_ranges(&template_ranges(content));
+    excluded.extend(html_comment_ranges(content));
+    excluded.extend(html_element_ranges(content, "ref"));
+    excluded.extend(html_element_ranges(content, "blockquote"));
+    excluded.extend(html_element_ranges(content, "q"));
+    excluded.extend(html_element_ranges(content, "poem"));
+    excluded.extend(quoted_ranges(content));
+    excluded.extend(piped_link_targets(content));
+    excluded.sort_unstable();
+    excluded
+}
+
+/// Text between paired double quotes on a single line.
+fn quoted_ranges(content: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut offset = 0usize;
+    for line in content.split_inclusive('\n') {
+        let mut open = None;
+        for (index, ch) in line.char_indices() {
+            match (ch, open) {
+                ('"' | '“', None) => open = Some(index),
+                ('"' | '”', Some(start)) => {
+                    ranges.push((offset + start, offset + index + ch.len_utf8()));
+                    open = None;
+                }
+                _ => {}
+            }
+        }
+        offset += line.len();
+    }
+    ranges
+}
+
+/// The hidden target half of `[[Target|label]]` links.
+fn piped_link_targets(content: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut cursor = 0usize;
+    while let Some(found) = content[cursor..].find("[[") {
+        let start = cursor + found + 2;
+        let Some(close) = content[start..].find("]]").map(|index| start + index) else {
+            break;
+        };
+        if let Some(pipe) = content[start..close].find('|') {
+            ranges.push((start, start + pipe));
+        }
+        cursor = start;
+    }
+    ranges
+}
+
+/// Words are alphanumeric runs; an apostrophe between letters stays inside
+/// the word so "it's" is one token. Curly apostrophes are folded to ASCII.
+fn tokenize(content: &str) -> Vec<Word> {
+    let mut words = Vec::new();
+    let mut current: Option<Word> = None;
+    let mut chars = content.char_indices().peekable();
+    while let Some((index, ch)) = chars.next() {
+        let next_is_alphanumeric = chars.peek().is_some_and(|(_, next)| next.is_alphanumeric());
+        if ch.is_alphanumeric()
+            || ch == '/'
+            || (matches!(ch, '\'' | '’') && current.is_some() && next_is_alphanumeric)
+        {
+            let word = current.get_or_insert_with(|| Word {
+                start: index,
+                end: index,
+                lowered: String::new(),
+            });
+            word.end = index + ch.len_utf8();
+            if ch == '’' {
+                word.lowered.push('\'');
+            } else {
+                word.lowered.extend(ch.to_lowercase());
+            }
+        } else if let Some(word) = current.take() {
+            words.push(word);
+        }
+    }
+    words.extend(current);
+    words
+}
+
+/// Only whitespace and link/emphasis/hyphen markup may separate phrase words.
+fn is_word_gap(gap: &str) -> bool {
+    !gap.is_empty()
+        && gap
+            .chars()
+            .all(|ch| ch.is_whitespace() || matches!(ch, '[' | ']' | '\'' | '-'))
+}
+
+/// A deliberately rough suffix stripper: enough to equate "serves", "served"
+/// and "serving", or "tapestry" and "tapestries".
+fn stem(word: &str) -> String {
+    let word = word.strip_suffix("'s").unwrap_or(word);
+    let long = |suffix: &str, min: usize| word.len() >= suffix.len() + min;
+    let stripped = if let Some(base) = word.strip_suffix("ies").filter(|_| long("ies", 2)) {
+        format!("{base}y")
+    } else if let Some(base) = word.strip_suffix("ied").filter(|_| long("ied", 2)) {
+        format!("{base}y")
+    } else if let Some(base) = word.strip_suffix("ing").filter(|_| long("ing", 3)) {
+        base.to_string()
+    } else if let Some(base) = word.strip_suffix("ed").filter(|_| long("ed", 3)) {
+        base.to_string()
+    } else if let Some(base) = word.strip_suffix("es").filter(|_| long("es", 3)) {
+        base.to_string()
+    } else if let Some(base) = word
+        .strip_suffix('s')
+        .filter(|_| long("s", 3) && !word.ends_with("ss"))
+    {
+        base.to_string()
+    } else {
+        word.to_string()
+    };
+    match stripped.strip_suffix('e') {
+        Some(base) if base.len() >= 3 => base.to_string(),
+        _ => stripped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_inflections_and_skips_quotes_and_citations() {
+        let content = "It served as a hub. \"It stands as a hub,\" she said.<ref>{{Cite web|title=Stands as a hub}}</ref> It [[Hub|stands as]] one.";
+        let found = find_phrase_matches(content, &["serves as", "stands as"]);
+        assert_eq!(found.len(), 2);
+        assert_eq!(&content[found[0].start..found[0].end], "served as");
+        assert!(!found[0].exact);
+        assert_eq!(&content[found[1].start..found[1].end], "stands as");
+        assert!(found[1].exact);
+    }
+
+    #[test]
+    fn longest_match_wins_and_slash_alternatives_expand() {
+        let content = "Shaping the rich tapestry of art.";
+        let found = find_phrase_matches(
+            content,
+            &["tapestry", "rich tapestry", "marking/shaping the"],
+        );
+        let texts = found
+            .iter()
+            .map(|found| &content[found.start..found.end])
+            .collect::<Vec<_>>();
+        assert_eq!(texts, vec!["Shaping the", "rich tapestry"]);
+        assert_eq!(stem("tapestries"), stem("tapestry"));
+        assert_eq!(stem("serving"), stem("serve"));
+    }
+}
@@ -0,0 +1,122 @@
+//! Content-only article quality measures computed from raw wikitext.
+
+use std::collections::BTreeMap;
+
+use crate::content_store::parsing::extract_media_records;
+use crate::wikitext::sentences::prose_sentences;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct QualityMeasure {
+    pub prose_words: usize,
+    pub lead_words: usize,
+    /// `<ref>` tags, including reuses of named references.
+    pub reference_count: usize,
+    pub paragraph_count: usize,
+    /// Body paragraphs (outside the lead) with prose but no reference.
+    pub uncited_paragraphs: usize,
+    pub section_count: usize,
+    /// Share of body prose words held by the largest section; 1.0 means one
+    /// section carries the whole body.
+    pub largest_section_share: f64,
+    pub image_count: usize,
+}
+
+pub(crate) fn measure_quality(content: &str) -> QualityMeasure {
+    let lines = content.split('\n').collect::<Vec<_>>();
+    let mut paragraph_of_line = vec![None; lines.len() + 1];
+    let mut paragraph_cited = Vec::<bool>::new();
+    let mut heading_lines = Vec::new();
+    let mut open = false;
+    for (index, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if is_heading(trimmed) {
+            heading_lines.push(index + 1);
+            open = false;
+            continue;
+        }
+        if trimmed.is_empty() {
+            open = false;
+            continue;
+        }
+        if !open {
+            paragraph_cited.push(false);
+            open = true;
+        }
+        let paragraph = paragraph_cited.len() - 1;
+        paragraph_of_line[index + 1] = Some(paragraph);
+        if count_reference_tags(line) > 0 {
+            paragraph_cited[paragraph] = true;
+        }
+    }
+
+    let mut measure = QualityMeasure {
+        reference_count: count_reference_tags(content),
+        section_count: heading_lines.len(),
+        image_count: extract_media_records(content).len(),
+        ..QualityMeasure::default()
+    };
+    let mut prose_paragraphs = BTreeMap::<usize, bool>::new();
+    let mut section_words = vec![0usize; heading_lines.len()];
+    for sentence in prose_sentences(content) {
+        let words = sentence.text.split_whitespace().count();
+        measure.prose_words += words;
+        if sentence.in_lead {
+            measure.lead_words += words;
+        } else if let Some(section) = heading_lines
+            .iter()
+            .rposition(|heading| *heading < sentence.line)
+        {
+            section_words[section] += words;
+        }
+        if let Some(paragraph) = paragraph_of_line.get(sentence.line).copied().flatten() {
+            prose_paragraphs.insert(paragraph, sentence.in_lead);
+        }
+    }
+    measure.paragraph_count = prose_paragraphs.len();
+    measure.uncited_paragraphs = prose_paragraphs
+        .iter()
+        .filter(|(paragraph, in_lead)| !**in_lead && !paragraph_cited[**paragraph])
+        .count();
+    let body_words = section_words.iter().sum::<usize>();
+    if body_words > 0 {
+        let largest = section_words.iter().copied().max().unwrap_or_default();
+        measure.largest_section_share = largest as f64 / body_words as f64;
+    }
+    measure
+}
+
+fn is_heading(trimmed: &str) -> bool {
+    trimmed.len() > 2 && trimmed.starts_with("==") && trimmed.ends_with("==")
+}
+
+fn count_reference_tags(text: &str) -> usize {
+    let lowered = text.to_ascii_lowercase();
+    lowered
+        .match_indices("<ref")
+        .filter(|(index, _)| {
+            lowered[index + 4..]
+                .chars()
+                .next()
+                .is_some_and(|next| next == '>' || next == '/' || next.is_whitespace())
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_prose_citations_sections_and_images() {
+        let content = "{{Infobox group\n| name = Milady\n}}\n'''Milady''' is a collection. It launched in 2021.\n\n== History ==\nThe collection sold out quickly.<ref>Source.</ref>\n\nA second paragraph has no citation at all.\n[[File:Milady.png|thumb|Cover]]\n\n== Reception ==\nCritics noticed.<ref name=\"a\" />\n\n== References ==\n<references />";
+        let measure = measure_quality(content);
+        assert_eq!(measure.prose_words, 23);
+        assert_eq!(measure.lead_words, 8);
+        assert_eq!(measure.reference_count, 2);
+        assert_eq!(measure.paragraph_count, 4);
+        assert_eq!(measure.uncited_paragraphs, 1);
+        assert_eq!(measure.section_count, 3);
+        assert!((measure.largest_section_share - 13.0 / 15.0).abs() < 1e-9);
+        assert_eq!(measure.image_count, 1);
+    }
+}
@@ -0,0 +1,148 @@
+//! Prose sentence segmentation over raw wikitext, skipping templates, references, and tables.
+
+use crate::content_store::parsing::{extract_wikilinks, html_comment_ranges, html_element_ranges};
+use crate::wikitext::facts::plain_text;
+use crate::wikitext::spans::{mask_ranges, template_ranges, top_level_ranges};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ProseSentence {
+    /// Byte offsets into the original content.
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    /// Sentence falls before the first section heading.
+    pub in_lead: bool,
+    /// Markup-free sentence text.
+    pub text: String,
+    /// Canonical titles of ordinary wikilinks in the sentence.
+    pub link_targets: Vec<String>,
+}
+
+/// Split article prose into sentences. Templates, references, comments, tables,
+/// headings, and file/category lines are not prose and are skipped.
+pub(crate) fn prose_sentences(content: &str) -> Vec<ProseSentence> {
+    let mut noise = top_level_ranges(&template_ranges(content));
+    noise.extend(html_comment_ranges(content));
+    noise.extend(html_element_ranges(content, "ref"));
+    noise.sort_unstable();
+    let masked = mask_ranges(content, &noise);
+
+    let mut sentences = Vec::new();
+    let mut in_lead = true;
+    let mut in_table = false;
+    let mut line_start = 0usize;
+    for (line_index, line) in masked.split('\n').enumerate() {
+        let line_end = line_start + line.len();
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("{|") {
+            in_table = true;
+        }
+        let skip = in_table
+            || trimmed.is_empty()
+            || trimmed.starts_with('=')
+            || is_file_or_category_line(trimmed);
+        if trimmed.starts_with("|}") {
+            in_table = false;
+        }
+        if trimmed.starts_with('=') && trimmed.trim_end().ends_with('=') {
+            in_lead = false;
+        }
+        if !skip {
+            for (start, end) in split_sentences(line) {
+                let raw = &line[start..end];
+                let text = plain_text(raw.trim_start_matches(['*', '#', ':', ';', ' ']));
+                if text.chars().filter(|ch| ch.is_alphabetic()).count() < 3 {
+                    continue;
+                }
+                let link_targets = extract_wikilinks(raw)
+                    .into_iter()
+                    .filter(|link| !link.is_category_membership)
+                    .map(|link| link.target_title)
+                    .collect();
+                sentences.push(ProseSentence {
+                    start: line_start + start,
+                    end: line_start + end,
+                    line: line_index + 1,
+                    in_lead,
+                    text,
+                    link_targets,
+                });
+            }
+        }
+        line_start = line_end + 1;
+    }
+    sentences
+}
+
+fn is_file_or_category_line(trimmed: &str) -> bool {
+    let lowered = trimmed.to_ascii_lowercase();
+    ["[[file:", "[[image:", "[[category:"]
+        .iter()
+        .any(|prefix| lowered.starts_with(prefix))
+}
+
+/// Sentence byte ranges within one line. A break is `.`, `!`, or `?` outside
+/// links followed by whitespace or the end of the line.
+fn split_sentences(line: &str) -> Vec<(usize, usize)> {
+    let bytes = line.as_bytes();
+    let mut out = Vec::new();
+    let mut start = 0usize;
+    let mut link_depth = 0usize;
+    let mut index = 0usize;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'[' => link_depth += 1,
+            b']' => link_depth = link_depth.saturating_sub(1),
+            b'.' | b'!' | b'?'
+                if link_depth == 0
+                    && bytes
+                        .get(index + 1)
+                        .is_none_or(|next| next.is_ascii_whitespace()) =>
+            {
+                push_trimmed(line, start, index + 1, &mut out);
+                start = index + 1;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    push_trimmed(line, start, line.len(), &mut out);
+    out
+}
+
+fn push_trimmed(line: &str, start: usize, end: usize, out: &mut Vec<(usize, usize)>) {
+    let slice = &line[start..end];
+    let leading = slice.len() - slice.trim_start().len();
+    let trailing = slice.len() - slice.trim_end().len();
+    if leading + trailing < slice.len() {
+        out.push((start + leading, end - trailing));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_lead_and_body_prose_without_markup() {
+        let content = "{{Infobox group\n| name = Milady\n}}\n'''Milady''' is a [[Remilia Corporation|Remilia]] collection.<ref>Cite. Me.</ref> It launched in 2021.\n\n== History ==\n{| class=\"wikitable\"\n| Row. Text.\n|}\n[[File:Milady.png|thumb]]\nThe [[Remilia Corporation]] founded it.\n[[Category:Collections]]";
+        let sentences = prose_sentences(content);
+        let summary = sentences
+            .iter()
+            .map(|sentence| (sentence.line, sentence.in_lead, sentence.text.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (4, true, "Milady is a Remilia collection."),
+                (4, true, "It launched in 2021."),
+                (11, false, "The Remilia Corporation founded it."),
+            ]
+        );
+        assert_eq!(sentences[0].link_targets, vec!["Remilia Corporation"]);
+        assert_eq!(
+            &content[sentences[1].start..sentences[1].end],
+            "It launched in 2021."
+        );
+    }
+}
@@ -0,0 +1,125 @@
+//! Byte-range helpers for locating wikitext markup around prose.
+
+use crate::content_store::parsing::{parse_open_tag, template_spans};
+
+pub(crate) fn word_matches<'a>(
+    lowered: &'a str,
+    phrase: &'a str,
+) -> impl Iterator<Item = usize> + 'a {
+    lowered.match_indices(phrase).filter_map(move |(start, _)| {
+        let end = start + phrase.len();
+        let before = lowered[..start].chars().next_back();
+        let after = lowered[end..].chars().next();
+        let boundary = |ch: Option<char>| ch.is_none_or(|ch| !ch.is_alphanumeric());
+        (boundary(before) && boundary(after)).then_some(start)
+    })
+}
+
+pub(crate) fn sentence_bounds(content: &str, offset: usize) -> (usize, usize) {
+    let is_break = |ch: char| matches!(ch, '.' | '!' | '?' | '\n');
+    let start = content[..offset]
+        .rfind(is_break)
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    let end = content[offset..]
+        .find(is_break)
+        .map(|index| offset + index)
+        .unwrap_or(content.len());
+    (start, end)
+}
+
+/// Every `{{...}}` span, including nested ones, as `(start, end)` byte offsets
+/// sorted by start.
+pub(crate) fn template_ranges(content: &str) -> Vec<(usize, usize)> {
+    let mut ranges = template_spans(content);
+    ranges.sort_unstable();
+    ranges
+}
+
+pub(crate) fn top_level_ranges(ranges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut out: Vec<(usize, usize)> = Vec::new();
+    for &(start, end) in ranges {
+        if out.last().is_some_and(|&(_, last_end)| start < last_end) {
+            continue;
+        }
+        out.push((start, end));
+    }
+    out
+}
+
+/// Every opening, closing or self-closing HTML tag on its own, without the
+/// element body between tags.
+pub(crate) fn html_tag_ranges(content: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut cursor = 0usize;
+    while let Some(found) = content[cursor..].find('<') {
+        let start = cursor + found;
+        let closing = content[start + 1..].starts_with('/');
+        let name_start = start + 1 + usize::from(closing);
+        let name_end = content[name_start..]
+            .find(|ch: char| !ch.is_ascii_alphanumeric())
+            .map_or(content.len(), |index| name_start + index);
+        let name = &content[name_start..name_end];
+        let end = if !name.starts_with(|ch: char| ch.is_ascii_alphabetic()) {
+            None
+        } else if closing {
+            content[name_end..]
+                .find('>')
+                .map(|index| name_end + index + 1)
+        } else {
+            parse_open_tag(content, start, name).map(|(tag_end, _, _)| tag_end)
+        };
+        match end {
+            Some(end) => {
+                ranges.push((start, end));
+                cursor = end;
+            }
+            None => cursor = start + 1,
+        }
+    }
+    ranges
+}
+
+/// Copy `content` without the given sorted, possibly overlapping byte ranges.
+pub(crate) fn remove_ranges(content: &str, ranges: &[(usize, usize)]) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut cursor = 0usize;
+    for &(start, end) in ranges {
+        if start > cursor {
+            out.push_str(&content[cursor..start]);
+        }
+        cursor = cursor.max(end);
+    }
+    if cursor < content.len() {
+        out.push_str(&content[cursor..]);
+    }
+    out
+}
+
+/// Replace the given byte ranges with spaces so offsets into the result still
+/// line up with `content`.
+pub(crate) fn mask_ranges(content: &str, ranges: &[(usize, usize)]) -> String {
+    let mut bytes = content.as_bytes().to_vec();
+    for &(start, end) in ranges {
+        for byte in &mut bytes[start.min(content.len())..end.min(content.len())] {
+            if *byte != b'\n' {
+                *byte = b' ';
+            }
+        }
+    }
+    // Ranges start and end on ASCII markup, so whole characters are blanked.
+    String::from_utf8(bytes).unwrap_or_else(|_| content.to_string())
+}
+
+pub(crate) fn is_excluded(ranges: &[(usize, usize)], offset: usize) -> bool {
+    ranges
+        .iter()
+        .any(|&(start, end)| (start..end).contains(&offset))
+}
+
+pub(crate) fn floor_char_boundary(content: &str, mut index: usize) -> usize {
+    while !content.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
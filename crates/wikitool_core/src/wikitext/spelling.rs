@@ -0,0 +1,590 @@
+//! Offline spell checking of article prose against an English word list and
+//! a project dictionary of page titles, aliases and house terms.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, OnceLock};
+
+use super::phrases::prose_exclusions;
+use super::spans::{html_tag_ranges, is_excluded};
+use crate::content_store::parsing::{html_element_ranges, wikilink_ranges};
+
+/// `form base` pairs for words the suffix rules cannot reach: irregular
+/// inflections ("seen see") and British spellings ("grey gray"). A form is
+/// known when its base is in the word list. The table is maintained by hand
+/// in this repository.
+const BUNDLED_FORMS: &str = include_str!("spelling_forms.txt");
+const MIN_CHECKED_WORD_LEN: usize = 3;
+const MAX_SUGGESTIONS: usize = 3;
+const STEM_DEPTH: usize = 2;
+const SUFFIX_STEMS: &[(&str, &str)] = &[
+    ("'s", ""),
+    ("ies", "y"),
+    ("ied", "y"),
+    ("ier", "y"),
+    ("iest", "y"),
+    ("ily", "y"),
+    ("iness", "y"),
+    ("es", ""),
+    ("s", ""),
+    ("ed", ""),
+    ("ed", "e"),
+    ("ing", ""),
+    ("ing", "e"),
+    ("er", ""),
+    ("er", "e"),
+    ("est", ""),
+    ("est", "e"),
+    ("ly", ""),
+    ("ly", "le"),
+    ("ness", ""),
+    ("ment", ""),
+    ("ful", ""),
+    ("less", ""),
+    ("able", ""),
+    ("able", "e"),
+    ("ably", ""),
+    ("ably", "e"),
+    ("ally", ""),
+    ("ation", "e"),
+    ("ation", "ate"),
+    ("ion", ""),
+    ("ion", "e"),
+    ("al", ""),
+    ("al", "e"),
+    ("ity", ""),
+    ("ive", ""),
+    ("ive", "e"),
+    ("cy", "t"),
+    ("ize", ""),
+    ("ized", ""),
+    ("izing", ""),
+    ("ization", ""),
+    ("ism", ""),
+    ("ist", ""),
+    ("ise", "ize"),
+    ("ised", "ize"),
+    ("ising", "ize"),
+    ("isation", "ize"),
+    ("our", "or"),
+    ("ourite", "orite"),
+    ("tre", "ter"),
+    ("bre", "ber"),
+    ("ogue", "og"),
+    ("yse", "yze"),
+];
+const DOUBLING_SUFFIXES: &[&str] = &["ed", "ing", "er", "est"];
+/// Suffixes re-attached to stem suggestions, so "recieved" can become
+/// "received" although only "receive" is listed.
+const SUGGESTION_SUFFIXES: &[&str] = &["'s", "s", "d", "ed", "ing", "ly", "er"];
+const PREFIXES: &[&str] = &[
+    "un", "in", "im", "ir", "il", "re", "non", "pre", "dis", "mis", "over", "under", "co", "anti",
+    "multi", "sub", "super", "inter", "counter", "semi", "post", "micro", "mega",
+];
+const CODE_TAGS: &[&str] = &[
+    "code",
+    "pre",
+    "syntaxhighlight",
+    "source",
+    "nowiki",
+    "math",
+    "tt",
+    "kbd",
+    "samp",
+    "gallery",
+    "score",
+    "templatedata",
+];
+
+/// English words the checker ranks against, most common first. No general
+/// list ships with wikitool; the caller loads one from the project or the
+/// system. Alphabetical lists work too, they just rank suggestions
+/// alphabetically among equally close words.
+#[derive(Debug, Default)]
+pub(crate) struct WordList {
+    ranks: BTreeMap<String, usize>,
+}
+
+impl WordList {
+    /// One word per line. `#` comments, Hunspell `.dic` affix flags after
+    /// `/`, and lines that are not a single word (such as a `.dic` count
+    /// header) are skipped.
+    pub(crate) fn parse(content: &str) -> Self {
+        let mut ranks = BTreeMap::new();
+        for word in content
+            .lines()
+            .map(|line| line.split('/').next().unwrap_or_default().trim())
+            .filter(|word| {
+                !word.is_empty()
+                    && word
+                        .chars()
+                        .all(|ch| ch.is_ascii_alphabetic() || ch == '\'')
+            })
+        {
+            let rank = ranks.len();
+            ranks.entry(word.to_ascii_lowercase()).or_insert(rank);
+        }
+        Self { ranks }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.ranks.is_empty()
+    }
+}
+
+fn bundled_forms() -> &'static BTreeMap<&'static str, &'static str> {
+    static FORMS: OnceLock<BTreeMap<&'static str, &'static str>> = OnceLock::new();
+    FORMS.get_or_init(|| {
+        BUNDLED_FORMS
+            .lines()
+            .filter_map(|line| line.trim().split_once(' '))
+            .collect()
+    })
+}
+
+/// Known words for one project: the word list plus lowercased project
+/// words. Cheap to clone; the word list is shared.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SpellingDictionary {
+    words: Arc<WordList>,
+    project_words: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Misspelling {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    pub(crate) word: String,
+    /// Best replacement first, cased like the original word.
+    pub(crate) suggestions: Vec<String>,
+}
+
+impl SpellingDictionary {
+    /// Every alphabetic word inside `terms` becomes known; titles and
+    /// phrases may be passed whole.
+    pub(crate) fn new<'a>(words: Arc<WordList>, terms: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut project_words = BTreeSet::new();
+        for term in terms {
+            for word in term.split(|ch: char| !ch.is_alphabetic() && ch != '\'') {
+                let word = word.trim_matches('\'');
+                if !word.is_empty() {
+                    project_words.insert(word.to_lowercase());
+                }
+            }
+        }
+        Self {
+            words,
+            project_words,
+        }
+    }
+
+    pub(crate) fn is_known(&self, word: &str) -> bool {
+        self.is_known_at_depth(&word.to_lowercase(), STEM_DEPTH)
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        let ranks = &self.words.ranks;
+        ranks.contains_key(word)
+            || bundled_forms()
+                .get(word)
+                .is_some_and(|base| ranks.contains_key(*base))
+            || self.project_words.contains(word)
+    }
+
+    fn is_known_at_depth(&self, word: &str, depth: usize) -> bool {
+        if self.contains(word) {
+            return true;
+        }
+        if depth == 0 {
+            return false;
+        }
+        let stems = word_stems(word);
+        stems
+            .iter()
+            .any(|stem| self.is_known_at_depth(stem, depth - 1))
+    }
+
+    /// Up to three known words within one edit (two for longer words),
+    /// closest first, then sharing the first letter, then by how common the
+    /// listed word is.
+    pub(crate) fn suggestions(&self, word: &str) -> Vec<String> {
+        let lowered = word.to_lowercase();
+        let max_distance = if lowered.chars().count() <= 4 { 1 } else { 2 };
+        let unranked = self.words.ranks.len();
+        let first = lowered.chars().next();
+        // Closer first, then words sharing the first letter (typos rarely
+        // hit it), then by how common the word is.
+        let mut ranked = BTreeMap::<String, (usize, bool, usize)>::new();
+        let mut consider = |candidate: String, rank: usize| {
+            let distance = edit_distance(&lowered, &candidate, max_distance);
+            if distance <= max_distance && candidate != lowered {
+                let key = (distance, candidate.chars().next() != first, rank);
+                let entry = ranked.entry(candidate).or_insert(key);
+                *entry = (*entry).min(key);
+            }
+        };
+        for stem_suffix in std::iter::once("")
+            .chain(SUGGESTION_SUFFIXES.iter().copied())
+            .filter(|suffix| lowered.len() > suffix.len() + 2 && lowered.ends_with(suffix))
+        {
+            let stem = &lowered[..lowered.len() - stem_suffix.len()];
+            let stem_len = stem.chars().count();
+            // Re-inflecting two-letter words yields non-words like "ated".
+            let min_len = if stem_suffix.is_empty() {
+                1
+            } else {
+                MIN_CHECKED_WORD_LEN
+            };
+            let close = |candidate: &str| {
+                let len = candidate.chars().count();
+                len >= min_len && len.abs_diff(stem_len) <= max_distance
+            };
+            for (candidate, &rank) in &self.words.ranks {
+                if close(candidate) {
+                    consider(reinflect(candidate, stem_suffix), rank);
+                }
+            }
+            for candidate in &self.project_words {
+                if close(candidate) {
+                    consider(reinflect(candidate, stem_suffix), unranked);
+                }
+            }
+        }
+        let mut ranked = ranked
+            .into_iter()
+            .filter(|(candidate, _)| self.is_known(candidate))
+            .collect::<Vec<_>>();
+        ranked.sort_by(|left, right| left.1.cmp(&right.1).then(left.0.cmp(&right.0)));
+        ranked
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(candidate, _)| match_case(word, &candidate))
+            .collect()
+    }
+
+    /// Unknown words in the prose of `content` that have at least one close
+    /// suggestion. Words with no neighbour within reach are far more often
+    /// rare-but-valid terms than typos, so they are left alone. Without a
+    /// word list every word would look unknown, so nothing is reported.
+    pub(crate) fn find_misspellings(&self, content: &str) -> Vec<Misspelling> {
+        if self.words.is_empty() {
+            return Vec::new();
+        }
+        let excluded = spelling_exclusions(content);
+        let mut suggestions_by_word = BTreeMap::<String, Vec<String>>::new();
+        let mut out = Vec::new();
+        for (start, end) in prose_words(content) {
+            if is_excluded(&excluded, start) {
+                continue;
+            }
+            let word = &content[start..end];
+            if !should_check(content, start, word) || self.is_known(word) {
+                continue;
+            }
+            let suggestions = suggestions_by_word
+                .entry(word.to_string())
+                .or_insert_with(|| self.suggestions(word));
+            if suggestions.is_empty() {
+                continue;
+            }
+            out.push(Misspelling {
+                start,
+                end,
+                word: word.to_string(),
+                suggestions: suggestions.clone(),
+            });
+        }
+        out
+    }
+}
+
+fn word_stems(word: &str) -> Vec<String> {
+    let mut stems = Vec::new();
+    for &(suffix, replacement) in SUFFIX_STEMS {
+        let Some(stem) = word.strip_suffix(suffix) else {
+            continue;
+        };
+        if stem.len() < 2 {
+            continue;
+        }
+        stems.push(format!("{stem}{replacement}"));
+        let bytes = stem.as_bytes();
+        if replacement.is_empty()
+            && DOUBLING_SUFFIXES.contains(&suffix)
+            && bytes.len() >= 3
+            && bytes[bytes.len() - 1] == bytes[bytes.len() - 2]
+        {
+            stems.push(stem[..stem.len() - 1].to_string());
+        }
+    }
+    for prefix in PREFIXES {
+        if let Some(rest) = word.strip_prefix(prefix)
+            && rest.len() >= 3
+        {
+            stems.push(rest.to_string());
+        }
+    }
+    stems
+}
+
+fn reinflect(candidate: &str, suffix: &str) -> String {
+    match suffix {
+        "d" | "ed" | "ing" | "er" if candidate.ends_with('e') => {
+            let suffix = suffix.strip_prefix('e').unwrap_or(suffix);
+            let stem = if suffix == "ing" {
+                &candidate[..candidate.len() - 1]
+            } else {
+                candidate
+            };
+            format!("{stem}{suffix}")
+        }
+        _ => format!("{candidate}{suffix}"),
+    }
+}
+
+/// Optimal string alignment distance, giving up once it exceeds `limit`.
+fn edit_distance(left: &str, right: &str, limit: usize) -> usize {
+    let left = left.chars().collect::<Vec<_>>();
+    let right = right.chars().collect::<Vec<_>>();
+    if left.len().abs_diff(right.len()) > limit {
+        return limit + 1;
+    }
+    let width = right.len() + 1;
+    let mut rows = vec![vec![0usize; width]; left.len() + 1];
+    for (index, row) in rows.iter_mut().enumerate() {
+        row[0] = index;
+    }
+    for (index, cell) in rows[0].iter_mut().enumerate() {
+        *cell = index;
+    }
+    for i in 1..=left.len() {
+        let mut row_min = usize::MAX;
+        for j in 1..=right.len() {
+            let cost = usize::from(left[i - 1] != right[j - 1]);
+            let mut value = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && left[i - 1] == right[j - 2] && left[i - 2] == right[j - 1] {
+                value = value.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = value;
+            row_min = row_min.min(value);
+        }
+        if row_min > limit {
+            return limit + 1;
+        }
+    }
+    rows[left.len()][right.len()]
+}
+
+fn match_case(original: &str, candidate: &str) -> String {
+    if original.chars().next().is_some_and(char::is_uppercase) {
+        let mut chars = candidate.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    } else {
+        candidate.to_string()
+    }
+}
+
+/// Prose exclusions plus every part of links, URLs, HTML tags, code-like
+/// elements and table markup lines.
+fn spelling_exclusions(content: &str) -> Vec<(usize, usize)> {
+    let mut excluded = prose_exclusions(content);
+    excluded.extend(wikilink_ranges(content));
+    excluded.extend(external_link_ranges(content));
+    for tag in CODE_TAGS {
+        excluded.extend(html_element_ranges(content, tag));
+    }
+    excluded.extend(html_tag_ranges(content));
+    let mut offset = 0usize;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("{|") || trimmed.starts_with("|-") || trimmed.starts_with("|}") {
+            excluded.push((offset, offset + line.len()));
+        }
+        offset += line.len();
+    }
+    excluded.sort_unstable();
+    excluded
+}
+
+/// Bracketed `[https://... label]` links and bare URLs.
+fn external_link_ranges(content: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    for scheme in ["http://", "https://", "//"] {
+        let mut cursor = 0usize;
+        while let Some(found) = content[cursor..].find(scheme) {
+            let start = cursor + found;
+            let bracketed = content[..start].ends_with('[');
+            let end = if bracketed {
+                content[start..]
+                    .find(']')
+                    .map(|index| start + index + 1)
+                    .unwrap_or(content.len())
+            } else {
+                content[start..]
+                    .find(|ch: char| ch.is_whitespace() || matches!(ch, '|' | '}' | ']' | '<'))
+                    .map(|index| start + index)
+                    .unwrap_or(content.len())
+            };
+            ranges.push((start - usize::from(bracketed), end));
+            cursor = end.max(start + scheme.len());
+        }
+    }
+    ranges
+}
+
+/// Runs of ASCII letters with inner apostrophes, skipping tokens glued to
+/// digits, other scripts, or URL and path punctuation.
+fn prose_words(content: &str) -> Vec<(usize, usize)> {
+    let bytes = content.as_bytes();
+    let mut words = Vec::new();
+    let mut index = 0usize;
+    while index < bytes.len() {
+        if !bytes[index].is_ascii_alphabetic() {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        while index < bytes.len()
+            && (bytes[index].is_ascii_alphabetic()
+                || (bytes[index] == b'\''
+                    && bytes.get(index + 1).is_some_and(u8::is_ascii_alphabetic)))
+        {
+            index += 1;
+        }
+        let before = content[..start].chars().next_back();
+        let after = content[index..].chars().next();
+        let after_next = content[index..].chars().nth(1);
+        let glued = |ch: Option<char>| {
+            ch.is_some_and(|ch| ch.is_alphanumeric() || matches!(ch, '_' | '@' | '/' | '\\' | '#'))
+        };
+        let dotted = |ch: Option<char>, next: Option<char>| {
+            ch == Some('.') && next.is_some_and(char::is_alphanumeric)
+        };
+        let preceded_by_dot = content[..start].ends_with('.')
+            && content[..start.saturating_sub(1)]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_alphanumeric);
+        if !glued(before) && !glued(after) && !dotted(after, after_next) && !preceded_by_dot {
+            words.push((start, index));
+        }
+    }
+    words
+}
+
+/// Lowercase words are always checked. Capitalized words are usually names,
+/// so they are only checked at the start of a sentence.
+fn should_check(content: &str, start: usize, word: &str) -> bool {
+    let bare = word.strip_suffix("'s").unwrap_or(word);
+    if bare.len() < MIN_CHECKED_WORD_LEN {
+        return false;
+    }
+    let mut chars = bare.chars();
+    let first = chars.next().unwrap_or_default();
+    if chars.any(|ch| ch.is_ascii_uppercase()) {
+        return false;
+    }
+    if first.is_ascii_lowercase() {
+        return true;
+    }
+    content[..start]
+        .trim_end_matches(|ch: char| ch.is_whitespace() || ch == '\'' || ch == '"')
+        .ends_with(['.', '!', '?'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_WORDS: &str = "the\nof\nand\nsee\nwas\nreceive\nproject\nlaunch\nteam\ngrow\nrun\nstudy\nhappy\nbelieve\neat\nbeat\nbite\nchild\ncolor\ncenter\nbehavior\nfavorite\nhonor\ngray\nprogram\ncatalog\nanalyze\norganize\n";
+
+    fn dictionary<'a>(terms: impl IntoIterator<Item = &'a str>) -> SpellingDictionary {
+        SpellingDictionary::new(Arc::new(WordList::parse(TEST_WORDS)), terms)
+    }
+
+    #[test]
+    fn known_words_include_inflections_and_project_terms() {
+        let dictionary = dictionary(["Remilia Corporation", "Milady Maker"]);
+        for word in [
+            "the",
+            "received",
+            "running",
+            "studies",
+            "happily",
+            "unbelievable",
+            "remilia",
+            "Milady's",
+        ] {
+            assert!(dictionary.is_known(word), "{word}");
+        }
+        assert!(!dictionary.is_known("recieve"));
+
+        let words = WordList::parse("2\nColor/MS\n# comment\nthe\ncolor\nice cream\n");
+        assert_eq!(
+            words.ranks.into_iter().collect::<Vec<_>>(),
+            vec![("color".to_string(), 0), ("the".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn known_words_include_irregular_forms_and_british_spellings() {
+        let dictionary = dictionary(Vec::<&str>::new());
+        for word in [
+            "seen",
+            "eaten",
+            "beaten",
+            "bitten",
+            "unseen",
+            "children's",
+            "colour",
+            "colours",
+            "centre",
+            "behaviour",
+            "favourite",
+            "honour",
+            "grey",
+            "programme",
+            "catalogue",
+            "analysed",
+            "organisation",
+        ] {
+            assert!(dictionary.is_known(word), "{word}");
+        }
+        let without_base =
+            SpellingDictionary::new(Arc::new(WordList::parse("the\n")), Vec::<&str>::new());
+        assert!(!without_base.is_known("seen"));
+    }
+
+    #[test]
+    fn suggestions_rank_closest_and_common_words_first() {
+        let dictionary = dictionary(["Remilia"]);
+        assert_eq!(dictionary.suggestions("recieve")[0], "receive");
+        assert_eq!(dictionary.suggestions("recieved")[0], "received");
+        assert_eq!(dictionary.suggestions("Teh")[0], "The");
+        assert_eq!(
+            dictionary.suggestions("remilla"),
+            vec!["remilia".to_string()]
+        );
+    }
+
+    #[test]
+    fn finds_misspellings_only_in_prose() {
+        let dictionary = dictionary(Vec::<&str>::new());
+        let content = "The projcet was launched. Teh team grew.<ref>{{Cite web|title=Projcet}}</ref> See [[Projcet page|projcet]] and [https://example.com/projcet projcet] or <code>projcet</code>.\n";
+        let found = dictionary.find_misspellings(content);
+        let words = found
+            .iter()
+            .map(|item| item.word.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(words, vec!["projcet", "Teh"]);
+        assert_eq!(found[0].suggestions[0], "project");
+        assert_eq!(&content[found[1].start..found[1].end], "Teh");
+
+        let without_list = SpellingDictionary::new(Arc::default(), ["Project"]);
+        assert!(without_list.find_misspellings(content).is_empty());
+    }
+}
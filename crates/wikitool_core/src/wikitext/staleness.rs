@@ -0,0 +1,339 @@
+//! Time-sensitive statement detection shared by article lint and the wiki-wide staleness report.
+
+use crate::content_store::parsing::{
+    canonical_template_title, html_comment_ranges, html_element_ranges,
+    normalize_template_parameter_key, split_once_top_level_equals, split_template_segments,
+};
+use crate::wikitext::dates::{ParsedDate, find_date, find_leading_date, parse_month, parse_year};
+use crate::wikitext::spans::{
+    floor_char_boundary, is_excluded, sentence_bounds, template_ranges, top_level_ranges,
+    word_matches,
+};
+
+/// Dated claims older than this are reported as stale.
+pub(crate) const STALE_CLAIM_AFTER_DAYS: i64 = 365;
+
+const RELATIVE_PHRASES: &[&str] = &[
+    "currently",
+    "recently",
+    "this year",
+    "last year",
+    "at present",
+    "presently",
+    "nowadays",
+    "as of now",
+    "as of today",
+    "at the time of writing",
+];
+const UPDATE_TEMPLATES: &[&str] = &["update", "outdated", "out of date", "update section"];
+const INFOBOX_DATED_KEY_MARKERS: &[&str] =
+    &["as of", "asof", "updated", "last update", "data date"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TemporalClaimKind {
+    AsOfTemplate,
+    UpdateTemplate,
+    AsOfPhrase,
+    RelativePhrase,
+    InfoboxField,
+}
+
+impl TemporalClaimKind {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::AsOfTemplate => "as_of_template",
+            Self::UpdateTemplate => "update_template",
+            Self::AsOfPhrase => "as_of_phrase",
+            Self::RelativePhrase => "relative_phrase",
+            Self::InfoboxField => "infobox_field",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TemporalClaim {
+    pub kind: TemporalClaimKind,
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+    /// Last day the claim can refer to, in days since the UNIX epoch. A bare
+    /// year counts from December 31 so claims are never aged early.
+    pub claim_day: Option<i64>,
+    pub age_days: Option<i64>,
+}
+
+impl TemporalClaim {
+    /// Dated claims go stale with age; undated relative wording and update
+    /// banners cannot be verified and always count.
+    pub(crate) fn is_stale(&self, stale_after_days: i64) -> bool {
+        match self.age_days {
+            Some(age) => age >= stale_after_days,
+            None => matches!(
+                self.kind,
+                TemporalClaimKind::RelativePhrase | TemporalClaimKind::UpdateTemplate
+            ),
+        }
+    }
+}
+
+/// Find dated and time-relative statements in raw wikitext. `today` is days
+/// since the UNIX epoch.
+pub(crate) fn find_temporal_claims(content: &str, today: i64) -> Vec<TemporalClaim> {
+    let mut claims = Vec::new();
+    let templates = template_ranges(content);
+    for &(start, end) in &templates {
+        collect_template_claims(content, start, end, &mut claims);
+    }
+
+    let mut excluded = top_level_ranges(&templates);
+    excluded.extend(html_comment_ranges(content));
+    excluded.extend(html_element_ranges(content, "ref"));
+    excluded.sort_unstable();
+
+    let lowered = content.to_ascii_lowercase();
+    collect_as_of_phrases(content, &lowered, &excluded, &mut claims);
+    let dated = claims
+        .iter()
+        .filter(|claim| claim.claim_day.is_some())
+        .map(|claim| claim.start)
+        .collect::<Vec<_>>();
+    collect_relative_phrases(content, &lowered, &excluded, &dated, &mut claims);
+
+    for claim in &mut claims {
+        claim.age_days = claim.claim_day.map(|day| (today - day).max(0));
+    }
+    claims.sort_by_key(|claim| (claim.start, claim.end));
+    claims
+}
+
+fn collect_template_claims(
+    content: &str,
+    start: usize,
+    end: usize,
+    claims: &mut Vec<TemporalClaim>,
+) {
+    let inner = &content[start + 2..end - 2];
+    let segments = split_template_segments(inner);
+    let Some(title) = segments
+        .first()
+        .and_then(|name| canonical_template_title(name))
+    else {
+        return;
+    };
+    let name = title["Template:".len()..].to_ascii_lowercase();
+    let raw = &content[start..end];
+
+    if name == "as of" {
+        let positional = segments
+            .iter()
+            .skip(1)
+            .filter(|segment| split_once_top_level_equals(segment).is_none())
+            .collect::<Vec<_>>();
+        let Some(year) = positional
+            .first()
+            .and_then(|value| parse_year(value.trim()))
+        else {
+            return;
+        };
+        let month = positional
+            .get(1)
+            .and_then(|value| parse_month(value.trim()))
+            .filter(|month| (1..=12).contains(month));
+        let day = month
+            .and(positional.get(2))
+            .and_then(|value| value.trim().parse::<u32>().ok())
+            .filter(|day| (1..=31).contains(day));
+        let date = ParsedDate {
+            year,
+            month,
+            day,
+            end: 0,
+        };
+        claims.push(claim(
+            TemporalClaimKind::AsOfTemplate,
+            start,
+            end,
+            raw,
+            Some(date),
+        ));
+        return;
+    }
+
+    if UPDATE_TEMPLATES.contains(&name.as_str()) {
+        let date = named_parameters(&segments)
+            .find(|(key, _)| key == "date")
+            .and_then(|(_, value)| find_date(&value));
+        claims.push(claim(
+            TemporalClaimKind::UpdateTemplate,
+            start,
+            end,
+            raw,
+            date,
+        ));
+        return;
+    }
+
+    if name.starts_with("infobox") {
+        for (key, value) in named_parameters(&segments) {
+            let lowered_value = value.to_ascii_lowercase();
+            let dated_key = INFOBOX_DATED_KEY_MARKERS
+                .iter()
+                .any(|marker| key.contains(marker));
+            if !dated_key && !lowered_value.contains("as of") {
+                continue;
+            }
+            let Some(date) = find_date(&value) else {
+                continue;
+            };
+            let (field_start, field_end) = raw
+                .find(value.as_str())
+                .map(|offset| (start + offset, start + offset + value.len()))
+                .unwrap_or((start, end));
+            claims.push(claim(
+                TemporalClaimKind::InfoboxField,
+                field_start,
+                field_end,
+                &format!("{key} = {value}"),
+                Some(date),
+            ));
+        }
+    }
+}
+
+fn collect_as_of_phrases(
+    content: &str,
+    lowered: &str,
+    excluded: &[(usize, usize)],
+    claims: &mut Vec<TemporalClaim>,
+) {
+    for start in word_matches(lowered, "as of") {
+        if is_excluded(excluded, start) {
+            continue;
+        }
+        let date_start = start + "as of".len();
+        let window_end = floor_char_boundary(content, (date_start + 24).min(content.len()));
+        let window = &content[date_start..window_end];
+        let Some(date) = find_leading_date(window) else {
+            continue;
+        };
+        let end = date_start + date.end;
+        claims.push(claim(
+            TemporalClaimKind::AsOfPhrase,
+            start,
+            end,
+            &content[start..end],
+            Some(date),
+        ));
+    }
+}
+
+fn collect_relative_phrases(
+    content: &str,
+    lowered: &str,
+    excluded: &[(usize, usize)],
+    dated: &[usize],
+    claims: &mut Vec<TemporalClaim>,
+) {
+    for phrase in RELATIVE_PHRASES {
+        for start in word_matches(lowered, phrase) {
+            if is_excluded(excluded, start) {
+                continue;
+            }
+            // A relative word anchored by a dated claim in the same sentence is
+            // already covered by that claim's age.
+            let (sentence_start, sentence_end) = sentence_bounds(content, start);
+            if dated
+                .iter()
+                .any(|offset| (sentence_start..sentence_end).contains(offset))
+            {
+                continue;
+            }
+            let end = start + phrase.len();
+            claims.push(claim(
+                TemporalClaimKind::RelativePhrase,
+                start,
+                end,
+                &content[start..end],
+                None,
+            ));
+        }
+    }
+}
+
+fn claim(
+    kind: TemporalClaimKind,
+    start: usize,
+    end: usize,
+    text: &str,
+    date: Option<ParsedDate>,
+) -> TemporalClaim {
+    TemporalClaim {
+        kind,
+        start,
+        end,
+        text: text.trim().to_string(),
+        claim_day: date.map(ParsedDate::last_day),
+        age_days: None,
+    }
+}
+
+fn named_parameters(segments: &[String]) -> impl Iterator<Item = (String, String)> + '_ {
+    segments.iter().skip(1).filter_map(|segment| {
+        let (key, value) = split_once_top_level_equals(segment)?;
+        let value = value.trim().to_string();
+        (!value.is_empty()).then(|| (normalize_template_parameter_key(&key), value))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::days_from_civil;
+
+    fn today() -> i64 {
+        days_from_civil(2026, 6, 1)
+    }
+
+    fn kinds(claims: &[TemporalClaim]) -> Vec<&'static str> {
+        claims.iter().map(|claim| claim.kind.as_str()).collect()
+    }
+
+    #[test]
+    fn dates_as_of_templates_and_phrases() {
+        let content = "{{As of|2023|3|5}}, the DAO had 40 members. As of March 2024 it ran a fund.";
+        let claims = find_temporal_claims(content, today());
+        assert_eq!(kinds(&claims), vec!["as_of_template", "as_of_phrase"]);
+        assert_eq!(claims[0].claim_day, Some(days_from_civil(2023, 3, 5)));
+        assert_eq!(claims[1].text, "As of March 2024");
+        assert_eq!(claims[1].claim_day, Some(days_from_civil(2024, 3, 31)));
+        assert!(claims.iter().all(|claim| claim.is_stale(365)));
+        assert!(!claims[1].is_stale(1_000));
+    }
+
+    #[test]
+    fn flags_unanchored_relative_wording_outside_markup() {
+        let content = "The project is currently active.<ref>Currently cited</ref>\n\
+                       <!-- recently edited --> {{Quote|currently}} It is currently, as of 2025, paused.";
+        let claims = find_temporal_claims(content, today());
+        assert_eq!(kinds(&claims), vec!["relative_phrase", "as_of_phrase"]);
+        assert_eq!(claims[0].text, "currently");
+        assert_eq!(claims[0].age_days, None);
+        assert!(claims[0].is_stale(365));
+        assert_eq!(claims[1].claim_day, Some(days_from_civil(2025, 12, 31)));
+    }
+
+    #[test]
+    fn reads_dated_infobox_fields_and_update_banners() {
+        let content = "{{Infobox organization\n| members = 1,200 (as of 2022)\n| founded = 2019\n\
+                       | revenue_as_of = 2021-07-04\n}}\n{{Update|date=January 2025}}";
+        let claims = find_temporal_claims(content, today());
+        assert_eq!(
+            kinds(&claims),
+            vec!["infobox_field", "infobox_field", "update_template"]
+        );
+        assert_eq!(claims[0].text, "members = 1,200 (as of 2022)");
+        assert_eq!(claims[1].claim_day, Some(days_from_civil(2021, 7, 4)));
+        assert_eq!(&content[claims[1].start..claims[1].end], "2021-07-04");
+        assert_eq!(claims[2].claim_day, Some(days_from_civil(2025, 1, 31)));
+    }
+}